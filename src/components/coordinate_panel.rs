@@ -5,8 +5,14 @@
 //!
 //! This widget shows the x, y, width, and height of the current selection,
 //! and includes a quadrant picker to choose which corner/edge to use as the
-//! reference point for multi-point selections.
+//! reference point for multi-point selections. With a single on-curve
+//! point selected it also shows the length, angle and Tunni tension of
+//! its incoming and outgoing handles; with a component selected, its
+//! offset, scale, rotation and skew.
 
+use crate::editing::session::{
+    ComponentField, ComponentTransform, HandleField, HandleInfo, HandleReading, HandleSide,
+};
 use crate::path::Quadrant;
 use kurbo::{Axis, Circle, Point, Rect};
 use masonry::accesskit::{Node, Role};
@@ -22,7 +28,8 @@ use masonry::imaging::Painter;
 use tracing;
 use xilem::WidgetView;
 use xilem::style::Style;
-use xilem::view::{CrossAxisAlignment, flex_col, flex_row, sized_box};
+use xilem::core::one_of::Either;
+use xilem::view::{CrossAxisAlignment, flex_col, flex_row, label, sized_box};
 
 // Import from theme (includes all sizing and color constants)
 use crate::theme::coordinate_panel::*;
@@ -38,6 +45,14 @@ mod layout {
     /// Overall panel dimensions
     pub const PANEL_WIDTH: f64 = 240.0;
     pub const PANEL_HEIGHT: f64 = 140.0;
+    /// Extra height for the handle rows of a single on-curve point
+    pub const HANDLE_ROWS_HEIGHT: f64 = 72.0;
+
+    /// Extra height for the transform rows of a selected component
+    pub const COMPONENT_ROWS_HEIGHT: f64 = 108.0;
+
+    /// Width of the "In" / "Out" labels in front of the handle rows
+    pub const HANDLE_LABEL_WIDTH: f64 = 24.0;
 
     /// Quadrant selector (3x3 grid picker) size
     /// Coordinate inputs total ~100px (48 + 4 + 48), so reducing quadrant
@@ -73,6 +88,10 @@ pub enum CoordField {
     Width,
     #[allow(dead_code)]
     Height,
+    /// Length, angle or tension of a handle of the selected point
+    Handle(HandleSide, HandleField),
+    /// A value of the selected component's transform
+    Component(ComponentField),
 }
 
 /// Coordinate data extracted from the session
@@ -192,13 +211,122 @@ fn build_coordinate_inputs<State: 'static>(
         .gap(layout::GAP_BETWEEN_ROWS.px())
 }
 
+/// Build one handle row: length, angle and tension of one side
+///
+/// Empty when the point has no handle on that side. Tension stays
+/// empty for handles that aren't part of a cubic segment with a Tunni
+/// point.
+fn build_handle_row<State: 'static>(
+    name: &'static str,
+    side: HandleSide,
+    handle: Option<HandleReading>,
+    on_coord_change: Arc<dyn Fn(&mut State, CoordField, String) + Send + Sync>,
+) -> impl WidgetView<State> {
+    let format = |value: Option<f64>| value.map(|v| format!("{v:.1}")).unwrap_or_default();
+    let input = |value: Option<f64>, placeholder: &'static str, field: HandleField| {
+        let cb = Arc::clone(&on_coord_change);
+        build_coord_input(format(value), placeholder, move |state: &mut State, val| {
+            cb(state, CoordField::Handle(side, field), val);
+        })
+    };
+
+    flex_row((
+        sized_box(
+            label(name)
+                .text_size(10.0)
+                .color(crate::theme::panel::GLYPH_PREVIEW),
+        )
+        .width(layout::HANDLE_LABEL_WIDTH.px()),
+        input(handle.map(|h| h.length), "L", HandleField::Length),
+        input(handle.map(|h| h.angle), "°", HandleField::Angle),
+        input(handle.and_then(|h| h.tension), "%", HandleField::Tension),
+    ))
+    .cross_axis_alignment(CrossAxisAlignment::Center)
+    .gap(layout::GAP_BETWEEN_INPUTS.px())
+}
+
+/// Build the handle section (incoming and outgoing rows)
+fn build_handle_inputs<State: 'static>(
+    info: HandleInfo,
+    on_coord_change: Arc<dyn Fn(&mut State, CoordField, String) + Send + Sync>,
+) -> impl WidgetView<State> {
+    let incoming = build_handle_row(
+        "In",
+        HandleSide::Incoming,
+        info.incoming,
+        Arc::clone(&on_coord_change),
+    );
+    let outgoing = build_handle_row("Out", HandleSide::Outgoing, info.outgoing, on_coord_change);
+
+    flex_col((incoming, outgoing))
+        .cross_axis_alignment(CrossAxisAlignment::End)
+        .gap(layout::GAP_BETWEEN_INPUTS.px())
+}
+
+/// Build the component section: offset, scale and rotation/skew rows
+fn build_component_inputs<State: 'static>(
+    values: ComponentTransform,
+    on_coord_change: Arc<dyn Fn(&mut State, CoordField, String) + Send + Sync>,
+) -> impl WidgetView<State> {
+    let row = |name: &'static str, fields: [(f64, &'static str, ComponentField); 2]| {
+        let input = |(value, placeholder, field): (f64, &'static str, ComponentField)| {
+            let cb = Arc::clone(&on_coord_change);
+            build_coord_input(format!("{value:.1}"), placeholder, move |state: &mut State, val| {
+                cb(state, CoordField::Component(field), val);
+            })
+        };
+        let [first, second] = fields;
+        flex_row((
+            sized_box(
+                label(name)
+                    .text_size(10.0)
+                    .color(crate::theme::panel::GLYPH_PREVIEW),
+            )
+            .width(layout::HANDLE_LABEL_WIDTH.px()),
+            input(first),
+            input(second),
+        ))
+        .cross_axis_alignment(CrossAxisAlignment::Center)
+        .gap(layout::GAP_BETWEEN_INPUTS.px())
+    };
+
+    flex_col((
+        row(
+            "Pos",
+            [
+                (values.x, "X", ComponentField::X),
+                (values.y, "Y", ComponentField::Y),
+            ],
+        ),
+        row(
+            "Scl",
+            [
+                (values.scale_x, "X%", ComponentField::ScaleX),
+                (values.scale_y, "Y%", ComponentField::ScaleY),
+            ],
+        ),
+        row(
+            "Rot",
+            [
+                (values.rotation, "°", ComponentField::Rotation),
+                (values.skew, "Skew", ComponentField::Skew),
+            ],
+        ),
+    ))
+    .cross_axis_alignment(CrossAxisAlignment::End)
+    .gap(layout::GAP_BETWEEN_INPUTS.px())
+}
+
 /// Build the final panel container with background, border, and layout
 ///
-/// Arranges: [Quadrant Selector] [Coordinate Inputs]
-/// Content is centered both horizontally and vertically within the panel.
+/// Arranges: [Quadrant Selector] [Coordinate Inputs], with the handle
+/// or component rows (if any) below, `extra_height` taller. Content is
+/// centered both horizontally and vertically within the panel.
 fn build_panel_container<State: 'static>(
     quadrant_selector: impl WidgetView<State>,
     coordinate_inputs: impl WidgetView<State>,
+    extra_inputs: Option<impl WidgetView<State>>,
+    extra_height: f64,
 ) -> impl WidgetView<State> {
     // Main horizontal layout: quadrant | inputs
     let row = flex_row((quadrant_selector, coordinate_inputs))
@@ -206,15 +334,22 @@ fn build_panel_container<State: 'static>(
         .cross_axis_alignment(CrossAxisAlignment::Center)
         .gap(layout::GAP_BETWEEN_SECTIONS.px());
 
-    // Center the row both horizontally and vertically within the panel
-    let centered_content = flex_col((row,))
+    let height = layout::PANEL_HEIGHT + extra_height;
+    let extra_inputs = match extra_inputs {
+        Some(inputs) => Either::A(inputs),
+        None => Either::B(sized_box(label("")).width(0.px()).height(0.px())),
+    };
+
+    // Center the rows both horizontally and vertically within the panel
+    let centered_content = flex_col((row, extra_inputs))
         .main_axis_alignment(MainAxisAlignment::Center)
-        .cross_axis_alignment(CrossAxisAlignment::Center);
+        .cross_axis_alignment(CrossAxisAlignment::Center)
+        .gap(layout::GAP_BETWEEN_ROWS.px());
 
     // Apply panel styling, dimensions, and padding
     sized_box(centered_content)
         .width(layout::PANEL_WIDTH.px())
-        .height(layout::PANEL_HEIGHT.px())
+        .height(height.px())
         .padding(layout::CONTENT_PADDING.px())
         .background_color(crate::theme::panel::BACKGROUND)
         .border_color(crate::theme::panel::OUTLINE)
@@ -233,8 +368,13 @@ fn build_panel_container<State: 'static>(
 /// │  │      │  ┌───┐ ┌───┐  │
 /// │  │      │  │ W │ │ H │  │
 /// │  └──────┘  └───┘ └───┘  │
+/// │  In  ┌───┐ ┌───┐ ┌───┐  │  (single on-curve point:
+/// │  Out ┌───┐ ┌───┐ ┌───┐  │   length, angle, tension)
 /// └─────────────────────────┘
 /// ```
+///
+/// A selected component gets offset, scale and rotation/skew rows in
+/// place of the handle rows.
 pub fn coordinate_panel<State: 'static, F, G>(
     session: Arc<crate::editing::EditSession>,
     on_session_update: F,
@@ -246,16 +386,34 @@ where
 {
     // Step 1: Prepare coordinate data (clone strings for use in closures)
     let coord_data = prepare_coordinate_data(&session.coord_selection);
+    let handles = session.selected_handles();
+    let component = session.selected_component_transform();
 
     // Step 2: Build the quadrant selector
     let quadrant_selector = build_quadrant_selector(session, on_session_update);
 
     // Step 3: Build the coordinate input fields
-    let on_coord_change = Arc::new(on_coord_change);
-    let coordinate_inputs = build_coordinate_inputs::<State>(coord_data.clone(), on_coord_change);
+    let on_coord_change: Arc<dyn Fn(&mut State, CoordField, String) + Send + Sync> =
+        Arc::new(on_coord_change);
+    let coordinate_inputs =
+        build_coordinate_inputs::<State>(coord_data.clone(), Arc::clone(&on_coord_change));
+
+    // Step 4: Handle rows for a single on-curve point, or transform
+    // rows for a selected component
+    let (extra_inputs, extra_height) = match (handles, component) {
+        (Some(info), _) => (
+            Some(Either::A(build_handle_inputs(info, on_coord_change))),
+            layout::HANDLE_ROWS_HEIGHT,
+        ),
+        (None, Some(values)) => (
+            Some(Either::B(build_component_inputs(values, on_coord_change))),
+            layout::COMPONENT_ROWS_HEIGHT,
+        ),
+        (None, None) => (None, 0.0),
+    };
 
-    // Step 4: Assemble the final panel
-    build_panel_container(quadrant_selector, coordinate_inputs)
+    // Step 5: Assemble the final panel
+    build_panel_container(quadrant_selector, coordinate_inputs, extra_inputs, extra_height)
 }
 
 // ============================================================================
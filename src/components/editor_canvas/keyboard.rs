@@ -118,6 +118,10 @@ impl EditorWidget {
             return true;
         }
 
+        if self.handle_selection_commands(ctx, cmd, shift, key) {
+            return true;
+        }
+
         if self.handle_ctrl_space_toggle(ctx, ctrl, key) {
             return true;
         }
@@ -162,7 +166,11 @@ impl EditorWidget {
             return true;
         }
 
-        if self.handle_import_image(ctx, cmd, key) {
+        if self.handle_contour_ops(ctx, cmd, shift, key) {
+            return true;
+        }
+
+        if self.handle_import_image(ctx, cmd, shift, key) {
             return true;
         }
 
@@ -288,6 +296,47 @@ impl EditorWidget {
         true
     }
 
+    /// Selection commands:
+    /// - Cmd+Shift+A: select all points of the same type
+    /// - Cmd+Shift+I: invert selection
+    /// - ] / [: grow / shrink the selection along its contours
+    /// - Cmd+] / Cmd+[: select the next / previous contour
+    fn handle_selection_commands(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        cmd: bool,
+        shift: bool,
+        key: &masonry::core::keyboard::Key,
+    ) -> bool {
+        use masonry::core::keyboard::Key;
+
+        if self.session.text_mode_active {
+            return false;
+        }
+
+        let Key::Character(c) = key else {
+            return false;
+        };
+
+        let handled = match c.as_str() {
+            "a" | "A" if cmd && shift => self.session.select_same_type(),
+            "i" | "I" if cmd && shift => self.session.invert_selection(),
+            "]" if cmd && !shift => self.session.select_adjacent_contour(true),
+            "[" if cmd && !shift => self.session.select_adjacent_contour(false),
+            "]" if !cmd && !shift => self.session.grow_selection(),
+            "[" if !cmd && !shift => self.session.shrink_selection(),
+            _ => return false,
+        };
+
+        if handled {
+            self.session.update_coord_selection();
+            self.emit_session_update(ctx, false);
+            ctx.request_render();
+        }
+        ctx.set_handled();
+        true
+    }
+
     fn handle_ctrl_space_toggle(
         &mut self,
         ctx: &mut EventCtx<'_>,
@@ -855,6 +904,47 @@ impl EditorWidget {
         true
     }
 
+    /// Structural contour operations:
+    /// - B: break contours at the selected points
+    /// - J: join the two selected endpoints
+    /// - Shift+J: close the selected open contours
+    /// - L: turn the selected curve segments into lines
+    fn handle_contour_ops(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        cmd: bool,
+        shift: bool,
+        key: &masonry::core::keyboard::Key,
+    ) -> bool {
+        use masonry::core::keyboard::Key;
+
+        if cmd || self.session.text_mode_active {
+            return false;
+        }
+
+        let Key::Character(c) = key else {
+            return false;
+        };
+
+        let changed = match c.as_str() {
+            "b" if !shift => self.session.break_contours_at_selection(),
+            "j" if !shift => self.session.join_selected_endpoints(),
+            "J" | "j" if shift => self.session.close_selected_contours(),
+            "l" if !shift => self.session.convert_selection_to_lines(),
+            _ => return false,
+        };
+
+        if changed {
+            self.record_edit(EditType::Normal);
+            self.session.sync_to_workspace();
+            self.session.update_coord_selection();
+            self.emit_session_update(ctx, false);
+            ctx.request_render();
+        }
+        ctx.set_handled();
+        true
+    }
+
     /// Cmd+I: Import a background image via file dialog.
     ///
     /// This is a workaround for the lack of drag-and-drop support in
@@ -867,11 +957,13 @@ impl EditorWidget {
         &mut self,
         ctx: &mut EventCtx<'_>,
         cmd: bool,
+        shift: bool,
         key: &masonry::core::keyboard::Key,
     ) -> bool {
         use masonry::core::keyboard::Key;
 
-        if !cmd {
+        // Cmd+Shift+I inverts the selection
+        if !cmd || shift {
             return false;
         }
 
@@ -1125,7 +1217,7 @@ impl EditorWidget {
             return false;
         }
 
-        if self.session.selection.is_empty() {
+        if self.session.selection.is_empty() && self.session.selected_component.is_none() {
             return false;
         }
 
@@ -1157,7 +1249,7 @@ impl EditorWidget {
             return false;
         }
 
-        if self.session.selection.is_empty() {
+        if self.session.selection.is_empty() && self.session.selected_component.is_none() {
             return false;
         }
 
@@ -1197,7 +1289,7 @@ impl EditorWidget {
             return false;
         };
 
-        if self.session.selection.is_empty() {
+        if self.session.selection.is_empty() && self.session.selected_component.is_none() {
             return false;
         }
 
@@ -1252,7 +1344,8 @@ impl EditorWidget {
         true
     }
 
-    /// Cmd+Shift+O: Remove overlap (union all contours)
+    /// Cmd+Shift+O: Remove overlap (union the selected contours, or
+    /// all contours and components when nothing is selected)
     fn handle_remove_overlap(
         &mut self,
         ctx: &mut EventCtx<'_>,
@@ -1275,6 +1368,7 @@ impl EditorWidget {
         );
         self.record_edit(EditType::Normal);
         self.session.sync_to_workspace();
+        self.session.update_coord_selection();
         self.emit_session_update(ctx, false);
         ctx.request_render();
         ctx.set_handled();
@@ -6,7 +6,9 @@
 mod drawing;
 mod keyboard;
 mod paint;
+mod paragraph;
 mod pointer;
+mod spacing;
 mod text_buffer;
 mod view;
 
@@ -89,6 +91,9 @@ pub struct EditorWidget {
     /// Current horizontal offset from start position during kern drag
     pub(super) kern_current_offset: f64,
 
+    /// Sidebearing drag in spacing mode
+    pub(super) spacing_drag: Option<spacing::SpacingDrag>,
+
     /// Clipboard for copied paths (contours with selected points)
     pub(super) point_clipboard: Option<Vec<Path>>,
 
@@ -180,6 +185,7 @@ impl EditorWidget {
             kern_start_x: 0.0,
             kern_original_value: 0.0,
             kern_current_offset: 0.0,
+            spacing_drag: None,
             point_clipboard: None,
             dragging_image: false,
             image_drag_origin: Point::ZERO,
@@ -193,6 +199,25 @@ impl EditorWidget {
         }
     }
 
+    /// Take over a session updated outside the widget (e.g. a tool
+    /// change), keeping the viewport to avoid re-initialization and
+    /// flickering
+    pub(super) fn set_session(&mut self, session: &EditSession) {
+        let old_viewport = self.session.viewport.clone();
+        let old_viewport_initialized = self.session.viewport_initialized;
+
+        // Undoing past a reload would bring back outlines the
+        // workspace no longer has
+        if session.reload_generation != self.session.reload_generation {
+            self.undo = UndoState::new();
+            self.last_edit_type = None;
+        }
+        self.session = session.clone();
+
+        self.session.viewport = old_viewport;
+        self.session.viewport_initialized = old_viewport_initialized;
+    }
+
     /// Record an edit operation for undo
     ///
     /// This manages undo grouping:
@@ -217,7 +242,9 @@ impl EditorWidget {
     /// Undo the last edit
     pub(super) fn undo(&mut self) {
         if let Some(previous) = self.undo.undo(self.session.clone()) {
+            let replayed = std::mem::take(&mut self.session.replayed_sidebearing_edits);
             self.session = previous;
+            self.session.sync_replayed_sidebearings(&replayed);
             tracing::debug!("Undo: restored previous state");
         }
     }
@@ -225,7 +252,9 @@ impl EditorWidget {
     /// Redo the last undone edit
     pub(super) fn redo(&mut self) {
         if let Some(next) = self.undo.redo(self.session.clone()) {
+            let replayed = std::mem::take(&mut self.session.replayed_sidebearing_edits);
             self.session = next;
+            self.session.sync_replayed_sidebearings(&replayed);
             tracing::debug!("Redo: restored next state");
         }
     }
@@ -502,6 +531,22 @@ impl Widget for EditorWidget {
                 }
             }
 
+            // Handle alt key for centered shapes and cut-and-separate knife
+            if let Key::Named(masonry::core::keyboard::NamedKey::Alt) = key_event.key {
+                let alt_pressed = key_event.state == KeyState::Down;
+                match &mut self.session.current_tool {
+                    crate::tools::ToolBox::Shapes(shapes_tool) => {
+                        shapes_tool.set_centered(alt_pressed);
+                        ctx.request_render();
+                    }
+                    crate::tools::ToolBox::Knife(knife_tool) => {
+                        knife_tool.set_separate(alt_pressed);
+                        ctx.request_render();
+                    }
+                    _ => {}
+                }
+            }
+
             // Handle spacebar for temporary preview mode
             if self.handle_spacebar(ctx, key_event) {
                 return;
@@ -533,6 +578,12 @@ impl Widget for EditorWidget {
                 return;
             }
 
+            // In spacing mode, arrows nudge the highlighted sidebearing
+            // instead of moving the cursor
+            if !cmd && self.handle_spacing_keys(ctx, &key_event.key, shift) {
+                return;
+            }
+
             // Phase 5: Handle text mode input (character typing, cursor movement)
             // Only handle after shortcuts, and only if no modifiers (except shift for caps)
             if self.session.text_mode_active
@@ -570,3 +621,75 @@ impl Widget for EditorWidget {
         ChildrenIds::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::AppState;
+    use crate::editing::compat::check_compat;
+    use crate::editing::normalize::DirectionConvention;
+    use crate::model::designspace::DesignspaceProject;
+    use crate::model::read_workspace;
+    use crate::model::workspace::{Contour, Glyph, Workspace};
+
+    /// A triangle right of a square: normalizing swaps them
+    fn master(size: f64) -> Workspace {
+        let mut glyph = Glyph::for_test("A", 600.0);
+        glyph.contours = vec![
+            Contour::for_test(&[(300.0, 0.0), (300.0 + size, 0.0), (300.0, size)]),
+            Contour::for_test(&[(0.0, 0.0), (0.0, size), (size, size), (size, 0.0)]),
+        ];
+        Workspace::from_glyphs([glyph])
+    }
+
+    #[test]
+    fn undo_after_normalizing_keeps_masters_compatible() {
+        let mut state = AppState::new();
+        state.designspace = Some(DesignspaceProject::from_workspaces(vec![
+            ("Light", master(100.0)),
+            ("Bold", master(200.0)),
+        ]));
+        state.open_editor("A".to_string());
+        let mut widget = EditorWidget::new(Arc::new(state.editor_session.clone().unwrap()));
+        // An edit made before normalizing
+        widget.record_edit(EditType::Normal);
+
+        state.editor_session = Some(widget.session.clone());
+        state.normalize_contours_in_font(DirectionConvention::PostScript);
+        widget.set_session(state.editor_session.as_ref().unwrap());
+        widget.undo();
+        widget.session.sync_to_workspace();
+
+        let ds = state.designspace.as_ref().unwrap();
+        let light = read_workspace(&ds.masters[0].workspace).glyphs["A"].clone();
+        let bold = vec![("Bold".to_string(), Arc::clone(&ds.masters[1].workspace))];
+        assert!(check_compat("A", &light, &bold).is_empty());
+    }
+
+    #[test]
+    fn undo_after_making_a_component_keeps_masters_compatible() {
+        let mut state = AppState::new();
+        state.designspace = Some(DesignspaceProject::from_workspaces(vec![
+            ("Light", master(100.0)),
+            ("Bold", master(200.0)),
+        ]));
+        state.open_editor("A".to_string());
+        let mut widget = EditorWidget::new(Arc::new(state.editor_session.clone().unwrap()));
+        // Select the triangle
+        let point = widget.session.paths[0].points().iter().next().unwrap().id;
+        widget.session.selection.insert(point);
+        widget.record_edit(EditType::Normal);
+
+        state.editor_session = Some(widget.session.clone());
+        state.make_component_from_selection();
+        widget.set_session(state.editor_session.as_ref().unwrap());
+        widget.undo();
+        widget.session.sync_to_workspace();
+
+        let ds = state.designspace.as_ref().unwrap();
+        let light = read_workspace(&ds.masters[0].workspace).glyphs["A"].clone();
+        assert_eq!(light.components.len(), 1);
+        let bold = vec![("Bold".to_string(), Arc::clone(&ds.masters[1].workspace))];
+        assert!(check_compat("A", &light, &bold).is_empty());
+    }
+}
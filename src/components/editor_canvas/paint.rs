@@ -41,6 +41,10 @@ impl EditorWidget {
         transform: &Affine,
         is_preview_mode: bool,
     ) {
+        if self.paragraph_active() {
+            self.paint_paragraph(painter);
+            return;
+        }
         if !is_preview_mode {
             draw_design_grid(painter, &self.session, self.size);
         }
@@ -59,6 +63,7 @@ impl EditorWidget {
         }
 
         if !is_preview_mode {
+            self.paint_spacing_handles(painter, transform);
             self.paint_tool_overlay(painter, transform);
         }
     }
@@ -0,0 +1,100 @@
+// Copyright 2026 the Runebender Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Paragraph mode for EditorWidget: the text buffer set as wrapped
+//! paragraphs, or as a waterfall of sizes, for proofing
+//!
+//! Text is set at its point size in screen pixels, so it reads the way
+//! it will in use; the viewport's zoom and pan don't apply. The buffer
+//! can still be typed into, but sorts can't be picked or spaced by
+//! pointer while the mode is on.
+
+use std::collections::HashMap;
+
+use super::EditorWidget;
+use crate::model::glyph_renderer::glyph_to_bezpath_with_components;
+use crate::model::read_workspace;
+use crate::sort::paragraph::{WATERFALL_SIZES, layout_paragraph};
+use crate::theme;
+use kurbo::{Affine, BezPath, Line, Point, Stroke};
+use masonry::imaging::Painter;
+use masonry::peniko::Brush;
+
+/// Distance from the canvas's left edge to the start of the lines
+const LEFT_MARGIN: f64 = 48.0;
+
+/// Distance from the canvas's top edge to the first line, clear of
+/// the toolbars
+const TOP_MARGIN: f64 = 96.0;
+
+impl EditorWidget {
+    /// Whether the text buffer is set as paragraphs. Paragraphs go
+    /// with the text tool, whose toolbar turns them on and off.
+    pub(super) fn paragraph_active(&self) -> bool {
+        self.session.paragraph.enabled
+            && self.session.text_buffer.is_some()
+            && self.session.current_tool.id() == crate::tools::ToolId::Text
+    }
+
+    /// Paint the text buffer as paragraphs, or the first line of it at
+    /// each waterfall size
+    pub(super) fn paint_paragraph(&self, painter: &mut Painter<'_>) {
+        let (Some(buffer), Some(workspace_arc)) =
+            (&self.session.text_buffer, &self.session.workspace)
+        else {
+            return;
+        };
+        let workspace = read_workspace(workspace_arc);
+        let settings = self.session.paragraph;
+        let rtl = self.session.text_direction.is_rtl();
+        let upm = self.session.units_per_em;
+        let kern = |first: &str, second: &str| workspace.pair_kerning(first, second);
+
+        // The measure: where lines start and how far they may run
+        let bottom = self.size.height;
+        for x in [LEFT_MARGIN, LEFT_MARGIN + settings.line_width] {
+            let guide = Line::new(Point::new(x, TOP_MARGIN), Point::new(x, bottom));
+            painter
+                .stroke(
+                    &guide,
+                    &Stroke::new(1.0),
+                    &Brush::Solid(theme::metrics::GUIDE),
+                )
+                .draw();
+        }
+
+        let sizes = if settings.waterfall {
+            WATERFALL_SIZES.to_vec()
+        } else {
+            vec![settings.point_size]
+        };
+        let fill = Brush::Solid(theme::path::PREVIEW_FILL);
+        let mut outlines: HashMap<String, BezPath> = HashMap::new();
+        let mut top = TOP_MARGIN;
+        for size in sizes {
+            let scale = size / upm;
+            let mut lines = layout_paragraph(buffer, settings.line_width / scale, rtl, &kern);
+            if settings.waterfall {
+                lines.truncate(1);
+            }
+            for line in lines {
+                if top > bottom {
+                    return;
+                }
+                let baseline = top + self.session.ascender * scale;
+                for glyph in &line.glyphs {
+                    let outline = outlines.entry(glyph.name.clone()).or_insert_with(|| {
+                        workspace
+                            .get_glyph(&glyph.name)
+                            .map(|g| glyph_to_bezpath_with_components(g, &workspace))
+                            .unwrap_or_default()
+                    });
+                    let transform = Affine::translate((LEFT_MARGIN + glyph.x * scale, baseline))
+                        * Affine::scale_non_uniform(scale, -scale);
+                    painter.fill(&(transform * &*outline), &fill).draw();
+                }
+                top += size * settings.line_spacing;
+            }
+        }
+    }
+}
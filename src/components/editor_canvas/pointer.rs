@@ -46,6 +46,10 @@ impl EditorWidget {
             return;
         }
 
+        if self.handle_spacing_pointer_down(ctx, design_pos) {
+            return;
+        }
+
         // Skip image interaction when in preview/pan mode
         if !self.is_preview_mode()
             && self.handle_image_pointer_down(ctx, design_pos)
@@ -78,6 +82,10 @@ impl EditorWidget {
             return true;
         }
 
+        if self.handle_segment_double_click(ctx, local_pos) {
+            return true;
+        }
+
         if self.handle_component_double_click(ctx, local_pos) {
             return true;
         }
@@ -114,6 +122,26 @@ impl EditorWidget {
         true
     }
 
+    /// Double-click on a segment selects its whole contour
+    fn handle_segment_double_click(&mut self, ctx: &mut EventCtx<'_>, local_pos: Point) -> bool {
+        if self.session.current_tool.id() != crate::tools::ToolId::Select {
+            return false;
+        }
+
+        let Some((seg_info, _t)) = self.session.hit_test_segments(local_pos, 10.0) else {
+            return false;
+        };
+
+        if !self.session.select_contour(seg_info.path_index) {
+            return false;
+        }
+
+        self.session.update_coord_selection();
+        self.emit_session_update(ctx, false);
+        ctx.request_render();
+        true
+    }
+
     /// Option/Alt + click on a segment: convert line → curve
     fn handle_option_click_segment(
         &mut self,
@@ -306,6 +334,15 @@ impl EditorWidget {
             return;
         }
 
+        if self.spacing_drag.is_some() {
+            self.handle_spacing_drag(ctx, local_pos);
+            return;
+        }
+
+        if self.spacing_active() {
+            self.handle_spacing_hover(ctx, local_pos);
+        }
+
         if self.resizing_handle.is_some() {
             self.handle_image_resize(ctx, local_pos);
             return;
@@ -606,7 +643,9 @@ impl EditorWidget {
     fn maybe_request_render(&self, ctx: &mut EventCtx<'_>) {
         use crate::tools::ToolId;
 
-        let needs_render = ctx.is_active() || self.session.current_tool.id() == ToolId::Pen;
+        // Pen and measure tools paint hover feedback
+        let tool_id = self.session.current_tool.id();
+        let needs_render = ctx.is_active() || matches!(tool_id, ToolId::Pen | ToolId::Measure);
         if needs_render {
             ctx.request_render();
         }
@@ -639,6 +678,11 @@ impl EditorWidget {
             return;
         }
 
+        if self.spacing_drag.is_some() {
+            self.handle_spacing_release(ctx);
+            return;
+        }
+
         if self.resizing_handle.is_some() {
             self.resizing_handle = None;
             self.emit_session_update(ctx, false);
@@ -811,6 +855,10 @@ impl EditorWidget {
     ///
     /// Returns the index of the sort, or None if no sort was clicked
     fn find_sort_at_position(&self, position: Point) -> Option<usize> {
+        // Paragraphs are set apart from the sorts' own layout
+        if self.paragraph_active() {
+            return None;
+        }
         let buffer = self.session.text_buffer.as_ref()?;
 
         // Check text direction for RTL support
@@ -915,7 +963,7 @@ impl EditorWidget {
     ///
     /// This loads the sort's paths into session.paths, updates the active_sort_* fields,
     /// and sets the is_active flag in the buffer.
-    fn activate_sort(&mut self, sort_index: usize) {
+    pub(super) fn activate_sort(&mut self, sort_index: usize) {
         // Calculate RTL info early, before mutable borrow of buffer
         let is_rtl = self.session.text_direction.is_rtl();
         let total_width = if is_rtl {
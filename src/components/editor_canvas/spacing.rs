@@ -0,0 +1,226 @@
+// Copyright 2026 the Runebender Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Spacing mode for EditorWidget: sidebearing handles on the sorts of
+//! the text buffer
+//!
+//! Hovering a sort shows its edges, with the nearer one highlighted.
+//! Dragging that edge (or nudging it with the arrow keys) changes the
+//! glyph's sidebearing; every sort of the glyph follows, since they
+//! all read the glyph from the workspace. With spacing on every master,
+//! each change is also sent to the app as a `SidebearingEdit` to replay
+//! on the other masters, and undo sends the changes it takes back.
+
+use super::EditorWidget;
+use super::text_buffer::SortPlacement;
+use crate::editing::EditType;
+use crate::editing::session::{SidebearingSide, SpacingHandle};
+use crate::{settings, theme};
+use kurbo::{Affine, Line, Point, Stroke};
+use masonry::core::EventCtx;
+use masonry::imaging::Painter;
+use masonry::peniko::Brush;
+
+/// A sidebearing drag in progress
+#[derive(Debug, Clone, Copy)]
+pub(super) struct SpacingDrag {
+    side: SidebearingSide,
+    /// Pointer x where the drag began, in design space
+    start_x: f64,
+    /// Whole units the edge has moved so far
+    moved: f64,
+}
+
+impl EditorWidget {
+    /// Whether the pointer and arrow keys edit sidebearings
+    pub(super) fn spacing_active(&self) -> bool {
+        self.session.spacing_mode && self.session.text_mode_active && !self.paragraph_active()
+    }
+
+    /// The sidebearing handle at a design-space position: the nearer
+    /// edge of the sort under the pointer, if it is close enough
+    fn spacing_handle_at(&self, pos: Point) -> Option<SpacingHandle> {
+        let tolerance = settings::spacing::HANDLE_DISTANCE / self.session.viewport.zoom;
+        let (ascender, descender) = (self.session.ascender, self.session.descender);
+        let placements = self.sort_placements();
+        let in_line = |p: &&SortPlacement| {
+            pos.y >= p.baseline_y + descender && pos.y <= p.baseline_y + ascender
+        };
+        let edges = |p: &SortPlacement| {
+            [
+                (SidebearingSide::Left, p.x),
+                (SidebearingSide::Right, p.x + p.advance_width),
+            ]
+            .map(|(side, x)| (p.index, side, (pos.x - x).abs()))
+        };
+
+        // The sort the pointer is over decides between two edges that
+        // touch; outside every sort, the nearest edge in the line
+        let under = placements
+            .iter()
+            .filter(in_line)
+            .find(|p| pos.x >= p.x && pos.x <= p.x + p.advance_width);
+        let candidates: Vec<_> = match under {
+            Some(p) => edges(p).to_vec(),
+            None => placements.iter().filter(in_line).flat_map(edges).collect(),
+        };
+        let (sort_index, side, distance) =
+            candidates.into_iter().min_by(|a, b| a.2.total_cmp(&b.2))?;
+        (distance <= tolerance).then_some(SpacingHandle { sort_index, side })
+    }
+
+    /// Highlight the handle under the pointer
+    pub(super) fn handle_spacing_hover(&mut self, ctx: &mut EventCtx<'_>, local_pos: Point) {
+        let design_pos = self.session.viewport.screen_to_design(local_pos);
+        let handle = self.spacing_handle_at(design_pos);
+        // The last handle stays for arrow-key nudges
+        if handle.is_some() && handle != self.session.spacing_handle {
+            self.session.spacing_handle = handle;
+            self.emit_session_update(ctx, false);
+            ctx.request_render();
+        }
+    }
+
+    /// Start dragging the sidebearing under the pointer
+    pub(super) fn handle_spacing_pointer_down(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        design_pos: Point,
+    ) -> bool {
+        if !self.spacing_active() {
+            return false;
+        }
+        let Some(handle) = self.spacing_handle_at(design_pos) else {
+            return false;
+        };
+
+        self.activate_sort(handle.sort_index);
+        self.session.spacing_handle = Some(handle);
+        self.spacing_drag = Some(SpacingDrag {
+            side: handle.side,
+            start_x: design_pos.x,
+            moved: 0.0,
+        });
+        self.emit_session_update(ctx, false);
+        ctx.request_render();
+        true
+    }
+
+    /// Follow the pointer with the dragged edge, in whole units
+    pub(super) fn handle_spacing_drag(&mut self, ctx: &mut EventCtx<'_>, local_pos: Point) {
+        let Some(drag) = &mut self.spacing_drag else {
+            return;
+        };
+        let design_pos = self.session.viewport.screen_to_design(local_pos);
+        let moved = (design_pos.x - drag.start_x).round();
+        let step = moved - drag.moved;
+        drag.moved = moved;
+        let side = drag.side;
+
+        self.move_sidebearing_edge(ctx, side, step);
+    }
+
+    pub(super) fn handle_spacing_release(&mut self, ctx: &mut EventCtx<'_>) {
+        if let Some(drag) = self.spacing_drag.take() {
+            tracing::info!("Spacing drag moved the {:?} edge {}", drag.side, drag.moved);
+            if drag.moved != 0.0 {
+                self.record_edit(EditType::Normal);
+            }
+        }
+        ctx.release_pointer();
+        ctx.request_render();
+    }
+
+    /// Arrow keys nudge the highlighted edge. Returns true if the key
+    /// was handled.
+    pub(super) fn handle_spacing_keys(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        key: &masonry::core::keyboard::Key,
+        shift: bool,
+    ) -> bool {
+        use masonry::core::keyboard::{Key, NamedKey};
+
+        if !self.spacing_active() {
+            return false;
+        }
+        let Some(handle) = self.session.spacing_handle else {
+            return false;
+        };
+        let direction = match key {
+            Key::Named(NamedKey::ArrowLeft) => -1.0,
+            Key::Named(NamedKey::ArrowRight) => 1.0,
+            _ => return false,
+        };
+        let amount = if shift {
+            settings::spacing::NUDGE_SHIFT
+        } else {
+            settings::spacing::NUDGE
+        };
+
+        if self.session.active_sort_index != Some(handle.sort_index) {
+            self.activate_sort(handle.sort_index);
+        }
+        self.move_sidebearing_edge(ctx, handle.side, direction * amount);
+        self.record_edit(EditType::Drag);
+        ctx.set_handled();
+        true
+    }
+
+    /// Move an edge of the active sort by `dx`: a left edge moving
+    /// left widens the left sidebearing, a right edge moving right
+    /// widens the right one
+    fn move_sidebearing_edge(&mut self, ctx: &mut EventCtx<'_>, side: SidebearingSide, dx: f64) {
+        let delta = match side {
+            SidebearingSide::Left => -dx,
+            SidebearingSide::Right => dx,
+        };
+        if !self.session.adjust_sidebearing(side, delta) {
+            return;
+        }
+        self.emit_session_update(ctx, false);
+        // The app has the edits now; don't send them twice
+        self.session.sidebearing_edits.clear();
+        ctx.request_render();
+    }
+
+    /// Paint the edges of the sort with the highlighted handle
+    pub(super) fn paint_spacing_handles(&self, painter: &mut Painter<'_>, transform: &Affine) {
+        if !self.spacing_active() {
+            return;
+        }
+        let Some(handle) = self.session.spacing_handle else {
+            return;
+        };
+        let Some(placement) = self
+            .sort_placements()
+            .into_iter()
+            .find(|p| p.index == handle.sort_index)
+        else {
+            return;
+        };
+
+        let bottom = placement.baseline_y + self.session.descender;
+        let top = placement.baseline_y + self.session.ascender;
+        for (side, x) in [
+            (SidebearingSide::Left, placement.x),
+            (
+                SidebearingSide::Right,
+                placement.x + placement.advance_width,
+            ),
+        ] {
+            let (color, width) = if side == handle.side {
+                (theme::spacing::HANDLE, theme::size::METRIC_LINE_WIDTH * 2.0)
+            } else {
+                (theme::spacing::EDGE, theme::size::METRIC_LINE_WIDTH)
+            };
+            let edge = Line::new(
+                *transform * Point::new(x, bottom),
+                *transform * Point::new(x, top),
+            );
+            painter
+                .stroke(&edge, &Stroke::new(width), &Brush::Solid(color))
+                .draw();
+        }
+    }
+}
@@ -10,164 +10,35 @@ use kurbo::{Affine, Point, Stroke};
 use masonry::imaging::Painter;
 use masonry::peniko::Brush;
 
+/// Where a glyph sort is set in the text buffer, in design space
+pub(super) struct SortPlacement {
+    pub index: usize,
+    pub name: String,
+    /// Left edge of the sort
+    pub x: f64,
+    pub baseline_y: f64,
+    pub advance_width: f64,
+    pub is_active: bool,
+}
+
 impl EditorWidget {
     /// Render the text buffer with multiple sorts (Phase 3)
     ///
-    /// This renders all sorts in the text buffer, laying them out horizontally
-    /// with correct spacing based on advance widths.
+    /// This renders all sorts in the text buffer, laid out by
+    /// `sort_placements`.
     pub(super) fn render_text_buffer(
         &self,
         painter: &mut Painter<'_>,
         transform: &Affine,
         is_preview_mode: bool,
     ) {
-        let buffer = match &self.session.text_buffer {
-            Some(buf) => buf,
-            None => return,
-        };
-
-        // Check text direction for RTL support
-        let is_rtl = self.session.text_direction.is_rtl();
-
-        // For RTL: calculate total width first so we can start from the right
-        let total_width = if is_rtl {
-            self.calculate_buffer_width()
-        } else {
-            0.0 // Not needed for LTR
-        };
-
-        // Starting position: LTR starts at 0, RTL starts at total_width
-        let mut x_offset = if is_rtl { total_width } else { 0.0 };
-        let mut baseline_y = 0.0;
-        let cursor_position = buffer.cursor();
-
-        // UPM height for line spacing (top of UPM on new line aligns with bottom of descender on previous line)
-        let upm_height = self.session.ascender - self.session.descender;
-
-        // Phase 6: Calculate cursor position while rendering sorts
-        let mut cursor_x = x_offset;
-        let mut cursor_y = 0.0;
-
-        // Track previous glyph for kerning lookup
-        let mut prev_glyph_name: Option<String> = None;
-        let mut prev_glyph_group: Option<String> = None;
-
-        tracing::debug!(
-            "[Cursor] buffer.len()={}, cursor_position={}, is_rtl={}",
-            buffer.len(),
-            cursor_position,
-            is_rtl
-        );
-
-        // Cursor at position 0 (before any sorts)
-        if cursor_position == 0 {
-            cursor_x = x_offset;
-            cursor_y = baseline_y;
-            tracing::debug!("[Cursor] Position 0: ({}, {})", cursor_x, cursor_y);
+        if self.session.text_buffer.is_none() {
+            return;
         }
 
-        // Collect sort render data for two-pass rendering
+        // Collected up front for two-pass rendering
         // (First pass: metrics behind, second pass: glyphs on top)
-        struct SortRenderData {
-            index: usize,
-            name: String,
-            x_offset: f64,
-            baseline_y: f64,
-            advance_width: f64,
-            is_active: bool,
-        }
-        let mut sort_render_data: Vec<SortRenderData> = Vec::new();
-
-        for (index, sort) in buffer.iter().enumerate() {
-            match &sort.kind {
-                crate::sort::SortKind::Glyph {
-                    name,
-                    advance_width,
-                    ..
-                } => {
-                    // For RTL: move x left BEFORE drawing this glyph
-                    if is_rtl {
-                        x_offset -= advance_width;
-                    }
-
-                    // Apply kerning if we have a previous glyph
-                    if let Some(prev_name) = &prev_glyph_name
-                        && let Some(workspace_arc) = &self.session.workspace
-                    {
-                        let workspace = read_workspace(workspace_arc);
-
-                        // Get current glyph's left kerning group
-                        let curr_group = workspace
-                            .get_glyph(name)
-                            .and_then(|g| g.left_group.as_deref());
-
-                        // Look up kerning value
-                        let kern_value = crate::model::kerning::lookup_kerning(
-                            &workspace.kerning,
-                            &workspace.groups,
-                            prev_name,
-                            prev_glyph_group.as_deref(),
-                            name,
-                            curr_group,
-                        );
-
-                        if is_rtl {
-                            x_offset -= kern_value; // RTL: kerning moves left
-                        } else {
-                            x_offset += kern_value;
-                        }
-                    }
-
-                    // Store data for two-pass rendering
-                    sort_render_data.push(SortRenderData {
-                        index,
-                        name: name.clone(),
-                        x_offset,
-                        baseline_y,
-                        advance_width: *advance_width,
-                        is_active: sort.is_active,
-                    });
-
-                    // For LTR: advance x forward AFTER processing
-                    if !is_rtl {
-                        x_offset += advance_width;
-                    }
-                    // (For RTL, we already moved x_offset before drawing)
-
-                    // Update previous glyph info for next iteration
-                    prev_glyph_name = Some(name.clone());
-                    if let Some(workspace_arc) = &self.session.workspace {
-                        let workspace = read_workspace(workspace_arc);
-                        prev_glyph_group = workspace
-                            .get_glyph(name)
-                            .and_then(|g| g.right_group.clone());
-                    }
-                }
-                crate::sort::SortKind::LineBreak => {
-                    // Line break: reset x, move y down by UPM height
-                    // Top of UPM on new line aligns with bottom of descender on previous line
-                    x_offset = if is_rtl { total_width } else { 0.0 };
-                    baseline_y -= upm_height;
-
-                    // Reset kerning tracking (no kerning across lines)
-                    prev_glyph_name = None;
-                    prev_glyph_group = None;
-                }
-            }
-
-            // Track cursor position AFTER processing this sort
-            // The cursor is positioned after the sort at this index
-            if index + 1 == cursor_position {
-                cursor_x = x_offset;
-                cursor_y = baseline_y;
-                tracing::debug!(
-                    "[Cursor] After sort {}: ({}, {})",
-                    index,
-                    cursor_x,
-                    cursor_y
-                );
-            }
-        }
+        let sort_render_data = self.sort_placements();
 
         // PASS 1: Render all metrics FIRST (behind glyphs)
         if !is_preview_mode {
@@ -191,7 +62,7 @@ impl EditorWidget {
                     // Text mode: minimal metrics for all sorts
                     self.render_sort_minimal_metrics(
                         painter,
-                        data.x_offset,
+                        data.x,
                         data.baseline_y,
                         data.advance_width,
                         transform,
@@ -201,7 +72,7 @@ impl EditorWidget {
                     // Non-text mode: full metrics only for active sort
                     self.render_sort_metrics(
                         painter,
-                        data.x_offset,
+                        data.x,
                         data.baseline_y,
                         data.advance_width,
                         transform,
@@ -210,7 +81,7 @@ impl EditorWidget {
                     // Non-text mode: minimal metrics for inactive sorts
                     self.render_sort_minimal_metrics(
                         painter,
-                        data.x_offset,
+                        data.x,
                         data.baseline_y,
                         data.advance_width,
                         transform,
@@ -222,7 +93,7 @@ impl EditorWidget {
 
         // PASS 2: Render all glyphs SECOND (on top of metrics)
         for data in &sort_render_data {
-            let sort_position = Point::new(data.x_offset, data.baseline_y);
+            let sort_position = Point::new(data.x, data.baseline_y);
 
             if data.is_active && !is_preview_mode && !self.session.text_mode_active {
                 // Non-text mode: render active sort with control points (editable)
@@ -233,18 +104,12 @@ impl EditorWidget {
             }
         }
 
-        // Cursor might be at the end of the buffer (after all sorts)
-        if cursor_position >= buffer.len() {
-            cursor_x = x_offset;
-            cursor_y = baseline_y;
-            tracing::debug!("[Cursor] At end: ({}, {})", cursor_x, cursor_y);
-        }
-
-        tracing::debug!("[Cursor] Final position: ({}, {})", cursor_x, cursor_y);
+        let cursor = self.text_cursor_position(&sort_render_data);
+        tracing::debug!("[Cursor] Final position: ({}, {})", cursor.x, cursor.y);
 
         // Phase 6: Render cursor in text mode (not in preview mode)
         if !is_preview_mode {
-            self.render_text_cursor(painter, cursor_x, cursor_y, transform);
+            self.render_text_cursor(painter, cursor.x, cursor.y, transform);
         }
     }
 
@@ -266,6 +131,94 @@ impl EditorWidget {
         total_width
     }
 
+    /// Where each glyph sort of the text buffer is set, kerning and
+    /// RTL included. Everything drawn or hit-tested over the buffer
+    /// follows this layout.
+    pub(super) fn sort_placements(&self) -> Vec<SortPlacement> {
+        let Some(buffer) = &self.session.text_buffer else {
+            return Vec::new();
+        };
+        let workspace = self.session.workspace.as_ref().map(read_workspace);
+        let is_rtl = self.session.text_direction.is_rtl();
+        let line_start = if is_rtl {
+            self.calculate_buffer_width()
+        } else {
+            0.0
+        };
+        let upm_height = self.session.ascender - self.session.descender;
+
+        let mut placements = Vec::new();
+        let mut x_offset = line_start;
+        let mut baseline_y = 0.0;
+        let mut prev: Option<&str> = None;
+        for (index, sort) in buffer.iter().enumerate() {
+            match &sort.kind {
+                crate::sort::SortKind::Glyph {
+                    name,
+                    advance_width,
+                    ..
+                } => {
+                    if is_rtl {
+                        x_offset -= advance_width;
+                    }
+                    if let (Some(prev_name), Some(workspace)) = (prev, &workspace) {
+                        let kern_value = workspace.pair_kerning(prev_name, name);
+                        x_offset += if is_rtl { -kern_value } else { kern_value };
+                    }
+                    placements.push(SortPlacement {
+                        index,
+                        name: name.clone(),
+                        x: x_offset,
+                        baseline_y,
+                        advance_width: *advance_width,
+                        is_active: sort.is_active,
+                    });
+                    if !is_rtl {
+                        x_offset += advance_width;
+                    }
+                    prev = Some(name);
+                }
+                crate::sort::SortKind::LineBreak => {
+                    x_offset = line_start;
+                    baseline_y -= upm_height;
+                    prev = None;
+                }
+            }
+        }
+        placements
+    }
+
+    /// Where the text cursor is set: after the sort before it, or at
+    /// the start of its line when that's a line break (or there's
+    /// none)
+    fn text_cursor_position(&self, placements: &[SortPlacement]) -> Point {
+        let Some(buffer) = &self.session.text_buffer else {
+            return Point::ZERO;
+        };
+        let is_rtl = self.session.text_direction.is_rtl();
+        let position = buffer.cursor().min(buffer.len());
+        let before = position
+            .checked_sub(1)
+            .and_then(|index| placements.iter().find(|p| p.index == index));
+        if let Some(p) = before {
+            let x = if is_rtl { p.x } else { p.x + p.advance_width };
+            return Point::new(x, p.baseline_y);
+        }
+
+        let line_start = if is_rtl {
+            self.calculate_buffer_width()
+        } else {
+            0.0
+        };
+        let upm_height = self.session.ascender - self.session.descender;
+        let lines = buffer
+            .iter()
+            .take(position)
+            .filter(|sort| matches!(sort.kind, crate::sort::SortKind::LineBreak))
+            .count();
+        Point::new(line_start, -(lines as f64) * upm_height)
+    }
+
     /// Render an active sort with control points and handles
     fn render_active_sort(
         &self,
@@ -83,23 +83,7 @@ impl<State: 'static, F: Fn(&mut State, EditSession, bool, bool) + 'static> View<
                  updating widget"
             );
 
-            // Preserve viewport state before updating session
-            let old_viewport = widget.widget.session.viewport.clone();
-            let old_viewport_initialized =
-                widget.widget.session.viewport_initialized;
-
-            // Update the session, but preserve:
-            // - Viewport state (to avoid re-initialization and
-            //   flickering)
-            // This allows tool changes and other session updates
-            // to take effect
-            widget.widget.session = (*self.session).clone();
-
-            // Restore viewport state
-            widget.widget.session.viewport = old_viewport;
-            widget.widget.session.viewport_initialized =
-                old_viewport_initialized;
-
+            widget.widget.set_session(&self.session);
             widget.ctx.request_render();
         }
     }
@@ -71,6 +71,8 @@ fn build_outline_from_contours(contours: &[Contour]) -> BezPath {
         right_group: None,
         mark_color: None,
         mark_label: None,
+        measurements: Vec::new(),
+        metrics_keys: Default::default(),
     };
     glyph_renderer::glyph_to_bezpath(&glyph)
 }
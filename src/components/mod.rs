@@ -346,12 +346,16 @@ mod render_tests {
     #[test]
     fn render_transform_panel() {
         let widget = NewWidget::new(
-            super::transform_panel::TransformPanelWidget::new(true, 3),
+            super::transform_panel::TransformPanelWidget::new(
+                true,
+                3,
+                crate::editing::session::AlignTarget::Selection,
+            ),
         );
         let mut harness = TestHarness::create_with_size(
             default_property_set(),
             widget,
-            (200, 500),
+            (200, 600),
         );
         save("transform_panel", harness.render());
     }
@@ -4,11 +4,11 @@
 //! Shapes sub-toolbar widget - shape type selection for the shapes tool
 //!
 //! This toolbar appears below the main edit mode toolbar when the shapes
-//! tool is selected, allowing users to choose between rectangle, ellipse,
-//! and other shape types.
+//! tool is selected, allowing users to choose between rectangle, rounded
+//! rectangle, ellipse, polygon, star and stroke line shapes.
 
 use crate::tools::shapes::ShapeType;
-use kurbo::{Axis, BezPath, Point, Size};
+use kurbo::{Affine, Axis, BezPath, Point, RoundedRect, Shape, Size, Vec2};
 use masonry::accesskit::{Node, Role};
 use masonry::core::{
     AccessCtx, MeasureCtx, ChildrenIds, EventCtx, LayoutCtx, PaintCtx, PointerButton,
@@ -28,7 +28,14 @@ use crate::components::toolbars::{
 };
 
 /// Available shape types in display order
-const TOOLBAR_SHAPES: &[ShapeType] = &[ShapeType::Rectangle, ShapeType::Ellipse];
+const TOOLBAR_SHAPES: &[ShapeType] = &[
+    ShapeType::Rectangle,
+    ShapeType::RoundedRect,
+    ShapeType::Ellipse,
+    ShapeType::Polygon,
+    ShapeType::Star,
+    ShapeType::StrokeLine,
+];
 
 /// Shapes sub-toolbar widget
 pub struct ShapesToolbarWidget {
@@ -50,7 +57,11 @@ impl ShapesToolbarWidget {
     fn icon_for_shape(shape: ShapeType) -> BezPath {
         match shape {
             ShapeType::Rectangle => rectangle_icon(),
+            ShapeType::RoundedRect => rounded_rect_icon(),
             ShapeType::Ellipse => ellipse_icon(),
+            ShapeType::Polygon => polygon_icon(),
+            ShapeType::Star => star_icon(),
+            ShapeType::StrokeLine => stroke_line_icon(),
         }
     }
 
@@ -243,6 +254,55 @@ fn ellipse_icon() -> BezPath {
     bez
 }
 
+fn rounded_rect_icon() -> BezPath {
+    // Outlined rounded square: outer contour plus a reversed inner one
+    let outer = RoundedRect::new(120.0, 114.0, 648.0, 652.0, 150.0).to_path(0.1);
+    let inner = RoundedRect::new(198.0, 192.0, 570.0, 574.0, 80.0).to_path(0.1);
+    let mut bez = outer;
+    bez.extend(inner.reverse_subpaths());
+    bez
+}
+
+fn polygon_icon() -> BezPath {
+    // Outlined pentagon, pointing up (screen space is y-down)
+    let mut bez = regular_star_icon_path(5, 300.0, 300.0);
+    bez.extend(regular_star_icon_path(5, 220.0, 220.0).reverse_subpaths());
+    bez
+}
+
+fn star_icon() -> BezPath {
+    // Filled five-pointed star
+    regular_star_icon_path(5, 310.0, 130.0)
+}
+
+fn stroke_line_icon() -> BezPath {
+    // Diagonal bar with round ends
+    let bar = RoundedRect::new(84.0, 334.0, 684.0, 434.0, 50.0).to_path(0.1);
+    Affine::rotate_about(-std::f64::consts::FRAC_PI_4, Point::new(384.0, 384.0)) * bar
+}
+
+/// Star around the icon center; equal radii give a regular polygon
+fn regular_star_icon_path(points: usize, outer: f64, inner: f64) -> BezPath {
+    let center = Point::new(384.0, 400.0);
+    let is_star = inner < outer;
+    let count = if is_star { points * 2 } else { points };
+    let step = std::f64::consts::TAU / count as f64;
+
+    let mut bez = BezPath::new();
+    for i in 0..count {
+        let radius = if is_star && i % 2 == 1 { inner } else { outer };
+        let angle = -std::f64::consts::FRAC_PI_2 + i as f64 * step;
+        let pt = center + Vec2::from_angle(angle) * radius;
+        if i == 0 {
+            bez.move_to(pt);
+        } else {
+            bez.line_to(pt);
+        }
+    }
+    bez.close_path();
+    bez
+}
+
 // --- Xilem View Wrapper ---
 
 /// Public API to create a shapes toolbar view
@@ -5,18 +5,23 @@
 //!
 //! This toolbar appears below the main edit mode toolbar when the text
 //! tool is selected, allowing users to choose between left-to-right
-//! and right-to-left text direction.
+//! and right-to-left text direction. Its last button opens a menu of
+//! test text generators, each of which replaces the text buffer.
 
+use crate::model::test_strings::{Script, TestText};
 use crate::shaping::TextDirection;
-use kurbo::{Axis, BezPath, Point, Size};
+use crate::theme;
+use kurbo::{Affine, Axis, BezPath, Point, Rect, RoundedRect, Size, Stroke};
 use masonry::accesskit::{Node, Role};
 use masonry::core::{
-    AccessCtx, MeasureCtx, ChildrenIds, EventCtx, LayoutCtx, PaintCtx, PointerButton,
-    PointerButtonEvent, PointerEvent, PropertiesMut, PropertiesRef, RegisterCtx, TextEvent, Update,
-    UpdateCtx, Widget,
+    AccessCtx, MeasureCtx, BrushIndex, ChildrenIds, EventCtx, LayoutCtx, PaintCtx, PointerButton,
+    PointerButtonEvent, PointerEvent, PropertiesMut, PropertiesRef, RegisterCtx, StyleProperty,
+    TextEvent, Update, UpdateCtx, Widget, render_text,
 };
 use masonry::imaging::Painter;
 use masonry::layout::{LenReq, Length};
+use masonry::peniko::Brush;
+use parley::{FontContext, LayoutContext};
 use std::marker::PhantomData;
 use tracing;
 use xilem::core::{MessageCtx, MessageResult, Mut, View, ViewMarker};
@@ -31,19 +36,51 @@ use crate::components::toolbars::{
 const TOOLBAR_DIRECTIONS: &[TextDirection] =
     &[TextDirection::LeftToRight, TextDirection::RightToLeft];
 
+/// The generators button follows the direction buttons
+const FILL_BUTTON: usize = TOOLBAR_DIRECTIONS.len();
+
+/// Gap between the buttons and the generators menu
+const MENU_GAP: f64 = 4.0;
+
+/// Test text generators in menu order, with their labels
+fn fill_items() -> Vec<(&'static str, TestText)> {
+    let mut items = vec![
+        ("Spacing  nnXnn", TestText::Spacing),
+        ("Kerning (table filter)", TestText::Kerning),
+    ];
+    items.extend(
+        Script::ALL
+            .into_iter()
+            .map(|script| (script.label(), TestText::Script(script))),
+    );
+    items
+}
+
+/// Something under the pointer
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Target {
+    Direction(TextDirection),
+    FillButton,
+    /// An item of the open generators menu, by index
+    FillItem(usize),
+}
+
 /// Text direction sub-toolbar widget
 pub struct TextDirectionToolbarWidget {
     /// Currently selected text direction
     selected_direction: TextDirection,
-    /// Currently hovered direction (if any)
-    hover_direction: Option<TextDirection>,
+    /// Button or menu item under the pointer (if any)
+    hover: Option<Target>,
+    /// Whether the generators menu is open
+    menu_open: bool,
 }
 
 impl TextDirectionToolbarWidget {
     pub fn new(selected_direction: TextDirection) -> Self {
         Self {
             selected_direction,
-            hover_direction: None,
+            hover: None,
+            menu_open: false,
         }
     }
 
@@ -55,23 +92,112 @@ impl TextDirectionToolbarWidget {
         }
     }
 
-    /// Find which direction was clicked
-    fn direction_at_point(&self, point: Point) -> Option<TextDirection> {
+    /// Size of the button row
+    fn toolbar_size() -> Size {
+        calculate_toolbar_size(FILL_BUTTON + 1)
+    }
+
+    /// Rect of a generators menu item, under the button row and as
+    /// wide as it
+    fn item_rect(index: usize) -> Rect {
+        let top = Self::toolbar_size().height
+            + MENU_GAP
+            + theme::context_menu::PADDING
+            + index as f64 * theme::context_menu::ITEM_HEIGHT;
+        Rect::new(
+            0.0,
+            top,
+            Self::toolbar_size().width,
+            top + theme::context_menu::ITEM_HEIGHT,
+        )
+    }
+
+    /// Find which button or menu item is at a point
+    fn target_at_point(&self, point: Point) -> Option<Target> {
         for (i, &direction) in TOOLBAR_DIRECTIONS.iter().enumerate() {
             if button_rect(i).contains(point) {
-                return Some(direction);
+                return Some(Target::Direction(direction));
             }
         }
-        None
+        if button_rect(FILL_BUTTON).contains(point) {
+            return Some(Target::FillButton);
+        }
+        if !self.menu_open {
+            return None;
+        }
+        (0..fill_items().len())
+            .find(|&i| Self::item_rect(i).contains(point))
+            .map(Target::FillItem)
+    }
+
+    /// Paint the generators menu under the button row
+    fn paint_menu(&self, painter: &mut Painter<'_>) {
+        let items = fill_items();
+        let toolbar = Self::toolbar_size();
+        let menu_rect = Rect::new(
+            0.0,
+            toolbar.height + MENU_GAP,
+            toolbar.width,
+            toolbar.height
+                + MENU_GAP
+                + theme::context_menu::PADDING * 2.0
+                + items.len() as f64 * theme::context_menu::ITEM_HEIGHT,
+        );
+        let rounded = RoundedRect::from_rect(menu_rect, theme::context_menu::BORDER_RADIUS);
+        painter
+            .fill(&rounded, &Brush::Solid(theme::context_menu::BACKGROUND))
+            .draw();
+        painter
+            .stroke(
+                &rounded,
+                &Stroke::new(1.0),
+                &Brush::Solid(theme::context_menu::BORDER),
+            )
+            .draw();
+
+        let mut font_cx = FontContext::default();
+        let mut layout_cx = LayoutContext::new();
+        let brushes = vec![Brush::Solid(theme::context_menu::TEXT)];
+        for (i, (text, _)) in items.into_iter().enumerate() {
+            let rect = Self::item_rect(i);
+            if self.hover == Some(Target::FillItem(i)) {
+                let highlight = RoundedRect::from_rect(rect.inset((-2.0, 0.0)), 3.0);
+                painter
+                    .fill(&highlight, &Brush::Solid(theme::context_menu::HOVER))
+                    .draw();
+            }
+
+            let mut builder = layout_cx.ranged_builder(&mut font_cx, text, 1.0, false);
+            builder.push_default(StyleProperty::FontSize(theme::context_menu::FONT_SIZE));
+            builder.push_default(StyleProperty::FontFamily(parley::FontFamily::Single(
+                parley::FontFamilyName::Generic(parley::GenericFamily::SansSerif),
+            )));
+            builder.push_default(StyleProperty::Brush(BrushIndex(0)));
+            let mut layout = builder.build(text);
+            layout.break_all_lines(None);
+
+            let text_y = rect.y0 + (rect.height() - layout.height() as f64) / 2.0;
+            render_text(
+                painter,
+                Affine::translate((theme::context_menu::TEXT_INSET, text_y)),
+                &layout,
+                &brushes,
+                false,
+            );
+        }
     }
 }
 
-/// Action sent when a text direction is selected
+/// Action sent when a text direction or a test text generator is
+/// selected
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
-pub struct TextDirectionSelected(pub TextDirection);
+pub enum TextDirectionToolbarAction {
+    Direction(TextDirection),
+    Fill(TestText),
+}
 
 impl Widget for TextDirectionToolbarWidget {
-    type Action = TextDirectionSelected;
+    type Action = TextDirectionToolbarAction;
 
     fn register_children(&mut self, _ctx: &mut RegisterCtx<'_>) {
         // Leaf widget - no children
@@ -94,7 +220,10 @@ impl Widget for TextDirectionToolbarWidget {
         _len_req: LenReq,
         _cross_length: Option<Length>,
     ) -> Length {
-        let size = calculate_toolbar_size(TOOLBAR_DIRECTIONS.len());
+        let mut size = Self::toolbar_size();
+        if self.menu_open {
+            size.height = Self::item_rect(fill_items().len() - 1).y1 + theme::context_menu::PADDING;
+        }
         crate::components::measure_fixed(axis, size)
     }
 
@@ -106,21 +235,30 @@ impl Widget for TextDirectionToolbarWidget {
     ) {
     }
 
-    fn paint(&mut self, ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, painter: &mut Painter<'_>) {
-        let size = ctx.content_box().size();
-        paint_panel(painter, size);
+    fn paint(&mut self, _ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, painter: &mut Painter<'_>) {
+        paint_panel(painter, Self::toolbar_size());
 
         // Paint each direction button
         for (i, &direction) in TOOLBAR_DIRECTIONS.iter().enumerate() {
             let rect = button_rect(i);
             let state = ButtonState::new(
-                self.hover_direction == Some(direction),
+                self.hover == Some(Target::Direction(direction)),
                 self.selected_direction == direction,
             );
 
             paint_button(painter, rect, state);
             paint_icon(painter, Self::icon_for_direction(direction), rect, state);
         }
+
+        // The generators button reads as selected while its menu is open
+        let rect = button_rect(FILL_BUTTON);
+        let state = ButtonState::new(self.hover == Some(Target::FillButton), self.menu_open);
+        paint_button(painter, rect, state);
+        paint_icon(painter, fill_icon(), rect, state);
+
+        if self.menu_open {
+            self.paint_menu(painter);
+        }
     }
 
     fn accessibility_role(&self) -> Role {
@@ -149,9 +287,9 @@ impl Widget for TextDirectionToolbarWidget {
         match event {
             PointerEvent::Move(state) => {
                 let local_pos = ctx.local_position(state.current.position);
-                let new_hover = self.direction_at_point(local_pos);
-                if new_hover != self.hover_direction {
-                    self.hover_direction = new_hover;
+                let new_hover = self.target_at_point(local_pos);
+                if new_hover != self.hover {
+                    self.hover = new_hover;
                     ctx.request_render();
                 }
             }
@@ -161,16 +299,35 @@ impl Widget for TextDirectionToolbarWidget {
                 ..
             }) => {
                 let local_pos = ctx.local_position(state.position);
-                if let Some(direction) = self.direction_at_point(local_pos) {
-                    tracing::debug!("Text direction toolbar: clicked {:?}", direction);
-                    self.selected_direction = direction;
-                    ctx.request_render();
-                    ctx.submit_action::<TextDirectionSelected>(TextDirectionSelected(direction));
+                match self.target_at_point(local_pos) {
+                    Some(Target::Direction(direction)) => {
+                        tracing::debug!("Text direction toolbar: clicked {:?}", direction);
+                        self.selected_direction = direction;
+                        ctx.request_render();
+                        ctx.submit_action::<TextDirectionToolbarAction>(
+                            TextDirectionToolbarAction::Direction(direction),
+                        );
+                    }
+                    Some(Target::FillButton) => {
+                        self.menu_open = !self.menu_open;
+                        ctx.request_layout();
+                    }
+                    Some(Target::FillItem(index)) => {
+                        let (_, text) = fill_items()[index];
+                        tracing::debug!("Text direction toolbar: fill with {:?}", text);
+                        self.menu_open = false;
+                        self.hover = None;
+                        ctx.request_layout();
+                        ctx.submit_action::<TextDirectionToolbarAction>(
+                            TextDirectionToolbarAction::Fill(text),
+                        );
+                    }
+                    None => {}
                 }
             }
             PointerEvent::Leave(_) => {
-                if self.hover_direction.is_some() {
-                    self.hover_direction = None;
+                if self.hover.is_some() {
+                    self.hover = None;
                     ctx.request_render();
                 }
             }
@@ -283,12 +440,39 @@ fn rtl_icon() -> BezPath {
     filled
 }
 
+/// Generators icon - lines of text over a menu chevron
+fn fill_icon() -> BezPath {
+    let mut filled = BezPath::new();
+
+    // Text lines
+    let line_height = 30.0;
+    for (y, right) in [(200.0, 618.0), (300.0, 518.0), (400.0, 618.0)] {
+        filled.move_to((150.0, y - line_height / 2.0));
+        filled.line_to((right, y - line_height / 2.0));
+        filled.line_to((right, y + line_height / 2.0));
+        filled.line_to((150.0, y + line_height / 2.0));
+        filled.close_path();
+    }
+
+    // Chevron pointing down
+    filled.move_to((284.0, 500.0));
+    filled.line_to((384.0, 600.0));
+    filled.line_to((484.0, 500.0));
+    filled.line_to((444.0, 500.0));
+    filled.line_to((384.0, 560.0));
+    filled.line_to((324.0, 500.0));
+    filled.close_path();
+
+    filled
+}
+
 // --- Xilem View Wrapper ---
 
 /// Public API to create a text direction toolbar view
 pub fn text_direction_toolbar_view<State, Action>(
     selected_direction: TextDirection,
     callback: impl Fn(&mut State, TextDirection) + Send + Sync + 'static,
+    fill_callback: impl Fn(&mut State, TestText) + Send + Sync + 'static,
 ) -> TextDirectionToolbarView<State, Action>
 where
     Action: 'static,
@@ -296,17 +480,20 @@ where
     TextDirectionToolbarView {
         selected_direction,
         callback: Box::new(callback),
+        fill_callback: Box::new(fill_callback),
         phantom: PhantomData,
     }
 }
 
 /// The Xilem View for TextDirectionToolbarWidget
 type TextDirectionToolbarCallback<State> = Box<dyn Fn(&mut State, TextDirection) + Send + Sync>;
+type TestTextCallback<State> = Box<dyn Fn(&mut State, TestText) + Send + Sync>;
 
 #[must_use = "View values do nothing unless provided to Xilem."]
 pub struct TextDirectionToolbarView<State, Action = ()> {
     selected_direction: TextDirection,
     callback: TextDirectionToolbarCallback<State>,
+    fill_callback: TestTextCallback<State>,
     phantom: PhantomData<fn() -> (State, Action)>,
 }
 
@@ -357,10 +544,17 @@ impl<State: 'static, Action: 'static + Default> View<State, Action, ViewCtx>
         _element: Mut<'_, Self::Element>,
         app_state: &mut State,
     ) -> MessageResult<Action> {
-        // Handle text direction selection actions from widget
-        match message.take_message::<TextDirectionSelected>() {
+        // Handle direction and generator actions from widget
+        match message.take_message::<TextDirectionToolbarAction>() {
             Some(action) => {
-                (self.callback)(app_state, action.0);
+                match *action {
+                    TextDirectionToolbarAction::Direction(direction) => {
+                        (self.callback)(app_state, direction);
+                    }
+                    TextDirectionToolbarAction::Fill(text) => {
+                        (self.fill_callback)(app_state, text);
+                    }
+                }
                 MessageResult::Action(Action::default())
             }
             None => MessageResult::Stale,
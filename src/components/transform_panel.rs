@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 //! Transform panel — compact 2-column icon button grid for flip, rotate,
-//! duplicate, and boolean operations.
+//! duplicate, boolean, and align/distribute operations.
 //!
 //! Follows the same custom-widget pattern as `edit_mode_toolbar.rs`,
 //! reusing the shared toolbar paint helpers from `toolbars.rs`.
@@ -28,6 +28,7 @@ use xilem::{Pod, ViewCtx};
 use crate::components::toolbars::{
     ButtonState, paint_button, paint_icon, paint_panel,
 };
+use crate::editing::session::AlignTarget;
 use crate::theme::size::{
     TOOLBAR_ITEM_SIZE, TOOLBAR_ITEM_SPACING, TOOLBAR_PADDING,
 };
@@ -39,7 +40,7 @@ use crate::theme::size::{
 /// Number of columns in the grid
 const COLS: usize = 2;
 /// Number of rows in the grid
-const ROWS: usize = 6;
+const ROWS: usize = BUTTONS.len().div_ceil(COLS);
 
 // ================================================================
 // TRANSFORM ACTION
@@ -59,6 +60,16 @@ pub enum TransformAction {
     Subtract,
     Intersect,
     Exclude,
+    /// Cycle what the align buttons align to
+    AlignTarget,
+    AlignLeft,
+    AlignRight,
+    AlignCenter,
+    AlignMiddle,
+    AlignTop,
+    AlignBottom,
+    DistributeH,
+    DistributeV,
 }
 
 impl TransformAction {
@@ -76,6 +87,15 @@ impl TransformAction {
             Self::Subtract => "Subtract",
             Self::Intersect => "Intersect",
             Self::Exclude => "Exclude (XOR)",
+            Self::AlignTarget => "Align To",
+            Self::AlignLeft => "Align Left",
+            Self::AlignRight => "Align Right",
+            Self::AlignCenter => "Align Center",
+            Self::AlignMiddle => "Align Middle",
+            Self::AlignTop => "Align Top",
+            Self::AlignBottom => "Align Bottom",
+            Self::DistributeH => "Distribute Horizontally",
+            Self::DistributeV => "Distribute Vertically",
         }
     }
 }
@@ -93,6 +113,15 @@ const BUTTONS: &[TransformAction] = &[
     TransformAction::Subtract,
     TransformAction::Intersect,
     TransformAction::Exclude,
+    TransformAction::AlignTarget,
+    TransformAction::AlignLeft,
+    TransformAction::AlignRight,
+    TransformAction::AlignCenter,
+    TransformAction::AlignMiddle,
+    TransformAction::AlignTop,
+    TransformAction::AlignBottom,
+    TransformAction::DistributeH,
+    TransformAction::DistributeV,
 ];
 
 // ================================================================
@@ -105,15 +134,22 @@ pub struct TransformPanelWidget {
     has_selection: bool,
     /// Number of contours (for boolean ops)
     contour_count: usize,
+    /// What the align buttons align to
+    align_target: AlignTarget,
     /// Currently hovered button index
     hover_index: Option<usize>,
 }
 
 impl TransformPanelWidget {
-    pub fn new(has_selection: bool, contour_count: usize) -> Self {
+    pub fn new(
+        has_selection: bool,
+        contour_count: usize,
+        align_target: AlignTarget,
+    ) -> Self {
         Self {
             has_selection,
             contour_count,
+            align_target,
             hover_index: None,
         }
     }
@@ -171,16 +207,33 @@ impl TransformPanelWidget {
             | TransformAction::RotateCCW
             | TransformAction::Rotate180
             | TransformAction::Duplicate
-            | TransformAction::DuplicateRepeat => self.has_selection,
+            | TransformAction::DuplicateRepeat
+            | TransformAction::AlignLeft
+            | TransformAction::AlignRight
+            | TransformAction::AlignCenter
+            | TransformAction::AlignMiddle
+            | TransformAction::AlignTop
+            | TransformAction::AlignBottom
+            | TransformAction::DistributeH
+            | TransformAction::DistributeV => self.has_selection,
             TransformAction::Union
             | TransformAction::Subtract
             | TransformAction::Intersect
             | TransformAction::Exclude => self.contour_count >= 2,
+            TransformAction::AlignTarget => true,
+        }
+    }
+
+    /// Tooltip text for a button
+    fn label_for(&self, action: TransformAction) -> &'static str {
+        match action {
+            TransformAction::AlignTarget => self.align_target.label(),
+            _ => action.label(),
         }
     }
 
     /// Get icon BezPath for a transform action
-    fn icon_for(action: TransformAction) -> BezPath {
+    fn icon_for(&self, action: TransformAction) -> BezPath {
         match action {
             TransformAction::FlipH => icon_flip_h(),
             TransformAction::FlipV => icon_flip_v(),
@@ -195,6 +248,17 @@ impl TransformPanelWidget {
             TransformAction::Subtract => icon_subtract(),
             TransformAction::Intersect => icon_intersect(),
             TransformAction::Exclude => icon_exclude(),
+            TransformAction::AlignTarget => {
+                icon_align_target(self.align_target)
+            }
+            TransformAction::AlignLeft => icon_align_left(),
+            TransformAction::AlignRight => icon_align_right(),
+            TransformAction::AlignCenter => icon_align_center(),
+            TransformAction::AlignMiddle => icon_align_middle(),
+            TransformAction::AlignTop => icon_align_top(),
+            TransformAction::AlignBottom => icon_align_bottom(),
+            TransformAction::DistributeH => icon_distribute_h(),
+            TransformAction::DistributeV => icon_distribute_v(),
         }
     }
 }
@@ -250,7 +314,7 @@ impl Widget for TransformPanelWidget {
             paint_button(painter, rect, state);
 
             // Draw icon (dimmed if disabled)
-            let icon = Self::icon_for(action);
+            let icon = self.icon_for(action);
             if enabled {
                 paint_icon(painter, icon, rect, state);
             } else {
@@ -268,7 +332,7 @@ impl Widget for TransformPanelWidget {
         if let Some(i) = self.hover_index {
             let action = BUTTONS[i];
             if self.is_enabled(action) {
-                let label = action.label();
+                let label = self.label_for(action);
                 let btn_rect = Self::button_rect(i);
 
                 // Build text layout
@@ -413,6 +477,7 @@ impl Widget for TransformPanelWidget {
 pub fn transform_panel<Action>(
     has_selection: bool,
     contour_count: usize,
+    align_target: AlignTarget,
     callback: impl Fn(&mut crate::data::AppState, TransformAction)
         + Send
         + Sync
@@ -421,6 +486,7 @@ pub fn transform_panel<Action>(
     TransformPanelView {
         has_selection,
         contour_count,
+        align_target,
         callback: Box::new(callback),
         phantom: PhantomData,
     }
@@ -436,6 +502,7 @@ type TransformCallback = Box<
 pub struct TransformPanelView<Action = ()> {
     has_selection: bool,
     contour_count: usize,
+    align_target: AlignTarget,
     callback: TransformCallback,
     phantom: PhantomData<fn() -> Action>,
 }
@@ -457,6 +524,7 @@ impl<Action: 'static + Default>
         let widget = TransformPanelWidget::new(
             self.has_selection,
             self.contour_count,
+            self.align_target,
         );
         let pod = ctx.create_pod(widget);
         ctx.record_action_source(pod.new_widget.id());
@@ -476,10 +544,12 @@ impl<Action: 'static + Default>
         let changed = widget.widget.has_selection
             != self.has_selection
             || widget.widget.contour_count
-                != self.contour_count;
+                != self.contour_count
+            || widget.widget.align_target != self.align_target;
         if changed {
             widget.widget.has_selection = self.has_selection;
             widget.widget.contour_count = self.contour_count;
+            widget.widget.align_target = self.align_target;
             widget.ctx.request_render();
         }
     }
@@ -1003,3 +1073,154 @@ fn icon_exclude() -> BezPath {
     bez.close_path();
     bez
 }
+
+/// Add an axis-aligned rectangle to an icon path
+fn push_rect(bez: &mut BezPath, x0: f64, y0: f64, x1: f64, y1: f64) {
+    bez.move_to((x0, y0));
+    bez.line_to((x1, y0));
+    bez.line_to((x1, y1));
+    bez.line_to((x0, y1));
+    bez.close_path();
+}
+
+/// Add a rectangular outline (outer rect minus inner cutout)
+fn push_frame(bez: &mut BezPath, rect: Rect, thickness: f64) {
+    push_rect(bez, rect.x0, rect.y0, rect.x1, rect.y1);
+    // Inner cutout with reverse winding
+    let inner = rect.inset(-thickness);
+    bez.move_to((inner.x0, inner.y0));
+    bez.line_to((inner.x0, inner.y1));
+    bez.line_to((inner.x1, inner.y1));
+    bez.line_to((inner.x1, inner.y0));
+    bez.close_path();
+}
+
+/// Align left: vertical edge with two bars hanging off it
+fn icon_align_left() -> BezPath {
+    let mut bez = BezPath::new();
+    push_rect(&mut bez, 100.0, 100.0, 140.0, 668.0);
+    push_rect(&mut bez, 180.0, 200.0, 668.0, 330.0);
+    push_rect(&mut bez, 180.0, 438.0, 500.0, 568.0);
+    bez
+}
+
+/// Align right: mirror of align left
+fn icon_align_right() -> BezPath {
+    let mut bez = BezPath::new();
+    push_rect(&mut bez, 628.0, 100.0, 668.0, 668.0);
+    push_rect(&mut bez, 100.0, 200.0, 588.0, 330.0);
+    push_rect(&mut bez, 268.0, 438.0, 588.0, 568.0);
+    bez
+}
+
+/// Align center: two bars centred on a vertical axis
+fn icon_align_center() -> BezPath {
+    let mut bez = BezPath::new();
+    push_rect(&mut bez, 364.0, 100.0, 404.0, 668.0);
+    push_rect(&mut bez, 100.0, 200.0, 668.0, 330.0);
+    push_rect(&mut bez, 234.0, 438.0, 534.0, 568.0);
+    bez
+}
+
+/// Align top: horizontal edge with two columns hanging below
+fn icon_align_top() -> BezPath {
+    let mut bez = BezPath::new();
+    push_rect(&mut bez, 100.0, 100.0, 668.0, 140.0);
+    push_rect(&mut bez, 200.0, 180.0, 330.0, 668.0);
+    push_rect(&mut bez, 438.0, 180.0, 568.0, 500.0);
+    bez
+}
+
+/// Align bottom: mirror of align top
+fn icon_align_bottom() -> BezPath {
+    let mut bez = BezPath::new();
+    push_rect(&mut bez, 100.0, 628.0, 668.0, 668.0);
+    push_rect(&mut bez, 200.0, 100.0, 330.0, 588.0);
+    push_rect(&mut bez, 438.0, 268.0, 568.0, 588.0);
+    bez
+}
+
+/// Align middle: two columns centred on a horizontal axis
+fn icon_align_middle() -> BezPath {
+    let mut bez = BezPath::new();
+    push_rect(&mut bez, 100.0, 364.0, 668.0, 404.0);
+    push_rect(&mut bez, 200.0, 100.0, 330.0, 668.0);
+    push_rect(&mut bez, 438.0, 234.0, 568.0, 534.0);
+    bez
+}
+
+/// Distribute horizontally: three evenly spaced columns between
+/// two edge lines
+fn icon_distribute_h() -> BezPath {
+    let mut bez = BezPath::new();
+    push_rect(&mut bez, 100.0, 100.0, 130.0, 668.0);
+    push_rect(&mut bez, 638.0, 100.0, 668.0, 668.0);
+    push_rect(&mut bez, 180.0, 234.0, 260.0, 534.0);
+    push_rect(&mut bez, 344.0, 234.0, 424.0, 534.0);
+    push_rect(&mut bez, 508.0, 234.0, 588.0, 534.0);
+    bez
+}
+
+/// Distribute vertically: three evenly spaced rows between two
+/// edge lines
+fn icon_distribute_v() -> BezPath {
+    let mut bez = BezPath::new();
+    push_rect(&mut bez, 100.0, 100.0, 668.0, 130.0);
+    push_rect(&mut bez, 100.0, 638.0, 668.0, 668.0);
+    push_rect(&mut bez, 234.0, 180.0, 534.0, 260.0);
+    push_rect(&mut bez, 234.0, 344.0, 534.0, 424.0);
+    push_rect(&mut bez, 234.0, 508.0, 534.0, 588.0);
+    bez
+}
+
+/// Align target: a picture of what the align buttons align to
+fn icon_align_target(target: AlignTarget) -> BezPath {
+    let mut bez = BezPath::new();
+    match target {
+        AlignTarget::Selection => {
+            // Corner brackets around a selection
+            for (x, y, dx, dy) in [
+                (100.0, 100.0, 1.0, 1.0),
+                (668.0, 100.0, -1.0, 1.0),
+                (100.0, 668.0, 1.0, -1.0),
+                (668.0, 668.0, -1.0, -1.0),
+            ] {
+                let arm_h = Rect::from_points(
+                    (x, y),
+                    (x + dx * 200.0, y + dy * 50.0),
+                );
+                let arm_v = Rect::from_points(
+                    (x, y),
+                    (x + dx * 50.0, y + dy * 200.0),
+                );
+                for r in [arm_h, arm_v] {
+                    push_rect(&mut bez, r.x0, r.y0, r.x1, r.y1);
+                }
+            }
+            push_rect(&mut bez, 284.0, 284.0, 484.0, 484.0);
+        }
+        AlignTarget::Glyph => {
+            // Bounding box frame around a glyph-ish block
+            push_frame(
+                &mut bez,
+                Rect::new(100.0, 100.0, 668.0, 668.0),
+                50.0,
+            );
+            push_rect(&mut bez, 234.0, 234.0, 534.0, 534.0);
+        }
+        AlignTarget::Advance => {
+            // Sidebearing lines on a baseline
+            push_rect(&mut bez, 100.0, 100.0, 150.0, 668.0);
+            push_rect(&mut bez, 618.0, 100.0, 668.0, 668.0);
+            push_rect(&mut bez, 150.0, 528.0, 618.0, 568.0);
+            push_rect(&mut bez, 284.0, 234.0, 484.0, 528.0);
+        }
+        AlignTarget::Metrics => {
+            // Stacked metrics lines
+            for y in [100.0, 284.0, 468.0, 628.0] {
+                push_rect(&mut bez, 100.0, y, 668.0, y + 40.0);
+            }
+        }
+    }
+    bez
+}
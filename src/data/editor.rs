@@ -3,10 +3,15 @@
 
 //! Editor session management for AppState
 
+use super::metrics::active_metrics;
 use super::{AppState, Tab};
 use crate::editing::EditSession;
 use crate::editing::background_image::BackgroundImage;
+use crate::model::kerning_table::kerning_rows;
+use crate::model::test_strings::{self, TestText};
 use crate::model::{read_workspace, write_workspace};
+use crate::shaping::TextDirection;
+use crate::sort::paragraph::ParagraphSettings;
 use std::sync::Arc;
 
 #[allow(dead_code)]
@@ -181,7 +186,7 @@ impl AppState {
     }
 
     /// Sync the current editor session to the workspace
-    fn sync_editor_to_workspace(&mut self) {
+    pub(super) fn sync_editor_to_workspace(&mut self) {
         let Some(session) = &self.editor_session else {
             return;
         };
@@ -323,6 +328,23 @@ impl AppState {
         }
     }
 
+    /// Update the parameters of the shapes tool (corner radius, side
+    /// count, star inner radius, stroke width)
+    pub fn update_shape_options(
+        &mut self,
+        edit: impl FnOnce(&mut crate::tools::shapes::ShapeOptions),
+    ) {
+        let Some(session) = &mut self.editor_session else {
+            return;
+        };
+
+        if let crate::tools::ToolBox::Shapes(shapes_tool) = &mut session.current_tool {
+            let mut options = shapes_tool.options();
+            edit(&mut options);
+            shapes_tool.set_options(options);
+        }
+    }
+
     /// Set the text direction for RTL/LTR text editing
     pub fn set_text_direction(&mut self, direction: crate::shaping::TextDirection) {
         let Some(session) = &mut self.editor_session else {
@@ -337,16 +359,156 @@ impl AppState {
     ///
     /// This also syncs the edited glyph back to the workspace so
     /// changes persist when switching views.
-    pub fn update_editor_session(&mut self, session: EditSession) {
+    pub fn update_editor_session(&mut self, mut session: EditSession) {
         self.sync_session_to_workspace(&session);
+        let edits = std::mem::take(&mut session.sidebearing_edits);
+        let metrics_changed =
+            self.editor_session.as_ref().map(active_metrics) != Some(active_metrics(&session));
         self.editor_session = Some(session);
+        if metrics_changed {
+            self.propagate_active_metrics();
+        }
+        if !edits.is_empty() {
+            self.replay_sidebearing_edits(&edits);
+        }
+    }
+
+    /// Toggle spacing mode, where the edges of the sorts in the text
+    /// buffer are sidebearing handles
+    pub fn toggle_spacing_mode(&mut self) {
+        let Some(session) = &mut self.editor_session else {
+            return;
+        };
+        session.spacing_mode = !session.spacing_mode;
+        session.spacing_handle = None;
+        tracing::info!("Spacing mode: {}", session.spacing_mode);
+    }
+
+    /// Toggle whether sidebearing changes apply to every master
+    pub fn toggle_spacing_all_masters(&mut self) {
+        let Some(session) = &mut self.editor_session else {
+            return;
+        };
+        session.spacing_all_masters = !session.spacing_all_masters;
+    }
+
+    /// Replace the text buffer with test text: spacing patterns for
+    /// the glyphs selected in the grid (or the glyph being edited),
+    /// the kerning pairs of the active master that pass the kerning
+    /// table's filters, or a script sample
+    pub fn fill_text_buffer(&mut self, text: TestText) {
+        self.sync_editor_to_workspace();
+        let Some(workspace_arc) = self.active_workspace() else {
+            return;
+        };
+        let Some(session) = &mut self.editor_session else {
+            return;
+        };
+
+        let lines = match text {
+            TestText::Spacing => {
+                let mut names: Vec<_> = self.selected_glyphs.iter().cloned().collect();
+                names.sort();
+                if names.is_empty() {
+                    names.extend(session.active_sort_name.clone());
+                }
+                test_strings::spacing_lines(&read_workspace(&workspace_arc), &names)
+            }
+            TestText::Kerning => {
+                let workspace = read_workspace(&workspace_arc);
+                // `kerning_lines` sets each group as a member found in
+                // the font
+                let pairs: Vec<_> = kerning_rows(
+                    &workspace.kerning,
+                    &workspace.groups,
+                    &self.kerning_first_filter,
+                    &self.kerning_second_filter,
+                )
+                .into_iter()
+                .map(|row| (row.first, row.second))
+                .collect();
+                tracing::info!("Kerning strings: {} pair(s)", pairs.len());
+                test_strings::kerning_lines(&workspace, &pairs)
+            }
+            TestText::Script(script) => {
+                session.text_direction = if script.is_rtl() {
+                    TextDirection::RightToLeft
+                } else {
+                    TextDirection::LeftToRight
+                };
+                if !session.fill_buffer_with_text(script.sample()) {
+                    tracing::warn!("No glyphs for the {} sample", script.label());
+                }
+                return;
+            }
+        };
+        if !session.fill_buffer_with_glyphs(&lines) {
+            tracing::warn!("Nothing to fill the text buffer with");
+        }
+    }
+
+    /// Toggle paragraph mode, where the text buffer is set as wrapped
+    /// paragraphs for proofing
+    pub fn toggle_paragraph_mode(&mut self) {
+        let Some(session) = &mut self.editor_session else {
+            return;
+        };
+        session.paragraph.enabled = !session.paragraph.enabled;
+        tracing::info!("Paragraph mode: {}", session.paragraph.enabled);
+    }
+
+    /// Toggle the waterfall of sizes in paragraph mode
+    pub fn toggle_paragraph_waterfall(&mut self) {
+        let Some(session) = &mut self.editor_session else {
+            return;
+        };
+        session.paragraph.waterfall = !session.paragraph.waterfall;
+    }
+
+    /// Set one of the paragraph settings from a text field. Input that
+    /// isn't a positive number is ignored.
+    pub fn set_paragraph_option(
+        &mut self,
+        value: String,
+        field: impl FnOnce(&mut ParagraphSettings) -> &mut f64,
+    ) {
+        let Some(session) = &mut self.editor_session else {
+            return;
+        };
+        if let Ok(value) = value.trim().parse::<f64>()
+            && value > 0.0
+        {
+            *field(&mut session.paragraph) = value;
+        }
+    }
+
+    /// Apply sidebearing changes made in the editor, or taken back by
+    /// undo, to the same glyphs in the other masters
+    fn replay_sidebearing_edits(&mut self, edits: &[crate::editing::session::SidebearingEdit]) {
+        use crate::editing::session::adjust_glyph_sidebearing;
+
+        if self.designspace.is_none() {
+            return;
+        }
+        // The active master already has them
+        for workspace_arc in self.master_workspaces().iter().skip(1) {
+            let mut workspace = write_workspace(workspace_arc);
+            for edit in edits {
+                if let Some(glyph) = workspace.get_glyph_mut(&edit.glyph) {
+                    adjust_glyph_sidebearing(glyph, edit);
+                    workspace.propagate_metrics(&edit.glyph);
+                }
+            }
+        }
+        self.mark_masters_modified();
     }
 
     /// Update a coordinate of the current selection from text input
     ///
     /// Parses the input value as f64, computes a delta from the
     /// current reference point, and moves the selection accordingly.
-    /// Only X and Y fields are supported; W/H are display-only.
+    /// Handle fields set the length, angle or tension of a handle of
+    /// the single selected on-curve point.
     pub fn update_selection_coordinate(
         &mut self,
         field: crate::components::coordinate_panel::CoordField,
@@ -363,10 +525,24 @@ impl AppState {
             return;
         };
 
+        if let CoordField::Component(component_field) = field {
+            if session.set_component_transform(component_field, new_val) {
+                session.sync_to_workspace();
+            }
+            return;
+        }
+
         if session.selection.is_empty() {
             return;
         }
 
+        if let CoordField::Handle(side, handle_field) = field {
+            if session.set_handle(side, handle_field, new_val) {
+                session.sync_to_workspace();
+            }
+            return;
+        }
+
         let reference = session.coord_selection.reference_point();
 
         // W/H resize the selection about the quadrant reference
@@ -408,7 +584,10 @@ impl AppState {
         let delta = match field {
             CoordField::X => kurbo::Vec2::new(new_val - reference.x, 0.0),
             CoordField::Y => kurbo::Vec2::new(0.0, new_val - reference.y),
-            CoordField::Width | CoordField::Height => unreachable!(),
+            CoordField::Width
+            | CoordField::Height
+            | CoordField::Handle(..)
+            | CoordField::Component(_) => unreachable!(),
         };
 
         if delta.x.abs() < 1e-9 && delta.y.abs() < 1e-9 {
@@ -421,6 +600,197 @@ impl AppState {
         session.sync_to_workspace();
     }
 
+    /// Apply the transform panel's numeric transform, or repeat the
+    /// last transform with `repeat`, to the panel's transform scope
+    ///
+    /// Selection and glyph scopes go through the edit session. The
+    /// selected-glyphs scope transforms every glyph selected in the
+    /// grid about its own quadrant origin, editing the workspace
+    /// directly except for the active glyph.
+    pub fn apply_numeric_transform(&mut self, repeat: bool) {
+        use crate::editing::session::{TransformScope, reanchor, transform_workspace_glyph};
+
+        let Some(session) = &mut self.editor_session else {
+            return;
+        };
+
+        let scope = session.transform_scope;
+        if scope != TransformScope::GridGlyphs {
+            let whole_glyph = scope == TransformScope::Glyph;
+            let changed = if repeat {
+                session.repeat_last_transform(whole_glyph)
+            } else {
+                session.apply_numeric_transform(whole_glyph)
+            };
+            if changed {
+                session.update_coord_selection();
+                session.sync_to_workspace();
+            }
+            return;
+        }
+
+        let names: Vec<String> = if self.selected_glyphs.is_empty() {
+            self.selected_glyph.iter().cloned().collect()
+        } else {
+            self.selected_glyphs.iter().cloned().collect()
+        };
+
+        // Numeric values are re-anchored per glyph, so remember them
+        // about the design-space origin
+        let affine_for: Box<dyn Fn(kurbo::Point) -> kurbo::Affine> = if repeat {
+            let Some(last) = session.last_transform else {
+                return;
+            };
+            let from = session.last_transform_origin.unwrap_or(kurbo::Point::ZERO);
+            Box::new(move |origin| reanchor(last, from, origin))
+        } else {
+            let params = session.numeric_transform;
+            let origin = kurbo::Point::ZERO;
+            session.remember_transform(params.affine_about(origin), origin);
+            Box::new(move |origin| params.affine_about(origin))
+        };
+
+        // The active glyph is edited through the session so its
+        // outlines stay in step with the workspace
+        let active = session.active_sort_name.clone();
+        if active.as_ref().is_some_and(|name| names.contains(name)) {
+            if let Some(origin) = session.transform_origin(true) {
+                session.transform_glyph(affine_for(origin));
+                session.update_coord_selection();
+                session.sync_to_workspace();
+            }
+        }
+
+        let quadrant = session.coord_selection.quadrant;
+        let Some(workspace_arc) = self.active_workspace() else {
+            return;
+        };
+        let mut workspace = write_workspace(&workspace_arc);
+        for name in names.iter().filter(|name| Some(*name) != active.as_ref()) {
+            transform_workspace_glyph(&mut workspace, name, quadrant, &affine_for);
+        }
+    }
+
+    /// Every master's workspace, the active one first, or just the
+    /// font's workspace outside a designspace
+    pub(super) fn master_workspaces(
+        &self,
+    ) -> Vec<Arc<std::sync::RwLock<crate::model::workspace::Workspace>>> {
+        let Some(ds) = &self.designspace else {
+            return self.active_workspace().into_iter().collect();
+        };
+        let mut workspaces: Vec<_> = ds
+            .masters
+            .iter()
+            .map(|m| Arc::clone(&m.workspace))
+            .collect();
+        if ds.active_master < workspaces.len() {
+            workspaces.swap(0, ds.active_master);
+        }
+        workspaces
+    }
+
+    /// Flag every master for saving after an edit made through
+    /// `master_workspaces`
+    pub(super) fn mark_masters_modified(&mut self) {
+        if let Some(ds) = &mut self.designspace {
+            ds.mark_all_modified();
+        }
+    }
+
+    /// Reload the editor's active glyph from the workspace after it
+    /// was changed there directly
+    pub(super) fn reload_editor_glyph(&mut self) {
+        let Some(workspace_arc) = self.active_workspace() else {
+            return;
+        };
+        let Some(session) = &mut self.editor_session else {
+            return;
+        };
+        let Some(glyph_name) = session.active_sort_name.clone() else {
+            return;
+        };
+        let workspace = read_workspace(&workspace_arc);
+        if let Some(glyph) = workspace.get_glyph(&glyph_name) {
+            session.glyph = Arc::new(glyph.clone());
+            let paths: Vec<crate::path::Path> = glyph
+                .contours
+                .iter()
+                .map(crate::path::Path::from_contour)
+                .collect();
+            session.paths = Arc::new(paths);
+            // Points have new IDs after the reload
+            session.selection = crate::editing::Selection::new();
+            session.selected_component = None;
+            session.reload_generation += 1;
+        }
+    }
+
+    /// Decompose every use of the glyph open in the editor, in all
+    /// masters, so it can be edited or removed without affecting the
+    /// glyphs built from it
+    pub fn decompose_uses_of_active_glyph(&mut self) {
+        use crate::editing::session::decompose_uses_in_workspace;
+
+        self.sync_editor_to_workspace();
+        let Some(name) = self
+            .editor_session
+            .as_ref()
+            .and_then(|s| s.active_sort_name.clone())
+        else {
+            return;
+        };
+
+        let mut changed = 0;
+        for workspace_arc in self.master_workspaces() {
+            changed += decompose_uses_in_workspace(&mut write_workspace(&workspace_arc), &name);
+        }
+        tracing::info!("Decomposed '{name}' in {changed} glyphs");
+        self.mark_masters_modified();
+        self.reload_editor_glyph();
+    }
+
+    /// Move the contours with selected points into a new `_part.`
+    /// glyph and reference it back as a component. Every master gets
+    /// the same contours (by index) moved, so the glyph stays
+    /// compatible.
+    pub fn make_component_from_selection(&mut self) {
+        use crate::editing::session::{extract_component, unique_part_name};
+
+        self.sync_editor_to_workspace();
+        let Some(session) = &self.editor_session else {
+            return;
+        };
+        let Some(name) = session.active_sort_name.clone() else {
+            return;
+        };
+        let indices = session.selected_contour_indices();
+        if indices.is_empty() {
+            return;
+        }
+
+        let workspaces = self.master_workspaces();
+        let Some(active) = workspaces.first() else {
+            return;
+        };
+        let part_name = unique_part_name(&read_workspace(active), &name);
+        for workspace_arc in &workspaces {
+            let mut workspace = write_workspace(workspace_arc);
+            let part = workspace
+                .get_glyph_mut(&name)
+                .and_then(|glyph| extract_component(glyph, &indices, &part_name));
+            match part {
+                Some(part) => workspace.update_glyph(&part_name, part),
+                None => tracing::warn!(
+                    "'{name}' in {} doesn't match the selected contours",
+                    workspace.style_name
+                ),
+            }
+        }
+        self.mark_masters_modified();
+        self.reload_editor_glyph();
+    }
+
     /// Sync a session's changes to the workspace
     fn sync_session_to_workspace(&mut self, session: &EditSession) {
         let Some(workspace_arc) = self.active_workspace() else {
@@ -345,4 +345,73 @@ impl AppState {
             }
         }
     }
+
+    /// Set smooth flags from tangent collinearity in every glyph of
+    /// every master, snapping nearly-collinear handles with `snap`
+    ///
+    /// The glyph open in the editor goes through its session so the
+    /// change isn't lost on the next sync.
+    pub fn detect_smooth_points_in_font(&mut self, snap: bool) {
+        use crate::editing::session::detect_smooth_in_workspace_glyph;
+        use crate::settings::smooth_detection::ANGLE_TOLERANCE;
+
+        let active = match &mut self.editor_session {
+            Some(session) => {
+                if session.detect_smooth_points(ANGLE_TOLERANCE, snap) {
+                    session.sync_to_workspace();
+                }
+                session.active_sort_name.clone()
+            }
+            None => None,
+        };
+
+        // The active master comes first, and its open glyph was done
+        // through the session above
+        let mut changed = 0;
+        for (index, workspace_arc) in self.master_workspaces().iter().enumerate() {
+            let mut workspace = write_workspace(workspace_arc);
+            for name in workspace.glyph_names() {
+                if index == 0 && Some(&name) == active.as_ref() {
+                    continue;
+                }
+                changed +=
+                    detect_smooth_in_workspace_glyph(&mut workspace, &name, ANGLE_TOLERANCE, snap);
+            }
+        }
+        tracing::info!("Smooth detection changed {changed} points");
+        self.mark_masters_modified();
+    }
+
+    /// Normalize start points, contour order and direction in every
+    /// glyph of every master. Glyphs whose masters aren't compatible
+    /// are skipped, so interpolation keeps working.
+    ///
+    /// The glyph open in the editor is synced first and reloaded
+    /// afterwards, since its points may have moved around.
+    pub fn normalize_contours_in_font(
+        &mut self,
+        convention: crate::editing::normalize::DirectionConvention,
+    ) {
+        use crate::editing::normalize::{NormalizeOptions, normalize_font};
+
+        if let Some(session) = &mut self.editor_session {
+            session.sync_to_workspace();
+        }
+
+        // The active master comes first: it's the one the plan for
+        // each glyph is derived from
+        let workspaces = self.master_workspaces();
+        let report = normalize_font(&workspaces, NormalizeOptions::all(convention));
+        tracing::info!("Normalized contours in {} glyphs", report.changed);
+        if !report.skipped.is_empty() {
+            tracing::warn!(
+                "Skipped {} incompatible glyphs: {}",
+                report.skipped.len(),
+                report.skipped.join(", ")
+            );
+        }
+
+        self.mark_masters_modified();
+        self.reload_editor_glyph();
+    }
 }
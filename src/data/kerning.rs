@@ -1,31 +1,21 @@
 // Copyright 2025 the Runebender Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
-//! Kerning and glyph property operations for AppState
+//! Kerning, kerning table, kerning group and glyph property
+//! operations for AppState
 
-use super::AppState;
-use crate::model::workspace::Workspace;
+use super::metrics::outline_extent;
+use super::{AppState, GroupDrag, KerningImport, KerningView, Tab};
+use crate::model::auto_kern::{self, AutoKernOptions};
+use crate::model::kerning_audit::{self, AuditOptions};
+use crate::model::kerning_groups::{self, GroupError, GroupSide};
+use crate::model::kerning_io::{self, ImportMode};
+use crate::model::kerning_table::GroupIndex;
+use crate::model::workspace::{Glyph, Workspace};
 use crate::model::{read_workspace, write_workspace};
+use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 
-/// Min/max x over every point of every path in the session, or
-/// None when the glyph has no outlines. Point-based (not curve
-/// bbox) to match the sidebearing values shown in the editor.
-fn outline_extent(
-    session: &crate::editing::EditSession,
-) -> Option<(f64, f64)> {
-    let mut min_x: Option<f64> = None;
-    let mut max_x: Option<f64> = None;
-    for path in session.paths.iter() {
-        for point in path.points().iter() {
-            let x = point.point.x;
-            min_x = Some(min_x.map_or(x, |m: f64| m.min(x)));
-            max_x = Some(max_x.map_or(x, |m: f64| m.max(x)));
-        }
-    }
-    Some((min_x?, max_x?))
-}
-
 #[allow(dead_code)]
 impl AppState {
     /// Set the left sidebearing: shift the whole outline so its
@@ -52,6 +42,7 @@ impl AppState {
         let width = glyph.width;
         Self::update_sort_advance(session, width);
         Self::sync_session(workspace_arc, session);
+        self.propagate_active_metrics();
     }
 
     /// Set the right sidebearing by adjusting the advance width.
@@ -76,6 +67,7 @@ impl AppState {
         let width = glyph.width;
         Self::update_sort_advance(session, width);
         Self::sync_session(workspace_arc, session);
+        self.propagate_active_metrics();
     }
 
     /// Set the glyph's unicode from a hex string ("0041"); an
@@ -179,6 +171,7 @@ impl AppState {
             let updated_glyph = session.to_glyph();
             write_workspace(&workspace_arc).update_glyph(active_name, updated_glyph);
         }
+        self.propagate_active_metrics();
     }
 
     /// Update the glyph's left kerning group
@@ -398,6 +391,621 @@ impl AppState {
     }
 }
 
+/// Kerning table tab
+impl AppState {
+    /// Show the kerning table
+    pub fn open_kerning_table(&mut self) {
+        self.kerning_page = 0;
+        self.active_tab = Tab::Kerning;
+    }
+
+    /// Leave the kerning table for the glyph grid
+    pub fn close_kerning_table(&mut self) {
+        self.active_tab = Tab::GlyphGrid;
+    }
+
+    /// Set the first- or second-side filter of the kerning table
+    pub fn set_kerning_filter(&mut self, second: bool, filter: String) {
+        let filter = filter.trim().to_string();
+        if second {
+            self.kerning_second_filter = filter;
+        } else {
+            self.kerning_first_filter = filter;
+        }
+        self.kerning_page = 0;
+    }
+
+    /// Set a pair's value from the kerning table. Input that isn't a
+    /// number (such as a lone "-" while typing) is ignored rather than
+    /// deleting the pair.
+    pub fn set_kern_pair_value(&mut self, first: &str, second: &str, value: String) {
+        if let Ok(value) = value.trim().parse::<f64>() {
+            self.insert_kern_pair(first, second, value);
+        }
+    }
+
+    /// Delete a pair. Deleting an exception is how it is removed: the
+    /// group kerning it overrode applies again.
+    pub fn delete_kern_pair(&mut self, first: &str, second: &str) {
+        let Some(workspace_arc) = self.active_workspace() else {
+            return;
+        };
+        let mut workspace = write_workspace(&workspace_arc);
+        if let Some(seconds) = workspace.kerning.get_mut(first) {
+            seconds.remove(second);
+            if seconds.is_empty() {
+                workspace.kerning.remove(first);
+            }
+        }
+    }
+
+    /// Make an exception for a glyph pair covered by a group pair,
+    /// starting from the group pair's value
+    pub fn make_kern_exception(&mut self, first_glyph: &str, second_glyph: &str, value: f64) {
+        self.insert_kern_pair(first_glyph, second_glyph, value);
+    }
+
+    fn insert_kern_pair(&mut self, first: &str, second: &str, value: f64) {
+        let Some(workspace_arc) = self.active_workspace() else {
+            return;
+        };
+        write_workspace(&workspace_arc)
+            .kerning
+            .entry(first.to_string())
+            .or_default()
+            .insert(second.to_string(), value);
+    }
+}
+
+/// Kerning audit
+impl AppState {
+    /// Audit the active master's kerning
+    pub fn run_kerning_audit(&mut self) {
+        let Some(workspace_arc) = self.active_workspace() else {
+            return;
+        };
+        let options = self.kerning_audit_options;
+        let issues = kerning_audit::audit_kerning(&read_workspace(&workspace_arc), options);
+        tracing::info!("Kerning audit: {} issue(s)", issues.len());
+        self.kerning_audit = Some(issues);
+        self.kerning_page = 0;
+    }
+
+    /// Set an audit threshold: the minimum outline distance, or the
+    /// maximum value as a percentage of glyph width
+    pub fn set_kerning_audit_threshold(&mut self, percent: bool, value: String) {
+        let Ok(value) = value.trim().parse::<f64>() else {
+            return;
+        };
+        let AuditOptions {
+            min_distance,
+            max_percent,
+        } = &mut self.kerning_audit_options;
+        if percent {
+            *max_percent = value.max(0.0);
+        } else {
+            *min_distance = value;
+        }
+    }
+
+    /// Open a glyph pair in the editor's text buffer with the text
+    /// tool active, so it can be kerned by shift-dragging the second
+    /// glyph or through the right kern field
+    pub fn open_kern_pair(&mut self, first: &str, second: &str) {
+        self.sync_editor_to_workspace();
+        self.open_editor(first.to_string());
+        let Some(session) = &mut self.editor_session else {
+            return;
+        };
+        if session.glyph.name != first || !session.add_glyph_to_buffer(second) {
+            return;
+        }
+        self.set_editor_tool(crate::tools::ToolId::Text);
+    }
+}
+
+/// Kerning suggestions. Suggestions are made for and applied to the
+/// active master.
+impl AppState {
+    pub fn set_auto_kern_glyphs(&mut self, glyphs: String) {
+        self.auto_kern_glyphs = glyphs;
+    }
+
+    pub fn set_auto_kern_references(&mut self, references: String) {
+        self.auto_kern_references = references;
+    }
+
+    /// Set one of the suggestion settings from a text field. Input
+    /// that isn't a number is ignored.
+    pub fn set_auto_kern_option(
+        &mut self,
+        value: String,
+        field: impl FnOnce(&mut AutoKernOptions) -> &mut f64,
+    ) {
+        if let Ok(value) = value.trim().parse::<f64>() {
+            *field(&mut self.auto_kern_options) = value.max(0.0);
+        }
+    }
+
+    /// Suggest kerning for the glyphs selected in the grid
+    pub fn auto_kern_grid_selection(&mut self) {
+        let mut names: Vec<_> = self.selected_glyphs.iter().cloned().collect();
+        names.sort();
+        self.auto_kern_glyphs = names.join(" ");
+    }
+
+    /// Measure the glyph list and hold the suggestions for review
+    pub fn run_auto_kern(&mut self) {
+        let Some(workspace_arc) = self.active_workspace() else {
+            return;
+        };
+        self.sync_editor_to_workspace();
+        let workspace = read_workspace(&workspace_arc);
+        let glyphs = auto_kern::parse_glyph_list(&self.auto_kern_glyphs, &workspace);
+        let references = auto_kern::parse_pairs(&self.auto_kern_references, &workspace);
+        let result =
+            auto_kern::suggest_kerning(&workspace, &glyphs, &references, self.auto_kern_options);
+        drop(workspace);
+
+        self.kerning_page = 0;
+        match result {
+            Ok(suggested) => {
+                tracing::info!(
+                    "Kerning suggestions: {} pair(s) for a gap of {:.0}",
+                    suggested.suggestions.len(),
+                    suggested.target
+                );
+                self.auto_kern_message = None;
+                self.auto_kern = Some(suggested);
+            }
+            Err(e) => {
+                self.auto_kern_message = Some(e.to_string());
+                self.auto_kern = None;
+            }
+        }
+    }
+
+    /// Accept or reject one suggestion
+    pub fn toggle_kern_suggestion(&mut self, index: usize) {
+        if let Some(suggestion) = self
+            .auto_kern
+            .as_mut()
+            .and_then(|a| a.suggestions.get_mut(index))
+        {
+            suggestion.accepted = !suggestion.accepted;
+        }
+    }
+
+    /// Accept or reject every suggestion
+    pub fn accept_all_kern_suggestions(&mut self, accepted: bool) {
+        if let Some(auto_kern) = &mut self.auto_kern {
+            for suggestion in &mut auto_kern.suggestions {
+                suggestion.accepted = accepted;
+            }
+        }
+    }
+
+    /// Write the accepted suggestions to the active master as glyph
+    /// pairs
+    pub fn apply_kern_suggestions(&mut self) {
+        let (Some(auto_kern), Some(workspace_arc)) =
+            (self.auto_kern.take(), self.active_workspace())
+        else {
+            return;
+        };
+        let mut applied = 0;
+        {
+            let mut workspace = write_workspace(&workspace_arc);
+            for suggestion in auto_kern.suggestions.into_iter().filter(|s| s.accepted) {
+                workspace
+                    .kerning
+                    .entry(suggestion.first)
+                    .or_default()
+                    .insert(suggestion.second, suggestion.value);
+                applied += 1;
+            }
+        }
+        if let Some(ds) = &mut self.designspace {
+            ds.mark_active_modified();
+        }
+        self.auto_kern_message = Some(format!(
+            "Applied {applied} pair(s) to {}",
+            self.kerning_master_name()
+        ));
+    }
+
+    pub fn discard_kern_suggestions(&mut self) {
+        self.auto_kern = None;
+        self.auto_kern_message = None;
+    }
+}
+
+/// Kerning import and export. Both work on the active master.
+impl AppState {
+    /// Name of the master imports and exports apply to
+    pub fn kerning_master_name(&self) -> String {
+        match (&self.designspace, self.active_workspace()) {
+            (Some(ds), _) => ds.active_master().style_name.clone(),
+            (None, Some(workspace_arc)) => read_workspace(&workspace_arc).style_name.clone(),
+            (None, None) => String::new(),
+        }
+    }
+
+    /// Save the active master's kerning and groups as CSV or, with
+    /// `feature`, as a feature file
+    pub fn export_kerning(&mut self, feature: bool) {
+        let Some(workspace_arc) = self.active_workspace() else {
+            return;
+        };
+        let (text, file_name) = {
+            let workspace = read_workspace(&workspace_arc);
+            let text = if feature {
+                kerning_io::to_feature(&workspace.kerning, &workspace.groups)
+            } else {
+                kerning_io::to_csv(&workspace.kerning, &workspace.groups)
+            };
+            let extension = if feature { "fea" } else { "csv" };
+            let name = format!(
+                "{}-{}-kerning.{extension}",
+                workspace.family_name, workspace.style_name
+            );
+            (text, name.replace(' ', ""))
+        };
+        let dialog = rfd::FileDialog::new()
+            .set_title("Export Kerning")
+            .set_file_name(&file_name);
+        let dialog = if feature {
+            dialog.add_filter("Feature File", &["fea"])
+        } else {
+            dialog.add_filter("CSV", &["csv"])
+        };
+        let Some(path) = dialog.save_file() else {
+            return;
+        };
+        self.kerning_io_message = Some(match std::fs::write(&path, text) {
+            Ok(()) => format!(
+                "Exported {} kerning to {}",
+                self.kerning_master_name(),
+                path.display()
+            ),
+            Err(e) => format!("Export failed: {e}"),
+        });
+    }
+
+    /// Read kerning from a CSV or feature file and hold it for review
+    pub fn import_kerning(&mut self, feature: bool) {
+        let dialog = rfd::FileDialog::new().set_title("Import Kerning");
+        let dialog = if feature {
+            dialog.add_filter("Feature File", &["fea"])
+        } else {
+            dialog.add_filter("CSV", &["csv", "txt"])
+        };
+        let Some(path) = dialog.pick_file() else {
+            return;
+        };
+        let parsed = std::fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|text| {
+                if feature {
+                    kerning_io::from_feature(&text)
+                } else {
+                    kerning_io::from_csv(&text)
+                }
+                .map_err(|e| e.to_string())
+            });
+        let source = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        match parsed {
+            Ok(data) => {
+                self.kerning_io_message = None;
+                self.kerning_import = Some(KerningImport { source, data });
+            }
+            Err(e) => {
+                self.kerning_io_message = Some(format!("Can't import {source}: {e}"));
+                self.kerning_import = None;
+            }
+        }
+    }
+
+    pub fn set_kerning_import_mode(&mut self, mode: ImportMode) {
+        self.kerning_import_mode = mode;
+    }
+
+    /// Apply the reviewed import to the active master
+    pub fn apply_kerning_import(&mut self) {
+        let (Some(import), Some(workspace_arc)) =
+            (self.kerning_import.take(), self.active_workspace())
+        else {
+            return;
+        };
+        {
+            let mut workspace = write_workspace(&workspace_arc);
+            let Workspace {
+                kerning, groups, ..
+            } = &mut *workspace;
+            kerning_io::apply_import(kerning, groups, &import.data, self.kerning_import_mode);
+        }
+        self.sync_glyph_groups(&workspace_arc);
+        if let Some(ds) = &mut self.designspace {
+            ds.mark_active_modified();
+        }
+        self.kerning_io_message = Some(format!(
+            "Imported {} into {}",
+            import.source,
+            self.kerning_master_name()
+        ));
+    }
+
+    pub fn discard_kerning_import(&mut self) {
+        self.kerning_import = None;
+    }
+}
+
+/// Kerning group manager. Groups are edited in every master so the
+/// masters keep the same groups.
+impl AppState {
+    /// Switch the kerning tab between the pair table, the groups and
+    /// the audit
+    pub fn set_kerning_view(&mut self, view: KerningView) {
+        self.kerning_view = view;
+        self.kerning_page = 0;
+        self.kerning_group_drag = None;
+        self.kerning_group_message = None;
+    }
+
+    /// List the first- or second-side groups
+    pub fn set_kerning_group_side(&mut self, side: GroupSide) {
+        self.kerning_group_side = side;
+        self.kerning_group_drag = None;
+        self.kerning_group_page = 0;
+    }
+
+    /// Create a group named after the name field
+    pub fn create_kerning_group(&mut self) {
+        let (side, name) = (self.kerning_group_side, self.kerning_group_name.clone());
+        if self.edit_kerning_groups(|_, groups| {
+            kerning_groups::create_group(groups, side, &name).map(|_| ())
+        }) {
+            self.kerning_group_name.clear();
+        }
+    }
+
+    /// Rename a group to the name field, rewriting its pairs
+    pub fn rename_kerning_group(&mut self, group: &str) {
+        let Some(side) = GroupSide::of(group) else {
+            return;
+        };
+        let new = side.group_name(&self.kerning_group_name);
+        if self.edit_kerning_groups(|kerning, groups| {
+            kerning_groups::rename_group(kerning, groups, group, &new).map(|_| ())
+        }) {
+            self.retarget_glyph_groups(group, Some(&new));
+            self.kerning_group_name.clear();
+        }
+    }
+
+    /// Delete a group along with its pairs
+    pub fn delete_kerning_group(&mut self, group: &str) {
+        self.edit_kerning_groups(|kerning, groups| {
+            kerning_groups::delete_group(kerning, groups, group);
+            Ok(())
+        });
+        self.retarget_glyph_groups(group, None);
+        self.kerning_group_drag = None;
+    }
+
+    /// Add the glyphs named in the name field (separated by spaces)
+    /// to a group, moving them out of their other groups on that side
+    pub fn add_glyphs_to_kerning_group(&mut self, group: &str) {
+        let glyphs: Vec<String> = self
+            .kerning_group_name
+            .split_whitespace()
+            .map(String::from)
+            .collect();
+        if glyphs.is_empty() {
+            return;
+        }
+        if self.edit_kerning_groups(|_, groups| {
+            glyphs
+                .iter()
+                .try_for_each(|glyph| kerning_groups::move_glyph(groups, glyph, group))
+        }) {
+            for glyph in &glyphs {
+                self.set_glyph_group(glyph, group);
+            }
+            self.kerning_group_name.clear();
+        }
+    }
+
+    /// Pick up a glyph or group, or put it back down when it is
+    /// already held
+    pub fn pick_up_in_kerning_groups(&mut self, item: GroupDrag) {
+        if self.kerning_group_drag.as_ref() == Some(&item) {
+            self.kerning_group_drag = None;
+        } else {
+            self.kerning_group_drag = Some(item);
+        }
+    }
+
+    /// Drop what is held on a group: a glyph moves into it, a group
+    /// is merged into it
+    pub fn drop_on_kerning_group(&mut self, group: &str) {
+        let Some(item) = self.kerning_group_drag.take() else {
+            return;
+        };
+        match item {
+            GroupDrag::Glyph(glyph) => {
+                if self.edit_kerning_groups(|_, groups| {
+                    kerning_groups::move_glyph(groups, &glyph, group)
+                }) {
+                    self.set_glyph_group(&glyph, group);
+                }
+            }
+            GroupDrag::Group(from) if from != group => {
+                if self.edit_kerning_groups(|kerning, groups| {
+                    kerning_groups::merge_groups(kerning, groups, &from, group)
+                }) {
+                    self.retarget_glyph_groups(&from, Some(group));
+                }
+            }
+            GroupDrag::Group(_) => {}
+        }
+    }
+
+    /// Take the held glyph out of every group on the listed side
+    pub fn ungroup_held_glyph(&mut self) {
+        let Some(GroupDrag::Glyph(glyph)) = self.kerning_group_drag.take() else {
+            return;
+        };
+        let side = self.kerning_group_side;
+        self.edit_kerning_groups(|_, groups| {
+            kerning_groups::remove_glyph(groups, &glyph, side);
+            Ok(())
+        });
+        self.update_glyph_side_group(&glyph, side, |group| *group = None);
+    }
+
+    /// Apply a group edit to every master. The active master goes
+    /// first; if it refuses the edit, nothing changes and the reason
+    /// is shown. Returns whether the edit was made.
+    fn edit_kerning_groups(
+        &mut self,
+        edit: impl Fn(
+            &mut HashMap<String, HashMap<String, f64>>,
+            &mut HashMap<String, Vec<String>>,
+        ) -> Result<(), GroupError>,
+    ) -> bool {
+        for (i, workspace_arc) in self.master_workspaces().iter().enumerate() {
+            let mut workspace = write_workspace(workspace_arc);
+            let Workspace {
+                kerning, groups, ..
+            } = &mut *workspace;
+            if let Err(err) = edit(kerning, groups) {
+                if i == 0 {
+                    self.kerning_group_message = Some(err.to_string());
+                    return false;
+                }
+                tracing::warn!("Group edit skipped in a master: {err}");
+            }
+        }
+        self.kerning_group_message = None;
+        self.mark_masters_modified();
+        true
+    }
+
+    /// Point a glyph that moved into `group` at it, in every master
+    /// and the editor
+    fn set_glyph_group(&mut self, glyph: &str, group: &str) {
+        let Some(side) = GroupSide::of(group) else {
+            return;
+        };
+        self.update_glyph_side_group(glyph, side, |field| *field = Some(group.to_string()));
+    }
+
+    /// Update one glyph's group on a side in every master, and in the
+    /// editor when it's the glyph being edited, so the next sync from
+    /// the editor doesn't undo the change
+    fn update_glyph_side_group(
+        &mut self,
+        glyph: &str,
+        side: GroupSide,
+        update: impl Fn(&mut Option<String>),
+    ) {
+        let masters = self.master_workspaces();
+        self.update_glyph_side_group_in(&masters, glyph, side, update);
+    }
+
+    /// `update_glyph_side_group` for the listed masters only
+    fn update_glyph_side_group_in(
+        &mut self,
+        masters: &[Arc<RwLock<Workspace>>],
+        glyph: &str,
+        side: GroupSide,
+        update: impl Fn(&mut Option<String>),
+    ) {
+        for workspace_arc in masters {
+            if let Some(g) = write_workspace(workspace_arc).get_glyph_mut(glyph) {
+                update(side_group(g, side));
+            }
+        }
+        if let Some(session) = &mut self.editor_session
+            && session.active_sort_name.as_deref() == Some(glyph)
+        {
+            update(side_group(Arc::make_mut(&mut session.glyph), side));
+        }
+    }
+
+    /// Point each glyph of a master at the groups that list it, after
+    /// the master's groups were replaced wholesale
+    fn sync_glyph_groups(&mut self, workspace_arc: &Arc<RwLock<Workspace>>) {
+        let changes: Vec<_> = {
+            let workspace = read_workspace(workspace_arc);
+            let index = GroupIndex::new(&workspace.groups);
+            workspace
+                .glyphs
+                .values()
+                .flat_map(|glyph| {
+                    [
+                        (
+                            GroupSide::First,
+                            &glyph.left_group,
+                            index.first_group(&glyph.name),
+                        ),
+                        (
+                            GroupSide::Second,
+                            &glyph.right_group,
+                            index.second_group(&glyph.name),
+                        ),
+                    ]
+                    .into_iter()
+                    .filter(|(_, current, group)| current.as_deref() != *group)
+                    .map(move |(side, _, group)| {
+                        (glyph.name.clone(), side, group.map(String::from))
+                    })
+                })
+                .collect()
+        };
+        let masters = [Arc::clone(workspace_arc)];
+        for (glyph, side, group) in changes {
+            self.update_glyph_side_group_in(&masters, &glyph, side, |field| {
+                *field = group.clone();
+            });
+        }
+    }
+
+    /// Point glyphs that name a renamed, merged or deleted group at
+    /// its replacement (or at no group)
+    fn retarget_glyph_groups(&mut self, old: &str, new: Option<&str>) {
+        let retarget = |group: &mut Option<String>| {
+            if group.as_deref() == Some(old) {
+                *group = new.map(String::from);
+            }
+        };
+        for workspace_arc in self.master_workspaces() {
+            for glyph in write_workspace(&workspace_arc).glyphs.values_mut() {
+                retarget(&mut glyph.left_group);
+                retarget(&mut glyph.right_group);
+            }
+        }
+        if let Some(session) = &mut self.editor_session {
+            let glyph = Arc::make_mut(&mut session.glyph);
+            retarget(&mut glyph.left_group);
+            retarget(&mut glyph.right_group);
+        }
+    }
+}
+
+/// A glyph's group on one side, matching the `public.kern1` /
+/// `public.kern2` glyph lib keys the groups are saved under
+fn side_group(glyph: &mut Glyph, side: GroupSide) -> &mut Option<String> {
+    match side {
+        GroupSide::First => &mut glyph.left_group,
+        GroupSide::Second => &mut glyph.right_group,
+    }
+}
+
 /// Parse a kern value string and update (or remove) the kerning pair.
 ///
 /// If `new_value` is empty or "-", removes the pair. Otherwise parses
@@ -427,3 +1035,83 @@ fn update_kern_pair(
         .or_default()
         .insert(second_name, kern_value);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::kerning_io::KerningData;
+
+    #[test]
+    fn group_moves_update_glyph_groups() {
+        let mut workspace =
+            Workspace::from_glyphs([Glyph::for_test("O", 600.0), Glyph::for_test("Q", 600.0)]);
+        workspace.groups = HashMap::from([
+            ("public.kern1.O".to_string(), vec!["O".to_string()]),
+            ("public.kern2.O".to_string(), Vec::new()),
+        ]);
+        let workspace = Arc::new(RwLock::new(workspace));
+        let mut state = AppState::new();
+        state.workspace = Some(Arc::clone(&workspace));
+
+        state.kerning_group_name = "Q".to_string();
+        state.add_glyphs_to_kerning_group("public.kern1.O");
+        state.pick_up_in_kerning_groups(GroupDrag::Glyph("O".to_string()));
+        state.drop_on_kerning_group("public.kern2.O");
+        {
+            let ws = read_workspace(&workspace);
+            assert_eq!(ws.groups["public.kern1.O"], ["O", "Q"]);
+            let q = &ws.glyphs["Q"];
+            assert_eq!(q.left_group.as_deref(), Some("public.kern1.O"));
+            let o = &ws.glyphs["O"];
+            assert_eq!(o.right_group.as_deref(), Some("public.kern2.O"));
+        }
+
+        state.kerning_group_side = GroupSide::First;
+        state.pick_up_in_kerning_groups(GroupDrag::Glyph("Q".to_string()));
+        state.ungroup_held_glyph();
+        let ws = read_workspace(&workspace);
+        assert_eq!(ws.groups["public.kern1.O"], ["O"]);
+        assert_eq!(ws.glyphs["Q"].left_group, None);
+    }
+
+    #[test]
+    fn kerning_import_updates_glyph_groups() {
+        let mut o = Glyph::for_test("O", 600.0);
+        o.left_group = Some("public.kern1.O".to_string());
+        let mut d = Glyph::for_test("D", 600.0);
+        d.left_group = Some("public.kern1.O".to_string());
+        let mut workspace = Workspace::from_glyphs([o, d, Glyph::for_test("Q", 600.0)]);
+        workspace.groups = HashMap::from([(
+            "public.kern1.O".to_string(),
+            vec!["O".to_string(), "D".to_string()],
+        )]);
+        let workspace = Arc::new(RwLock::new(workspace));
+        let mut state = AppState::new();
+        state.workspace = Some(Arc::clone(&workspace));
+
+        state.kerning_import = Some(KerningImport {
+            source: "round.csv".to_string(),
+            data: KerningData {
+                groups: HashMap::from([(
+                    "public.kern1.round".to_string(),
+                    vec!["O".to_string(), "Q".to_string()],
+                )]),
+                ..Default::default()
+            },
+        });
+        state.set_kerning_import_mode(ImportMode::Replace);
+        state.apply_kerning_import();
+
+        let ws = read_workspace(&workspace);
+        assert_eq!(
+            ws.glyphs["O"].left_group.as_deref(),
+            Some("public.kern1.round")
+        );
+        assert_eq!(
+            ws.glyphs["Q"].left_group.as_deref(),
+            Some("public.kern1.round")
+        );
+        // Its group was replaced
+        assert_eq!(ws.glyphs["D"].left_group, None);
+    }
+}
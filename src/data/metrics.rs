@@ -0,0 +1,162 @@
+// Copyright 2026 the Runebender Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Metrics key operations for AppState, and the sidebearings of the
+//! glyph open in the editor
+
+use super::AppState;
+use crate::editing::EditSession;
+use crate::model::metrics_keys::MetricSide;
+use crate::model::{read_workspace, write_workspace};
+use std::sync::Arc;
+
+/// Min/max x over every point of every path in the session, or
+/// None when the glyph has no outlines. Point-based (not curve
+/// bbox) to match the sidebearing values shown in the editor.
+pub(super) fn outline_extent(session: &EditSession) -> Option<(f64, f64)> {
+    let mut min_x: Option<f64> = None;
+    let mut max_x: Option<f64> = None;
+    for path in session.paths.iter() {
+        for point in path.points().iter() {
+            let x = point.point.x;
+            min_x = Some(min_x.map_or(x, |m: f64| m.min(x)));
+            max_x = Some(max_x.map_or(x, |m: f64| m.max(x)));
+        }
+    }
+    Some((min_x?, max_x?))
+}
+
+/// The glyph open in the editor, its advance width and its outline
+/// extent: what the glyphs keyed to it follow
+pub(super) fn active_metrics(session: &EditSession) -> (Option<&str>, f64, Option<(f64, f64)>) {
+    (
+        session.active_sort_name.as_deref(),
+        session.glyph.width,
+        outline_extent(session),
+    )
+}
+
+/// Metrics keys. A key is set on the glyph in every master, and the
+/// glyphs keyed to a glyph follow it when its metrics change.
+impl AppState {
+    /// Set a metrics key of the glyph open in the editor (an empty
+    /// key clears it) and apply the glyph's keys
+    pub fn update_metrics_key(&mut self, side: MetricSide, key: String) {
+        let Some(session) = &mut self.editor_session else {
+            return;
+        };
+        let Some(name) = session.active_sort_name.clone() else {
+            return;
+        };
+        if session.glyph.metrics_keys.get(side).unwrap_or("") == key.trim() {
+            return;
+        }
+        Arc::make_mut(&mut session.glyph)
+            .metrics_keys
+            .set(side, &key);
+        session.sync_to_workspace();
+
+        for workspace_arc in self.master_workspaces() {
+            if let Some(glyph) = write_workspace(&workspace_arc).get_glyph_mut(&name) {
+                glyph.metrics_keys.set(side, &key);
+            }
+        }
+        self.apply_active_metrics_keys();
+    }
+
+    /// Set the metrics of the glyph open in the editor from its keys,
+    /// in every master
+    pub fn apply_active_metrics_keys(&mut self) {
+        let Some(session) = &mut self.editor_session else {
+            return;
+        };
+        let Some(name) = session.active_sort_name.clone() else {
+            return;
+        };
+        session.sync_to_workspace();
+
+        let mut changed = Vec::new();
+        for (index, workspace_arc) in self.master_workspaces().iter().enumerate() {
+            let mut workspace = write_workspace(workspace_arc);
+            if workspace.apply_metrics_keys(&name) && index == 0 {
+                changed.push(name.clone());
+            }
+            let followers = workspace.propagate_metrics(&name);
+            if index == 0 {
+                changed.extend(followers);
+            }
+        }
+        self.mark_masters_modified();
+        if changed.contains(&name) {
+            self.reload_editor_glyph();
+        }
+        self.refresh_sort_advances(&changed);
+    }
+
+    /// Apply the metrics keys of every glyph in every master
+    pub fn update_all_metrics_from_keys(&mut self) {
+        if let Some(session) = &mut self.editor_session {
+            session.sync_to_workspace();
+        }
+
+        let mut changed = Vec::new();
+        for (index, workspace_arc) in self.master_workspaces().iter().enumerate() {
+            let mut workspace = write_workspace(workspace_arc);
+            let updated = workspace.update_all_metrics();
+            tracing::info!(
+                "Updated metrics of {} glyphs in {}",
+                updated.len(),
+                workspace.style_name
+            );
+            if index == 0 {
+                changed = updated;
+            }
+        }
+        self.mark_masters_modified();
+        let active = self
+            .editor_session
+            .as_ref()
+            .and_then(|session| session.active_sort_name.clone());
+        if active.is_some_and(|name| changed.contains(&name)) {
+            self.reload_editor_glyph();
+        }
+        self.refresh_sort_advances(&changed);
+    }
+
+    /// Carry a metrics change of the glyph open in the editor to the
+    /// glyphs keyed to it, in the active master
+    pub(super) fn propagate_active_metrics(&mut self) {
+        let Some(name) = self
+            .editor_session
+            .as_ref()
+            .and_then(|session| session.active_sort_name.clone())
+        else {
+            return;
+        };
+        let Some(workspace_arc) = self.active_workspace() else {
+            return;
+        };
+        let changed = write_workspace(&workspace_arc).propagate_metrics(&name);
+        if !changed.is_empty() {
+            tracing::debug!("Metrics of {name} carried to {}", changed.join(", "));
+            self.refresh_sort_advances(&changed);
+        }
+    }
+
+    /// Re-read the advance width of the text buffer's sorts of the
+    /// listed glyphs from the active master
+    pub(super) fn refresh_sort_advances(&mut self, names: &[String]) {
+        let Some(workspace_arc) = self.active_workspace() else {
+            return;
+        };
+        let Some(session) = &mut self.editor_session else {
+            return;
+        };
+        let workspace = read_workspace(&workspace_arc);
+        for name in names {
+            if let Some(glyph) = workspace.get_glyph(name) {
+                session.set_sort_advance(name, glyph.width);
+            }
+        }
+    }
+}
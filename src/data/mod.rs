@@ -7,15 +7,22 @@
 //! selection, and window metadata. Every UI rebuild reads from `AppState`;
 //! mutations happen in event callbacks and propagate through the Xilem view
 //! tree. Sub-modules split the methods by domain: file I/O, grid navigation,
-//! editor lifecycle, and kerning.
+//! editor lifecycle, kerning, metrics keys, and autospacing.
 
 mod editor;
 mod file_io;
 mod grid;
 mod kerning;
+mod metrics;
+mod spacing;
 
 use crate::components::GlyphCategory;
 use crate::editing::EditSession;
+use crate::model::auto_kern::{AutoKern, AutoKernOptions};
+use crate::model::auto_space::{AutoSpace, AutoSpaceOptions};
+use crate::model::kerning_audit::{AuditIssue, AuditOptions};
+use crate::model::kerning_groups::GroupSide;
+use crate::model::kerning_io::{ImportMode, KerningData};
 use crate::model::workspace::{self, Workspace};
 use std::collections::HashSet;
 use std::path::PathBuf;
@@ -31,6 +38,39 @@ pub struct GlyphClipboard {
     pub width: f64,
 }
 
+/// What the kerning tab shows
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KerningView {
+    /// The pair table
+    #[default]
+    Pairs,
+    /// The group manager
+    Groups,
+    /// The kerning audit
+    Audit,
+    /// Kerning import and export
+    Exchange,
+    /// Kerning suggestions from outline distance
+    Suggest,
+}
+
+/// Kerning read from a file, waiting to be applied after its diff
+/// has been reviewed
+#[derive(Debug, Clone)]
+pub struct KerningImport {
+    /// File name the kerning came from
+    pub source: String,
+    pub data: KerningData,
+}
+
+/// What the kerning group manager is carrying between groups: a
+/// glyph picked out of a group, or a whole group to merge
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GroupDrag {
+    Glyph(String),
+    Group(String),
+}
+
 /// Which tab is currently active
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(usize)]
@@ -39,6 +79,8 @@ pub enum Tab {
     GlyphGrid = 0,
     /// Editor view for a specific glyph
     Editor = 1,
+    /// Kerning table listing every pair
+    Kerning = 2,
 }
 
 /// Main application state
@@ -113,6 +155,74 @@ pub struct AppState {
     /// Optional directory of glyph PNGs (from img2glyph) to auto-load
     /// as background images when opening a glyph for editing.
     pub glyph_images_dir: Option<PathBuf>,
+
+    /// Kerning table filters: glyph or group on the first and second
+    /// side
+    pub kerning_first_filter: String,
+    pub kerning_second_filter: String,
+
+    /// Page of the kerning table being shown
+    pub kerning_page: usize,
+
+    /// What the kerning tab shows
+    pub kerning_view: KerningView,
+
+    /// Which side's groups the group manager lists
+    pub kerning_group_side: GroupSide,
+
+    /// Name typed into the group manager, used to create and rename
+    /// groups and to add glyphs to one
+    pub kerning_group_name: String,
+
+    /// Glyph or group picked up in the group manager
+    pub kerning_group_drag: Option<GroupDrag>,
+
+    /// Page of the group manager's group list
+    pub kerning_group_page: usize,
+
+    /// Why the last group edit was refused
+    pub kerning_group_message: Option<String>,
+
+    /// Thresholds of the kerning audit
+    pub kerning_audit_options: AuditOptions,
+
+    /// Findings of the last kerning audit, if one was run
+    pub kerning_audit: Option<Vec<AuditIssue>>,
+
+    /// Kerning import waiting to be applied
+    pub kerning_import: Option<KerningImport>,
+
+    /// Whether an import merges into or replaces the master's kerning
+    pub kerning_import_mode: ImportMode,
+
+    /// Outcome of the last kerning import or export
+    pub kerning_io_message: Option<String>,
+
+    /// Glyphs to suggest kerning for, and the reference pairs the
+    /// target gap is measured from, as typed
+    pub auto_kern_glyphs: String,
+    pub auto_kern_references: String,
+
+    /// Settings of the kerning suggestions
+    pub auto_kern_options: AutoKernOptions,
+
+    /// Suggestions waiting to be reviewed
+    pub auto_kern: Option<AutoKern>,
+
+    /// Why the last suggestion run failed
+    pub auto_kern_message: Option<String>,
+
+    /// Whether the autospacing panel is shown over the grid
+    pub auto_space_open: bool,
+
+    /// Settings of the autospacing, per category
+    pub auto_space_options: AutoSpaceOptions,
+
+    /// Proposed sidebearings waiting to be reviewed
+    pub auto_space: Option<AutoSpace>,
+
+    /// Outcome of the last autospacing run or apply
+    pub auto_space_message: Option<String>,
 }
 
 #[allow(dead_code)]
@@ -142,6 +252,29 @@ impl AppState {
             clipboard: None,
             save_in_progress: Arc::new(AtomicBool::new(false)),
             glyph_images_dir: None,
+            kerning_first_filter: String::new(),
+            kerning_second_filter: String::new(),
+            kerning_page: 0,
+            kerning_view: KerningView::Pairs,
+            kerning_group_side: GroupSide::First,
+            kerning_group_name: String::new(),
+            kerning_group_drag: None,
+            kerning_group_page: 0,
+            kerning_group_message: None,
+            kerning_audit_options: AuditOptions::default(),
+            kerning_audit: None,
+            kerning_import: None,
+            kerning_import_mode: ImportMode::Merge,
+            kerning_io_message: None,
+            auto_kern_glyphs: String::new(),
+            auto_kern_references: "nn oo".to_string(),
+            auto_kern_options: AutoKernOptions::default(),
+            auto_kern: None,
+            auto_kern_message: None,
+            auto_space_open: false,
+            auto_space_options: AutoSpaceOptions::default(),
+            auto_space: None,
+            auto_space_message: None,
         }
     }
 
@@ -0,0 +1,135 @@
+// Copyright 2026 the Runebender Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Autospacing operations for AppState
+
+use super::AppState;
+use crate::model::auto_space::{self, AutoSpaceOptions};
+use crate::model::{read_workspace, write_workspace};
+
+/// Autospacing. Sidebearings are proposed for and applied to the
+/// active master.
+impl AppState {
+    /// Set one of the spacing settings from a text field. Input that
+    /// isn't a number is ignored.
+    pub fn set_auto_space_option(
+        &mut self,
+        value: String,
+        field: impl FnOnce(&mut AutoSpaceOptions) -> &mut f64,
+    ) {
+        if let Ok(value) = value.trim().parse::<f64>() {
+            *field(&mut self.auto_space_options) = value.max(0.0);
+        }
+    }
+
+    pub fn set_auto_space_reference(&mut self, index: usize, reference: String) {
+        if let Some(category) = self.auto_space_options.categories.get_mut(index) {
+            category.reference = reference.trim().to_string();
+        }
+    }
+
+    pub fn set_auto_space_factor(&mut self, index: usize, value: String) {
+        if let (Some(category), Ok(factor)) = (
+            self.auto_space_options.categories.get_mut(index),
+            value.trim().parse::<f64>(),
+        ) {
+            category.factor = factor.max(0.0);
+        }
+    }
+
+    /// Measure the glyphs selected in the grid and hold the proposed
+    /// sidebearings for review
+    pub fn run_auto_space(&mut self) {
+        let Some(workspace_arc) = self.active_workspace() else {
+            return;
+        };
+        self.sync_editor_to_workspace();
+        let mut names: Vec<_> = self.selected_glyphs.iter().cloned().collect();
+        names.sort();
+        if names.is_empty() {
+            self.auto_space = None;
+            self.auto_space_message = Some("Select glyphs in the grid first".to_string());
+            return;
+        }
+
+        let workspace = read_workspace(&workspace_arc);
+        let result = auto_space::propose_spacing(&workspace, &names, &self.auto_space_options);
+        drop(workspace);
+
+        tracing::info!(
+            "Autospacing: {} proposal(s), {} glyph(s) skipped",
+            result.proposals.len(),
+            result.skipped.len()
+        );
+        self.auto_space_message = (!result.skipped.is_empty()).then(|| {
+            let skipped: Vec<_> = result
+                .skipped
+                .iter()
+                .map(|(name, reason)| format!("{name} ({reason})"))
+                .collect();
+            format!("Skipped {}", skipped.join(", "))
+        });
+        self.auto_space = Some(result);
+    }
+
+    /// Accept or reject one proposal
+    pub fn toggle_space_proposal(&mut self, index: usize) {
+        if let Some(proposal) = self
+            .auto_space
+            .as_mut()
+            .and_then(|a| a.proposals.get_mut(index))
+        {
+            proposal.accepted = !proposal.accepted;
+        }
+    }
+
+    /// Accept or reject every proposal
+    pub fn accept_all_space_proposals(&mut self, accepted: bool) {
+        if let Some(auto_space) = &mut self.auto_space {
+            for proposal in &mut auto_space.proposals {
+                proposal.accepted = accepted;
+            }
+        }
+    }
+
+    /// Set the accepted sidebearings in the active master, and carry
+    /// them to the glyphs keyed to the spaced ones
+    pub fn apply_auto_space(&mut self) {
+        let (Some(auto_space), Some(workspace_arc)) =
+            (self.auto_space.take(), self.active_workspace())
+        else {
+            return;
+        };
+        let mut changed = Vec::new();
+        {
+            let mut workspace = write_workspace(&workspace_arc);
+            for proposal in auto_space.proposals.iter().filter(|p| p.accepted) {
+                let name = &proposal.glyph;
+                if workspace.set_sidebearings(name, Some(proposal.left), Some(proposal.right)) {
+                    changed.push(name.clone());
+                }
+                changed.extend(workspace.propagate_metrics(name));
+            }
+        }
+        changed.sort();
+        changed.dedup();
+        if let Some(ds) = &mut self.designspace {
+            ds.mark_active_modified();
+        }
+
+        let active = self
+            .editor_session
+            .as_ref()
+            .and_then(|session| session.active_sort_name.clone());
+        if active.is_some_and(|name| changed.contains(&name)) {
+            self.reload_editor_glyph();
+        }
+        self.refresh_sort_advances(&changed);
+        self.auto_space_message = Some(format!("Spaced {} glyph(s)", changed.len()));
+    }
+
+    pub fn discard_auto_space(&mut self) {
+        self.auto_space = None;
+        self.auto_space_message = None;
+    }
+}
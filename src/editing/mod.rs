@@ -14,6 +14,7 @@ pub mod background_image;
 pub mod compat;
 pub mod hit_test;
 pub mod mouse;
+pub mod normalize;
 pub mod session;
 pub mod quiver;
 pub mod tracing;
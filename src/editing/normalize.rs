@@ -0,0 +1,399 @@
+// Copyright 2026 the Runebender Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Font-wide contour normalization: canonical start points, a stable
+//! contour order and consistent direction.
+//!
+//! The rearrangement for each glyph is worked out once on the
+//! reference master and then applied point-for-point to every master,
+//! so glyphs that interpolated before still interpolate afterwards.
+//! Glyphs whose masters don't already match structurally are skipped
+//! and reported rather than guessed at.
+
+use std::sync::{Arc, RwLock};
+
+use kurbo::{Point, Shape};
+
+use crate::model::workspace::{Contour, PointType, Workspace};
+use crate::model::{read_workspace, write_workspace};
+use crate::path::Path;
+
+/// Which way outer contours should run (y-up)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DirectionConvention {
+    /// Outer contours counter-clockwise, counters clockwise (CFF)
+    #[default]
+    PostScript,
+    /// Outer contours clockwise, counters counter-clockwise
+    TrueType,
+}
+
+/// Which normalizations to run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NormalizeOptions {
+    /// Start closed contours at the on-curve point nearest their
+    /// bottom-left corner
+    pub start_points: bool,
+    /// Sort contours by the left, then bottom edge of their bounds
+    pub contour_order: bool,
+    /// Reverse contours that run against this convention
+    pub direction: Option<DirectionConvention>,
+}
+
+impl NormalizeOptions {
+    /// Every normalization, with directions fixed to `convention`
+    pub fn all(convention: DirectionConvention) -> Self {
+        Self {
+            start_points: true,
+            contour_order: true,
+            direction: Some(convention),
+        }
+    }
+}
+
+/// Outcome of normalizing one glyph across its masters
+#[derive(Debug, Clone)]
+pub enum GlyphNormalization {
+    /// Already normalized
+    Unchanged,
+    /// New contours for each master, in the order they were given
+    Changed(Vec<Vec<Contour>>),
+    /// The masters don't share one contour structure, so no common
+    /// rearrangement exists
+    Incompatible,
+}
+
+/// Summary of a font-wide run
+#[derive(Debug, Clone, Default)]
+pub struct NormalizeReport {
+    /// Glyphs whose contours were rearranged
+    pub changed: usize,
+    /// Glyphs left alone because their masters aren't compatible or
+    /// one master lacks the glyph
+    pub skipped: Vec<String>,
+}
+
+/// How one glyph's contours are rearranged
+#[derive(Debug, Clone, PartialEq)]
+struct ContourPlan {
+    /// Old contour index for each new position
+    order: Vec<usize>,
+    /// Per old contour: reverse it before rotating
+    reverse: Vec<bool>,
+    /// Per old contour: index of the new start point after reversing
+    start: Vec<usize>,
+}
+
+impl ContourPlan {
+    fn is_identity(&self) -> bool {
+        self.order.iter().enumerate().all(|(i, &o)| i == o)
+            && !self.reverse.iter().any(|&r| r)
+            && self.start.iter().all(|&s| s == 0)
+    }
+}
+
+/// Normalize every glyph in the font. `workspaces` holds one workspace
+/// per master, the first being the reference the plan is derived from.
+pub fn normalize_font(
+    workspaces: &[Arc<RwLock<Workspace>>],
+    options: NormalizeOptions,
+) -> NormalizeReport {
+    let mut report = NormalizeReport::default();
+    let Some(reference) = workspaces.first() else {
+        return report;
+    };
+    let names = read_workspace(reference).glyph_names();
+    let mut guards: Vec<_> = workspaces.iter().map(write_workspace).collect();
+
+    for name in names {
+        let masters: Option<Vec<&[Contour]>> = guards
+            .iter()
+            .map(|ws| ws.get_glyph(&name).map(|g| g.contours.as_slice()))
+            .collect();
+        let result = match masters {
+            Some(masters) => normalize_glyph(&masters, options),
+            None => GlyphNormalization::Incompatible,
+        };
+        match result {
+            GlyphNormalization::Unchanged => {}
+            GlyphNormalization::Incompatible => report.skipped.push(name),
+            GlyphNormalization::Changed(contours) => {
+                for (ws, contours) in guards.iter_mut().zip(contours) {
+                    if let Some(glyph) = ws.get_glyph_mut(&name) {
+                        glyph.contours = contours;
+                    }
+                }
+                report.changed += 1;
+            }
+        }
+    }
+    report
+}
+
+/// Normalize one glyph given its contours in each master, the first
+/// being the reference
+pub fn normalize_glyph(masters: &[&[Contour]], options: NormalizeOptions) -> GlyphNormalization {
+    let Some((&reference, others)) = masters.split_first() else {
+        return GlyphNormalization::Unchanged;
+    };
+    if !others.iter().all(|other| compatible(reference, other)) {
+        return GlyphNormalization::Incompatible;
+    }
+
+    let plan = plan(reference, options);
+    if plan.is_identity() {
+        return GlyphNormalization::Unchanged;
+    }
+
+    let normalized: Vec<Vec<Contour>> = masters
+        .iter()
+        .map(|contours| apply(contours, &plan))
+        .collect();
+    if !normalized[1..]
+        .iter()
+        .all(|other| compatible(&normalized[0], other))
+    {
+        return GlyphNormalization::Incompatible;
+    }
+    GlyphNormalization::Changed(normalized)
+}
+
+/// Whether two contour lists have the same counts and point types
+fn compatible(a: &[Contour], b: &[Contour]) -> bool {
+    a.len() == b.len()
+        && a.iter().zip(b).all(|(a, b)| {
+            a.points.len() == b.points.len()
+                && a.points
+                    .iter()
+                    .zip(&b.points)
+                    .all(|(p, q)| p.point_type == q.point_type)
+        })
+}
+
+fn is_closed(contour: &Contour) -> bool {
+    contour
+        .points
+        .first()
+        .is_some_and(|pt| pt.point_type != PointType::Move)
+}
+
+fn is_segment_type(typ: PointType) -> bool {
+    matches!(typ, PointType::Line | PointType::Curve | PointType::QCurve)
+}
+
+/// Work out the rearrangement on the reference master's contours
+fn plan(contours: &[Contour], options: NormalizeOptions) -> ContourPlan {
+    let paths: Vec<_> = contours
+        .iter()
+        .map(|c| Path::from_contour(c).to_bezpath())
+        .collect();
+
+    let reverse: Vec<bool> = (0..contours.len())
+        .map(|i| match options.direction {
+            Some(convention) if is_closed(&contours[i]) => {
+                let area = paths[i].area();
+                if area == 0.0 {
+                    return false;
+                }
+                // Contours inside an odd number of others are counters
+                let inside = contours[i]
+                    .points
+                    .iter()
+                    .find(|pt| pt.point_type != PointType::OffCurve)
+                    .map(|pt| Point::new(pt.x, pt.y))
+                    .map_or(0, |at| {
+                        (0..contours.len())
+                            .filter(|&j| j != i && is_closed(&contours[j]))
+                            .filter(|&j| paths[j].winding(at) != 0)
+                            .count()
+                    });
+                let outer = inside % 2 == 0;
+                let counter_clockwise = outer == (convention == DirectionConvention::PostScript);
+                (area > 0.0) != counter_clockwise
+            }
+            _ => false,
+        })
+        .collect();
+
+    let start = contours
+        .iter()
+        .zip(&reverse)
+        .map(|(contour, &reversed)| {
+            if !options.start_points || !is_closed(contour) {
+                return 0;
+            }
+            let contour = if reversed {
+                reversed_contour(contour)
+            } else {
+                contour.clone()
+            };
+            canonical_start(&contour)
+        })
+        .collect();
+
+    let mut order: Vec<usize> = (0..contours.len()).collect();
+    if options.contour_order {
+        let bounds: Vec<_> = paths.iter().map(|p| p.bounding_box()).collect();
+        order.sort_by(|&a, &b| {
+            bounds[a]
+                .x0
+                .total_cmp(&bounds[b].x0)
+                .then(bounds[a].y0.total_cmp(&bounds[b].y0))
+        });
+    }
+
+    ContourPlan {
+        order,
+        reverse,
+        start,
+    }
+}
+
+/// Index of the on-curve point nearest the contour's bottom-left
+/// corner, the first one winning ties
+fn canonical_start(contour: &Contour) -> usize {
+    let on_curve = || {
+        contour
+            .points
+            .iter()
+            .enumerate()
+            .filter(|(_, pt)| pt.point_type != PointType::OffCurve)
+    };
+    let left = on_curve().map(|(_, pt)| pt.x).fold(f64::INFINITY, f64::min);
+    let bottom = on_curve().map(|(_, pt)| pt.y).fold(f64::INFINITY, f64::min);
+    let corner = Point::new(left, bottom);
+    on_curve()
+        .map(|(i, pt)| (i, Point::new(pt.x, pt.y).distance(corner)))
+        .fold(None, |best: Option<(usize, f64)>, (i, d)| match best {
+            Some((_, best_d)) if best_d <= d => best,
+            _ => Some((i, d)),
+        })
+        .map_or(0, |(i, _)| i)
+}
+
+/// A closed contour run backwards from the same first point. Each
+/// on-curve point takes the segment type of the on-curve point after
+/// it, since the segment it ends is now the one that used to follow.
+fn reversed_contour(contour: &Contour) -> Contour {
+    let points = &contour.points;
+    let on_curve: Vec<usize> = (0..points.len())
+        .filter(|&i| points[i].point_type != PointType::OffCurve)
+        .collect();
+
+    let mut retyped = points.clone();
+    for (k, &i) in on_curve.iter().enumerate() {
+        let next = on_curve[(k + 1) % on_curve.len()];
+        let (own, theirs) = (points[i].point_type, points[next].point_type);
+        // Hyper points describe themselves, not a segment
+        if is_segment_type(own) && is_segment_type(theirs) {
+            retyped[i].point_type = theirs;
+        }
+    }
+
+    let mut reversed = Vec::with_capacity(points.len());
+    reversed.extend(retyped.first().cloned());
+    reversed.extend(retyped.into_iter().skip(1).rev());
+    Contour { points: reversed }
+}
+
+fn apply(contours: &[Contour], plan: &ContourPlan) -> Vec<Contour> {
+    plan.order
+        .iter()
+        .map(|&i| {
+            let mut contour = if plan.reverse[i] {
+                reversed_contour(&contours[i])
+            } else {
+                contours[i].clone()
+            };
+            contour.points.rotate_left(plan.start[i]);
+            contour
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::workspace::ContourPoint;
+
+    fn contour(points: &[(f64, f64, PointType)]) -> Contour {
+        Contour {
+            points: points
+                .iter()
+                .map(|&(x, y, point_type)| ContourPoint {
+                    x,
+                    y,
+                    point_type,
+                    smooth: false,
+                })
+                .collect(),
+        }
+    }
+
+    /// Closed square, clockwise in y-up, starting at its top-right
+    fn clockwise_square(x: f64, size: f64) -> Contour {
+        use PointType::Line;
+        contour(&[
+            (x + size, size, Line),
+            (x + size, 0.0, Line),
+            (x, 0.0, Line),
+            (x, size, Line),
+        ])
+    }
+
+    fn area(contour: &Contour) -> f64 {
+        Path::from_contour(contour).to_bezpath().area()
+    }
+
+    #[test]
+    fn reversing_keeps_segment_types_with_their_segments() {
+        use PointType::{Curve, Line, OffCurve};
+        // Line up the left side, curve over the top, line back down
+        let original = contour(&[
+            (0.0, 0.0, Line),
+            (0.0, 100.0, Line),
+            (30.0, 150.0, OffCurve),
+            (70.0, 150.0, OffCurve),
+            (100.0, 100.0, Curve),
+            (100.0, 0.0, Line),
+        ]);
+        let reversed = reversed_contour(&original);
+        let types: Vec<_> = reversed.points.iter().map(|p| p.point_type).collect();
+
+        assert_eq!(types, [Line, Line, Line, OffCurve, OffCurve, Curve]);
+        assert_eq!((reversed.points[2].x, reversed.points[2].y), (100.0, 100.0));
+        assert!((area(&original) + area(&reversed)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn normalizes_every_master_the_same_way() {
+        let options = NormalizeOptions::all(DirectionConvention::PostScript);
+        let light = [clockwise_square(200.0, 50.0), clockwise_square(0.0, 50.0)];
+        let bold = [clockwise_square(200.0, 80.0), clockwise_square(0.0, 80.0)];
+
+        let GlyphNormalization::Changed(masters) = normalize_glyph(&[&light, &bold], options)
+        else {
+            panic!("expected the glyph to change");
+        };
+        for contours in &masters {
+            assert_eq!(contours[0].points[0].x, 0.0);
+            assert_eq!(contours[1].points[0].x, 200.0);
+            for contour in contours {
+                assert_eq!(contour.points[0].y, 0.0);
+                assert!(area(contour) > 0.0);
+            }
+        }
+
+        let normalized: Vec<&[Contour]> = masters.iter().map(Vec::as_slice).collect();
+        assert!(matches!(
+            normalize_glyph(&normalized, options),
+            GlyphNormalization::Unchanged
+        ));
+
+        let mismatched = [clockwise_square(0.0, 50.0)];
+        assert!(matches!(
+            normalize_glyph(&[&light, &mismatched], options),
+            GlyphNormalization::Incompatible
+        ));
+    }
+}
@@ -0,0 +1,331 @@
+// Copyright 2026 the Runebender Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Align and distribute for the edit session.
+//!
+//! The selection is split into units: a fully selected contour moves as
+//! one rigid unit, otherwise each selected point is its own unit (with
+//! its handles following, as in any other transform). Units are aligned
+//! to an edge of the selection bounds, the glyph bounds, the advance
+//! width box or the nearest metrics line, or spread evenly along an
+//! axis.
+
+use kurbo::{Affine, Axis, Rect, Shape, Vec2};
+
+use crate::path::Path;
+
+use runebender_core::editing::Selection;
+
+use super::EditSession;
+
+/// Which edge (or centre line) of each unit to align
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlignEdge {
+    Left,
+    Center,
+    Right,
+    Top,
+    Middle,
+    Bottom,
+}
+
+impl AlignEdge {
+    /// The axis this edge moves units along
+    fn axis(self) -> Axis {
+        match self {
+            AlignEdge::Left | AlignEdge::Center | AlignEdge::Right => Axis::Horizontal,
+            AlignEdge::Top | AlignEdge::Middle | AlignEdge::Bottom => Axis::Vertical,
+        }
+    }
+
+    /// Position of this edge on a rect (design space, y-up)
+    fn of(self, rect: Rect) -> f64 {
+        match self {
+            AlignEdge::Left => rect.x0,
+            AlignEdge::Center => rect.center().x,
+            AlignEdge::Right => rect.x1,
+            AlignEdge::Top => rect.y1,
+            AlignEdge::Middle => rect.center().y,
+            AlignEdge::Bottom => rect.y0,
+        }
+    }
+}
+
+/// What units are aligned relative to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AlignTarget {
+    /// Bounds of the whole selection
+    #[default]
+    Selection,
+    /// Bounds of the glyph's contours and components
+    Glyph,
+    /// The advance width, from descender to ascender
+    Advance,
+    /// The nearest metrics line (or sidebearing / advance centre)
+    Metrics,
+}
+
+impl AlignTarget {
+    /// The next target, for cycling through them from the panel
+    pub fn next(self) -> Self {
+        match self {
+            AlignTarget::Selection => AlignTarget::Glyph,
+            AlignTarget::Glyph => AlignTarget::Advance,
+            AlignTarget::Advance => AlignTarget::Metrics,
+            AlignTarget::Metrics => AlignTarget::Selection,
+        }
+    }
+
+    /// Human-readable label for tooltip display
+    pub fn label(self) -> &'static str {
+        match self {
+            AlignTarget::Selection => "Align To: Selection",
+            AlignTarget::Glyph => "Align To: Glyph Bounds",
+            AlignTarget::Advance => "Align To: Advance Width",
+            AlignTarget::Metrics => "Align To: Metrics Lines",
+        }
+    }
+}
+
+/// A group of points that moves together
+struct AlignUnit {
+    ids: Selection,
+    bounds: Rect,
+}
+
+impl EditSession {
+    /// Align the selected points or contours to `edge` of the current
+    /// `align_target`. Returns whether anything moved.
+    pub fn align_selection(&mut self, edge: AlignEdge) -> bool {
+        let units = align_units(&self.paths, &self.selection);
+        if units.is_empty() {
+            return false;
+        }
+
+        let reference = match self.align_target {
+            AlignTarget::Selection => union(units.iter().map(|unit| unit.bounds)),
+            AlignTarget::Glyph => {
+                let components = self.decomposed_components();
+                let component_paths = components.iter().flat_map(|(_, paths)| paths);
+                let bounds = self
+                    .paths
+                    .iter()
+                    .chain(component_paths)
+                    .map(|path| path.to_bezpath().bounding_box());
+                union(bounds)
+            }
+            AlignTarget::Advance => Some(Rect::new(
+                0.0,
+                self.descender,
+                self.glyph.width,
+                self.ascender,
+            )),
+            AlignTarget::Metrics => None,
+        };
+        let lines = self.metrics_lines(edge.axis());
+
+        let mut moved = false;
+        for unit in &units {
+            let current = edge.of(unit.bounds);
+            let target = match reference {
+                Some(rect) => edge.of(rect),
+                None => nearest(&lines, current),
+            };
+            let delta = target - current;
+            if delta.abs() < 1e-9 {
+                continue;
+            }
+            let offset = match edge.axis() {
+                Axis::Horizontal => Vec2::new(delta, 0.0),
+                Axis::Vertical => Vec2::new(0.0, delta),
+            };
+            self.translate_unit(&unit.ids, offset);
+            moved = true;
+        }
+        moved
+    }
+
+    /// Space the selected points or contours evenly along `axis`,
+    /// keeping the outermost two in place. Needs at least three units.
+    pub fn distribute_selection(&mut self, axis: Axis) -> bool {
+        let units = align_units(&self.paths, &self.selection);
+        let offsets = distribute_offsets(&units, axis);
+
+        let mut moved = false;
+        for (unit, offset) in units.iter().zip(offsets) {
+            if offset.abs() < 1e-9 {
+                continue;
+            }
+            let offset = match axis {
+                Axis::Horizontal => Vec2::new(offset, 0.0),
+                Axis::Vertical => Vec2::new(0.0, offset),
+            };
+            self.translate_unit(&unit.ids, offset);
+            moved = true;
+        }
+        moved
+    }
+
+    /// Lines the Metrics target snaps to along `axis`
+    fn metrics_lines(&self, axis: Axis) -> Vec<f64> {
+        match axis {
+            Axis::Horizontal => {
+                let width = self.glyph.width;
+                vec![0.0, width / 2.0, width]
+            }
+            Axis::Vertical => [
+                Some(self.descender),
+                Some(0.0),
+                self.x_height,
+                self.cap_height,
+                Some(self.ascender),
+            ]
+            .into_iter()
+            .flatten()
+            .collect(),
+        }
+    }
+
+    /// Translate one unit, reusing `transform_selection` so handles
+    /// follow and hyperbezier paths re-solve
+    fn translate_unit(&mut self, ids: &Selection, offset: Vec2) {
+        let saved = std::mem::replace(&mut self.selection, ids.clone());
+        self.transform_selection(Affine::translate(offset));
+        self.selection = saved;
+    }
+}
+
+/// Split the selection into units: whole contours when every point is
+/// selected, otherwise single points. Selected off-curve points are
+/// left out when an on-curve point of the same contour is selected,
+/// since they already follow it.
+fn align_units(paths: &[Path], sel: &Selection) -> Vec<AlignUnit> {
+    let mut units = Vec::new();
+    for path in paths {
+        let points = path.points();
+        if points.is_empty() {
+            continue;
+        }
+        if points.iter().all(|pt| sel.contains(&pt.id)) {
+            let mut ids = Selection::new();
+            for pt in points.iter() {
+                ids.insert(pt.id);
+            }
+            units.push(AlignUnit {
+                ids,
+                bounds: path.to_bezpath().bounding_box(),
+            });
+            continue;
+        }
+
+        let has_on_curve = points
+            .iter()
+            .any(|pt| pt.is_on_curve() && sel.contains(&pt.id));
+        for pt in points.iter().filter(|pt| sel.contains(&pt.id)) {
+            if has_on_curve && !pt.is_on_curve() {
+                continue;
+            }
+            let mut ids = Selection::new();
+            ids.insert(pt.id);
+            units.push(AlignUnit {
+                ids,
+                bounds: Rect::from_points(pt.point, pt.point),
+            });
+        }
+    }
+    units
+}
+
+/// Offsets along `axis` that make the gaps between units equal
+fn distribute_offsets(units: &[AlignUnit], axis: Axis) -> Vec<f64> {
+    let mut offsets = vec![0.0; units.len()];
+    if units.len() < 3 {
+        return offsets;
+    }
+
+    let range = |rect: Rect| match axis {
+        Axis::Horizontal => (rect.x0, rect.x1),
+        Axis::Vertical => (rect.y0, rect.y1),
+    };
+
+    let mut order: Vec<usize> = (0..units.len()).collect();
+    order.sort_by(|&a, &b| {
+        let a = range(units[a].bounds);
+        let b = range(units[b].bounds);
+        (a.0 + a.1).total_cmp(&(b.0 + b.1))
+    });
+
+    let (start, _) = range(units[order[0]].bounds);
+    let (_, end) = range(units[order[order.len() - 1]].bounds);
+    let total: f64 = units
+        .iter()
+        .map(|unit| {
+            let (min, max) = range(unit.bounds);
+            max - min
+        })
+        .sum();
+    let gap = (end - start - total) / (units.len() - 1) as f64;
+
+    let mut cursor = start;
+    for &i in &order {
+        let (min, max) = range(units[i].bounds);
+        offsets[i] = cursor - min;
+        cursor += max - min + gap;
+    }
+    offsets
+}
+
+/// Union of a set of rects, or `None` when empty
+fn union(rects: impl Iterator<Item = Rect>) -> Option<Rect> {
+    rects.reduce(|a, b| a.union(b))
+}
+
+/// The value in `lines` closest to `value` (or `value` when empty)
+fn nearest(lines: &[f64], value: f64) -> f64 {
+    lines
+        .iter()
+        .copied()
+        .min_by(|a, b| (a - value).abs().total_cmp(&(b - value).abs()))
+        .unwrap_or(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kurbo::Point;
+
+    fn point_unit(x: f64) -> AlignUnit {
+        AlignUnit {
+            ids: Selection::new(),
+            bounds: Rect::from_points(Point::new(x, 0.0), Point::new(x, 0.0)),
+        }
+    }
+
+    #[test]
+    fn distribute_spaces_gaps_evenly() {
+        let units = [
+            point_unit(100.0),
+            point_unit(0.0),
+            AlignUnit {
+                ids: Selection::new(),
+                bounds: Rect::new(10.0, 0.0, 30.0, 10.0),
+            },
+            point_unit(60.0),
+        ];
+
+        let offsets = distribute_offsets(&units, Axis::Horizontal);
+
+        // Span 0..100 holds 20 units of width, leaving three 80/3 gaps
+        let gap = 80.0 / 3.0;
+        assert_eq!(offsets[1], 0.0);
+        assert!((offsets[2] - (gap - 10.0)).abs() < 1e-9);
+        assert!((offsets[3] - (2.0 * gap + 20.0 - 60.0)).abs() < 1e-9);
+        assert!(offsets[0].abs() < 1e-9);
+    }
+
+    #[test]
+    fn nearest_picks_closest_line() {
+        assert_eq!(nearest(&[-200.0, 0.0, 500.0, 700.0], 540.0), 500.0);
+        assert_eq!(nearest(&[], 12.0), 12.0);
+    }
+}
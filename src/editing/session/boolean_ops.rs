@@ -0,0 +1,472 @@
+// Copyright 2026 the Runebender Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Boolean operations for EditSession — union, subtract, intersect and
+//! exclude on the selected contours, the selected component, or the
+//! whole glyph.
+//!
+//! Contours that come out of the operation unchanged are kept as they
+//! were (same curve type, same points). Changed contours are rebuilt
+//! from the linesweeper output, snapped back onto original point
+//! positions, and converted to quadratics when every operand was
+//! quadratic.
+
+use super::EditSession;
+use crate::editing::selection::Selection;
+use crate::editing::tracing::bezpath_to_cubic;
+use crate::model::EntityId;
+use crate::path::{self, CubicPath, Path, PathPoint, QuadraticPath};
+use kurbo::{BezPath, ParamCurve, PathEl, PathSeg, Point, Rect, Shape};
+use linesweeper::BinaryOp;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// Output points closer than this (design units) to an original point
+/// are treated as that point
+const MATCH_TOLERANCE: f64 = 0.5;
+
+/// Maximum deviation when converting result curves back to quadratics
+const QUADRATIC_ACCURACY: f64 = 0.1;
+
+/// The contours taking part in a boolean operation
+struct BooleanOperands {
+    /// First operand (targets, or everything for union)
+    set_a: Vec<Path>,
+    /// Second operand (the selected cutters)
+    set_b: Vec<Path>,
+    /// Indices into `EditSession::paths` replaced by the result
+    consumed_paths: HashSet<usize>,
+    /// Components decomposed into the operation
+    consumed_components: HashSet<EntityId>,
+    /// Whether the operands came from the selection
+    from_selection: bool,
+}
+
+impl EditSession {
+    /// Apply a boolean operation (union, subtract, intersect, XOR)
+    ///
+    /// With a selection, the contours containing selected points (plus
+    /// the selected component, decomposed) are the operands. Union
+    /// merges them with each other; Subtract, Intersect and Exclude
+    /// apply them to the unselected contours they overlap. Contours
+    /// that don't take part are left alone.
+    ///
+    /// Without a selection the whole glyph is used, components
+    /// included: Union removes all overlap, and the other operations
+    /// act between the first contour and all remaining ones.
+    pub fn boolean_op(&mut self, op: BinaryOp) {
+        let Some(operands) = self.boolean_operands(op) else {
+            return;
+        };
+
+        let result = match linesweeper::binary_op(
+            &combine_bezpaths(&operands.set_a),
+            &combine_bezpaths(&operands.set_b),
+            linesweeper::FillRule::NonZero,
+            op,
+        ) {
+            Ok(contours) => contours,
+            Err(e) => {
+                tracing::error!("Boolean operation failed: {e}");
+                return;
+            }
+        };
+
+        let sources: Vec<&Path> = operands.set_a.iter().chain(&operands.set_b).collect();
+        let results: Vec<Path> = result
+            .contours()
+            .filter_map(|contour| rebuild_contour(&contour.path, &sources))
+            .collect();
+
+        if results.is_empty() {
+            tracing::warn!("Boolean op produced no contours");
+            return;
+        }
+
+        tracing::info!(
+            "Boolean op: {} contours → {} contours",
+            sources.len(),
+            results.len()
+        );
+
+        self.selection = if operands.from_selection {
+            select_all_points(&results)
+        } else {
+            Selection::new()
+        };
+        self.paths = Arc::new(splice_results(
+            &self.paths,
+            &operands.consumed_paths,
+            results,
+        ));
+        self.remove_consumed_components(&operands.consumed_components);
+    }
+
+    // ===== PRIVATE HELPERS =====
+
+    /// Work out which contours the operation acts on
+    fn boolean_operands(&self, op: BinaryOp) -> Option<BooleanOperands> {
+        let selected: Vec<usize> = self
+            .paths
+            .iter()
+            .enumerate()
+            .filter(|(_, path)| {
+                path.points()
+                    .iter()
+                    .any(|pt| self.selection.contains(&pt.id))
+            })
+            .map(|(i, _)| i)
+            .collect();
+        let components = self.decomposed_components();
+
+        if selected.is_empty() && self.selected_component.is_none() {
+            return whole_glyph_operands(&self.paths, components, op);
+        }
+
+        let mut cutters: Vec<Path> = selected.iter().map(|&i| self.paths[i].clone()).collect();
+        let mut consumed_paths: HashSet<usize> = selected.iter().copied().collect();
+        let mut consumed_components = HashSet::new();
+        if let Some(id) = self.selected_component
+            && let Some((_, paths)) = components.into_iter().find(|(cid, _)| *cid == id)
+        {
+            cutters.extend(paths);
+            consumed_components.insert(id);
+        }
+
+        if matches!(op, BinaryOp::Union) {
+            if cutters.is_empty() {
+                return None;
+            }
+            return Some(BooleanOperands {
+                set_a: cutters,
+                set_b: Vec::new(),
+                consumed_paths,
+                consumed_components,
+                from_selection: true,
+            });
+        }
+
+        // Targets: unselected contours the selection overlaps
+        let cutter_bounds = paths_bounds(&cutters)?;
+        let mut targets = Vec::new();
+        for (i, path) in self.paths.iter().enumerate() {
+            if consumed_paths.contains(&i) {
+                continue;
+            }
+            if rects_overlap(path.to_bezpath().bounding_box(), cutter_bounds) {
+                targets.push(path.clone());
+                consumed_paths.insert(i);
+            }
+        }
+
+        if targets.is_empty() {
+            tracing::warn!("Boolean op: selection doesn't overlap any other contour");
+            return None;
+        }
+
+        Some(BooleanOperands {
+            set_a: targets,
+            set_b: cutters,
+            consumed_paths,
+            consumed_components,
+            from_selection: true,
+        })
+    }
+
+    /// Decompose every component of the glyph into editable paths
+    ///
+    /// Also used by the knife tool to cut through components.
+    pub(crate) fn decomposed_components(&self) -> Vec<(EntityId, Vec<Path>)> {
+        let Some(workspace) = self.workspace.as_ref() else {
+            return Vec::new();
+        };
+        let Ok(workspace) = workspace.read() else {
+            return Vec::new();
+        };
+        self.glyph
+            .components
+            .iter()
+            .map(|c| (c.id, path::decompose_component(c, &workspace)))
+            .collect()
+    }
+
+    /// Drop components whose outlines were decomposed into the contours
+    pub(crate) fn remove_consumed_components(&mut self, consumed: &HashSet<EntityId>) {
+        if consumed.is_empty() {
+            return;
+        }
+        let glyph = Arc::make_mut(&mut self.glyph);
+        glyph.components.retain(|c| !consumed.contains(&c.id));
+        if self
+            .selected_component
+            .is_some_and(|id| consumed.contains(&id))
+        {
+            self.selected_component = None;
+        }
+    }
+}
+
+// ============================================================================
+// OPERANDS
+// ============================================================================
+
+/// Operands for a boolean op with nothing selected
+fn whole_glyph_operands(
+    paths: &[Path],
+    components: Vec<(EntityId, Vec<Path>)>,
+    op: BinaryOp,
+) -> Option<BooleanOperands> {
+    let consumed_components = components.iter().map(|(id, _)| *id).collect();
+    let mut all: Vec<Path> = paths.to_vec();
+    all.extend(components.into_iter().flat_map(|(_, paths)| paths));
+
+    if all.len() < 2 {
+        tracing::warn!("Boolean ops need at least 2 contours");
+        return None;
+    }
+
+    let (set_a, set_b) = match op {
+        BinaryOp::Union => (all, Vec::new()),
+        _ => {
+            let rest = all.split_off(1);
+            (all, rest)
+        }
+    };
+
+    Some(BooleanOperands {
+        set_a,
+        set_b,
+        consumed_paths: (0..paths.len()).collect(),
+        consumed_components,
+        from_selection: false,
+    })
+}
+
+/// Concatenate paths into a single BezPath operand
+fn combine_bezpaths(paths: &[Path]) -> BezPath {
+    let mut combined = BezPath::new();
+    for path in paths {
+        combined.extend(path.to_bezpath());
+    }
+    combined
+}
+
+/// Union of the bounding boxes of `paths`
+fn paths_bounds(paths: &[Path]) -> Option<Rect> {
+    paths
+        .iter()
+        .map(|p| p.to_bezpath().bounding_box())
+        .reduce(|a, b| a.union(b))
+}
+
+fn rects_overlap(a: Rect, b: Rect) -> bool {
+    a.x0 <= b.x1 && b.x0 <= a.x1 && a.y0 <= b.y1 && b.y0 <= a.y1
+}
+
+// ============================================================================
+// RESULT CONVERSION
+// ============================================================================
+
+/// Turn one output contour back into an editable path
+fn rebuild_contour(bezpath: &BezPath, sources: &[&Path]) -> Option<Path> {
+    let on_curves = on_curve_positions(bezpath);
+    if on_curves.is_empty() {
+        return None;
+    }
+
+    if let Some(original) = sources
+        .iter()
+        .find(|src| same_on_curves(&on_curves, &on_curve_positions(&src.to_bezpath())))
+    {
+        let same_direction = original.to_bezpath().area().signum() == bezpath.area().signum();
+        return Some(if same_direction {
+            (*original).clone()
+        } else {
+            reversed(original)
+        });
+    }
+
+    let originals: Vec<&PathPoint> = sources
+        .iter()
+        .flat_map(|src| src.points().iter())
+        .filter(|pt| pt.is_on_curve())
+        .collect();
+
+    let all_quadratic = sources.iter().all(|src| matches!(src, Path::Quadratic(_)));
+    let mut cubic = if all_quadratic {
+        bezpath_to_cubic(&cubics_to_quads(bezpath))
+    } else {
+        bezpath_to_cubic(bezpath)
+    };
+    snap_to_originals(&mut cubic, &originals);
+
+    Some(if all_quadratic {
+        Path::Quadratic(QuadraticPath::new(cubic.points, cubic.closed))
+    } else {
+        Path::Cubic(cubic)
+    })
+}
+
+/// On-curve (segment end) positions of a BezPath, in order
+fn on_curve_positions(bezpath: &BezPath) -> Vec<Point> {
+    bezpath
+        .elements()
+        .iter()
+        .filter_map(|el| match *el {
+            PathEl::MoveTo(p)
+            | PathEl::LineTo(p)
+            | PathEl::QuadTo(_, p)
+            | PathEl::CurveTo(_, _, p) => Some(p),
+            PathEl::ClosePath => None,
+        })
+        .collect()
+}
+
+/// Whether two contours share the same set of on-curve positions
+///
+/// A contour closing back onto its start repeats that point as the
+/// last element, so positions are compared as sets.
+fn same_on_curves(a: &[Point], b: &[Point]) -> bool {
+    let close = |p: &Point, q: &Point| p.distance(*q) < MATCH_TOLERANCE;
+    a.iter().all(|p| b.iter().any(|q| close(p, q)))
+        && b.iter().all(|q| a.iter().any(|p| close(p, q)))
+}
+
+/// Copy of a path with its point order reversed
+fn reversed(path: &Path) -> Path {
+    let mut path = path.clone();
+    match &mut path {
+        Path::Cubic(c) => c.points.make_mut().reverse(),
+        Path::Quadratic(q) => q.points.make_mut().reverse(),
+        Path::Hyper(h) => {
+            h.points.make_mut().reverse();
+            h.after_change();
+        }
+    }
+    path
+}
+
+/// Approximate every cubic segment with quadratics
+fn cubics_to_quads(bezpath: &BezPath) -> BezPath {
+    let mut out = BezPath::new();
+    for seg in bezpath.segments() {
+        if out.is_empty() {
+            out.move_to(seg.start());
+        }
+        match seg {
+            PathSeg::Line(line) => out.line_to(line.p1),
+            PathSeg::Quad(quad) => out.quad_to(quad.p1, quad.p2),
+            PathSeg::Cubic(cubic) => {
+                for (_, _, quad) in cubic.to_quads(QUADRATIC_ACCURACY) {
+                    out.quad_to(quad.p1, quad.p2);
+                }
+            }
+        }
+    }
+    if bezpath.elements().last() == Some(&PathEl::ClosePath) {
+        out.close_path();
+    }
+    out
+}
+
+/// Snap on-curve points onto nearby original points, restoring their
+/// exact position and type (smooth/corner)
+fn snap_to_originals(cubic: &mut CubicPath, originals: &[&PathPoint]) {
+    for pt in cubic.points.make_mut().iter_mut() {
+        if !pt.is_on_curve() {
+            continue;
+        }
+        if let Some(orig) = originals
+            .iter()
+            .find(|orig| orig.point.distance(pt.point) < MATCH_TOLERANCE)
+        {
+            pt.point = orig.point;
+            pt.typ = orig.typ;
+        }
+    }
+}
+
+/// Replace the consumed contours with the results, keeping the
+/// results where the first consumed contour used to be
+fn splice_results(paths: &[Path], consumed: &HashSet<usize>, results: Vec<Path>) -> Vec<Path> {
+    let insert_at = (0..paths.len())
+        .find(|i| consumed.contains(i))
+        .unwrap_or(paths.len());
+    let mut results = Some(results);
+    let mut out = Vec::with_capacity(paths.len());
+    for (i, path) in paths.iter().enumerate() {
+        if i == insert_at {
+            out.extend(results.take().unwrap_or_default());
+        }
+        if !consumed.contains(&i) {
+            out.push(path.clone());
+        }
+    }
+    out.extend(results.unwrap_or_default());
+    out
+}
+
+/// Selection containing every point of `paths`
+fn select_all_points(paths: &[Path]) -> Selection {
+    let mut selection = Selection::new();
+    for path in paths {
+        for pt in path.points().iter() {
+            selection.insert(pt.id);
+        }
+    }
+    selection
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(x: f64, y: f64, size: f64) -> BezPath {
+        let mut bp = BezPath::new();
+        bp.move_to((x, y));
+        bp.line_to((x + size, y));
+        bp.line_to((x + size, y + size));
+        bp.line_to((x, y + size));
+        bp.close_path();
+        bp
+    }
+
+    #[test]
+    fn unchanged_contour_is_detected() {
+        let a = on_curve_positions(&square(0.0, 0.0, 100.0));
+        let b = on_curve_positions(&square(0.0, 0.0, 100.0).reverse_subpaths());
+        assert!(same_on_curves(&a, &b));
+
+        let moved = on_curve_positions(&square(10.0, 0.0, 100.0));
+        assert!(!same_on_curves(&a, &moved));
+    }
+
+    #[test]
+    fn results_replace_first_consumed_contour() {
+        let path = |x| Path::Cubic(bezpath_to_cubic(&square(x, 0.0, 10.0)));
+        let paths = vec![path(0.0), path(100.0), path(200.0)];
+        let consumed: HashSet<usize> = [1, 2].into_iter().collect();
+        let out = splice_results(&paths, &consumed, vec![path(500.0)]);
+
+        assert_eq!(out.len(), 2);
+        assert_eq!(out[0].points().iter().next().unwrap().point.x, 0.0);
+        assert!(out[1].points().iter().any(|pt| pt.point.x == 500.0));
+    }
+
+    #[test]
+    fn cubic_segments_become_quadratics() {
+        let mut bp = BezPath::new();
+        bp.move_to((0.0, 0.0));
+        bp.curve_to((0.0, 100.0), (100.0, 100.0), (100.0, 0.0));
+        bp.close_path();
+
+        let quads = cubics_to_quads(&bp);
+        assert!(
+            quads
+                .elements()
+                .iter()
+                .all(|el| !matches!(el, PathEl::CurveTo(..)))
+        );
+        assert_eq!(quads.elements().last(), Some(&PathEl::ClosePath));
+    }
+}
@@ -0,0 +1,400 @@
+// Copyright 2026 the Runebender Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Component operations: decomposing the selected or all components
+//! into editable paths, decomposing every use of a glyph across the
+//! font, moving selected contours out into a new glyph referenced back
+//! as a component, and editing a component's transform and base
+//! glyph.
+//!
+//! The coordinate panel shows a component transform as offset,
+//! scale, rotation and skew: the affine is split into a rotation, a
+//! horizontal skew and a scale (in that order), with the x scale
+//! negative rather than the rotation past ±90° for flipped components.
+
+use std::collections::HashSet;
+
+use kurbo::{Affine, Rect, Shape};
+
+use crate::model::EntityId;
+use crate::model::workspace::{Component, Contour, Glyph, Workspace};
+use crate::path;
+
+use super::EditSession;
+
+/// Which value of a component's transform is being edited
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComponentField {
+    X,
+    Y,
+    ScaleX,
+    ScaleY,
+    Rotation,
+    Skew,
+}
+
+/// A component transform split into editable values
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ComponentTransform {
+    /// Offset in design units
+    pub x: f64,
+    pub y: f64,
+    /// Scale in percent, negative when flipped
+    pub scale_x: f64,
+    pub scale_y: f64,
+    /// Rotation in degrees, counter-clockwise
+    pub rotation: f64,
+    /// Horizontal skew in degrees
+    pub skew: f64,
+}
+
+impl ComponentTransform {
+    pub fn from_affine(affine: Affine) -> Self {
+        let [a, b, c, d, e, f] = affine.as_coeffs();
+        let mut scale_x = a.hypot(b);
+        if a < 0.0 {
+            scale_x = -scale_x;
+        }
+        let det = a * d - b * c;
+        let (rotation, scale_y, skew) = if scale_x == 0.0 {
+            (0.0, d, 0.0)
+        } else {
+            let rotation = (b / scale_x).atan2(a / scale_x);
+            let shear = if det == 0.0 {
+                0.0
+            } else {
+                (a * c + b * d) / det
+            };
+            (rotation, det / scale_x, shear.atan())
+        };
+        Self {
+            x: e,
+            y: f,
+            scale_x: scale_x * 100.0,
+            scale_y: scale_y * 100.0,
+            rotation: rotation.to_degrees(),
+            skew: skew.to_degrees(),
+        }
+    }
+
+    pub fn to_affine(self) -> Affine {
+        let (sin, cos) = self.rotation.to_radians().sin_cos();
+        let shear = self.skew.to_radians().tan();
+        let (sx, sy) = (self.scale_x / 100.0, self.scale_y / 100.0);
+        Affine::new([
+            sx * cos,
+            sx * sin,
+            sy * (cos * shear - sin),
+            sy * (sin * shear + cos),
+            self.x,
+            self.y,
+        ])
+    }
+
+    /// A copy with one field set, or `None` for a zero scale
+    fn with(self, field: ComponentField, value: f64) -> Option<Self> {
+        let mut t = self;
+        match field {
+            ComponentField::X => t.x = value,
+            ComponentField::Y => t.y = value,
+            ComponentField::ScaleX | ComponentField::ScaleY if value == 0.0 => return None,
+            ComponentField::ScaleX => t.scale_x = value,
+            ComponentField::ScaleY => t.scale_y = value,
+            ComponentField::Rotation => t.rotation = value,
+            ComponentField::Skew => t.skew = value,
+        }
+        Some(t)
+    }
+}
+
+impl EditSession {
+    /// Decompose the selected component, or every component with
+    /// `all`, into editable contours. Nested components are flattened
+    /// with their combined transforms. Returns whether anything
+    /// changed.
+    pub fn decompose_components(&mut self, all: bool) -> bool {
+        // Without the workspace the base outlines can't be resolved,
+        // and dropping the components would lose them
+        if self.workspace.is_none() {
+            return false;
+        }
+        let consumed: HashSet<EntityId> = self
+            .glyph
+            .components
+            .iter()
+            .filter(|c| all || self.selected_component == Some(c.id))
+            .map(|c| c.id)
+            .collect();
+        if consumed.is_empty() {
+            return false;
+        }
+
+        let decomposed = self.decomposed_components();
+        let paths = std::sync::Arc::make_mut(&mut self.paths);
+        for (id, component_paths) in decomposed {
+            if consumed.contains(&id) {
+                paths.extend(component_paths);
+            }
+        }
+        self.remove_consumed_components(&consumed);
+        true
+    }
+
+    /// The selected component's transform, split into editable values
+    pub fn selected_component_transform(&self) -> Option<ComponentTransform> {
+        let id = self.selected_component?;
+        let component = self.glyph.components.iter().find(|c| c.id == id)?;
+        Some(ComponentTransform::from_affine(component.transform))
+    }
+
+    /// Set one value of the selected component's transform. Returns
+    /// whether anything changed.
+    pub fn set_component_transform(&mut self, field: ComponentField, value: f64) -> bool {
+        let Some(current) = self.selected_component_transform() else {
+            return false;
+        };
+        let Some(updated) = current.with(field, value) else {
+            return false;
+        };
+        if updated == current {
+            return false;
+        }
+        self.update_selected_component(|c| c.transform = updated.to_affine())
+    }
+
+    /// Apply `affine` (in glyph space) on top of the selected
+    /// component's transform
+    pub(crate) fn transform_selected_component(&mut self, affine: Affine) -> bool {
+        self.update_selected_component(|c| c.transform = affine * c.transform)
+    }
+
+    /// Bounds of the selected component's outlines in glyph space
+    pub(crate) fn selected_component_bounds(&self) -> Option<Rect> {
+        let id = self.selected_component?;
+        self.decomposed_components()
+            .into_iter()
+            .find(|(component, _)| *component == id)?
+            .1
+            .iter()
+            .map(|path| path.to_bezpath().bounding_box())
+            .reduce(|a, b| a.union(b))
+    }
+
+    /// Point the selected component at another glyph, keeping its
+    /// transform. Refuses glyphs that don't exist or that would make
+    /// the glyph contain itself.
+    pub fn replace_selected_component_base(&mut self, base: &str) -> bool {
+        let Some(workspace) = self.workspace.as_ref() else {
+            return false;
+        };
+        let valid = {
+            let workspace = crate::model::read_workspace(workspace);
+            let own = self.active_sort_name.as_deref().unwrap_or(&self.glyph.name);
+            workspace.glyphs.contains_key(base) && !uses_glyph(&workspace, base, own)
+        };
+        valid && self.update_selected_component(|c| c.base = base.to_string())
+    }
+
+    fn update_selected_component(&mut self, edit: impl FnOnce(&mut Component)) -> bool {
+        let Some(id) = self.selected_component else {
+            return false;
+        };
+        let glyph = std::sync::Arc::make_mut(&mut self.glyph);
+        match glyph.components.iter_mut().find(|c| c.id == id) {
+            Some(component) => {
+                edit(component);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Indices of the contours with at least one selected point
+    pub fn selected_contour_indices(&self) -> Vec<usize> {
+        self.paths
+            .iter()
+            .enumerate()
+            .filter(|(_, path)| {
+                path.points()
+                    .iter()
+                    .any(|pt| self.selection.contains(&pt.id))
+            })
+            .map(|(i, _)| i)
+            .collect()
+    }
+}
+
+/// Whether `glyph` is `target` or uses it through its components,
+/// however deeply nested
+fn uses_glyph(workspace: &Workspace, glyph: &str, target: &str) -> bool {
+    let mut pending = vec![glyph.to_string()];
+    let mut seen = HashSet::new();
+    while let Some(name) = pending.pop() {
+        if name == target {
+            return true;
+        }
+        if !seen.insert(name.clone()) {
+            continue;
+        }
+        if let Some(g) = workspace.glyphs.get(&name) {
+            pending.extend(g.components.iter().map(|c| c.base.clone()));
+        }
+    }
+    false
+}
+
+/// A name for a glyph holding contours taken out of `glyph_name`,
+/// not yet used in the workspace
+pub fn unique_part_name(workspace: &Workspace, glyph_name: &str) -> String {
+    let base = format!("_part.{glyph_name}");
+    if !workspace.glyphs.contains_key(&base) {
+        return base;
+    }
+    (1..)
+        .map(|n| format!("{base}.{n}"))
+        .find(|name| !workspace.glyphs.contains_key(name))
+        .expect("unbounded range yields a free name")
+}
+
+/// Move the contours at `indices` out of `glyph` into a new glyph
+/// named `part_name`, leaving a component in their place. Returns the
+/// new glyph, or `None` when an index is out of range.
+pub fn extract_component(glyph: &mut Glyph, indices: &[usize], part_name: &str) -> Option<Glyph> {
+    if indices.is_empty() || indices.iter().any(|&i| i >= glyph.contours.len()) {
+        return None;
+    }
+    let taken: Vec<Contour> = indices.iter().map(|&i| glyph.contours[i].clone()).collect();
+    let mut index = 0;
+    glyph.contours.retain(|_| {
+        let keep = !indices.contains(&index);
+        index += 1;
+        keep
+    });
+    glyph.components.push(Component {
+        base: part_name.to_string(),
+        transform: Affine::IDENTITY,
+        id: EntityId::next(),
+    });
+
+    Some(Glyph {
+        name: part_name.to_string(),
+        width: glyph.width,
+        height: glyph.height,
+        codepoints: Vec::new(),
+        contours: taken,
+        components: Vec::new(),
+        left_group: None,
+        right_group: None,
+        mark_color: None,
+        mark_label: None,
+        measurements: Vec::new(),
+        metrics_keys: Default::default(),
+    })
+}
+
+/// Decompose every component referencing `base` in the workspace
+/// into contours. Returns the number of glyphs changed.
+pub fn decompose_uses_in_workspace(workspace: &mut Workspace, base: &str) -> usize {
+    // Resolve outlines first, while the workspace is only borrowed
+    let mut decomposed: Vec<(String, Vec<Contour>)> = Vec::new();
+    for (name, glyph) in &workspace.glyphs {
+        let contours: Vec<Contour> = glyph
+            .components
+            .iter()
+            .filter(|c| c.base == base)
+            .flat_map(|c| path::decompose_component(c, workspace))
+            .map(|p| p.to_contour())
+            .collect();
+        if glyph.components.iter().any(|c| c.base == base) {
+            decomposed.push((name.clone(), contours));
+        }
+    }
+
+    let changed = decomposed.len();
+    for (name, contours) in decomposed {
+        if let Some(glyph) = workspace.get_glyph_mut(&name) {
+            glyph.components.retain(|c| c.base != base);
+            glyph.contours.extend(contours);
+        }
+    }
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(x: f64) -> Contour {
+        Contour::for_test(&[(x, 0.0), (x + 10.0, 0.0), (x + 10.0, 10.0), (x, 10.0)])
+    }
+
+    fn glyph(name: &str, contours: Vec<Contour>, components: Vec<Component>) -> Glyph {
+        Glyph {
+            contours,
+            components,
+            ..Glyph::for_test(name, 500.0)
+        }
+    }
+
+    fn component(base: &str, dx: f64) -> Component {
+        Component {
+            base: base.to_string(),
+            transform: Affine::translate((dx, 0.0)),
+            id: EntityId::next(),
+        }
+    }
+
+    #[test]
+    fn transform_values_round_trip() {
+        let affine = Affine::translate((30.0, -12.0))
+            * Affine::rotate(0.4)
+            * Affine::skew(0.2, 0.0)
+            * Affine::scale_non_uniform(-1.5, 0.75);
+        let values = ComponentTransform::from_affine(affine);
+        assert!((values.scale_x + 150.0).abs() < 1e-9);
+        assert!((values.scale_y - 75.0).abs() < 1e-9);
+        assert!((values.x - 30.0).abs() < 1e-9);
+
+        let back = values.to_affine().as_coeffs();
+        for (a, b) in back.iter().zip(affine.as_coeffs()) {
+            assert!((a - b).abs() < 1e-9);
+        }
+
+        let flipped = ComponentTransform::from_affine(Affine::FLIP_X);
+        assert_eq!((flipped.scale_x, flipped.scale_y), (-100.0, 100.0));
+        assert_eq!(flipped.rotation, 0.0);
+    }
+
+    #[test]
+    fn extract_moves_contours_into_a_component() {
+        let mut a = glyph("a", vec![square(0.0), square(20.0), square(40.0)], vec![]);
+        let part = extract_component(&mut a, &[0, 2], "_part.a").unwrap();
+
+        assert_eq!(part.contours.len(), 2);
+        assert_eq!(part.contours[1].points[0].x, 40.0);
+        assert_eq!(a.contours.len(), 1);
+        assert_eq!(a.contours[0].points[0].x, 20.0);
+        assert_eq!(a.components[0].base, "_part.a");
+        assert!(extract_component(&mut a, &[3], "_part.a.1").is_none());
+    }
+
+    #[test]
+    fn decomposes_nested_uses_with_combined_transforms() {
+        let glyphs = [
+            glyph("dot", vec![square(0.0)], vec![]),
+            glyph("i", vec![square(0.0)], vec![component("dot", 5.0)]),
+            glyph("ij", vec![], vec![component("i", 100.0)]),
+        ];
+        let mut workspace = Workspace::from_glyphs(glyphs);
+
+        assert_eq!(decompose_uses_in_workspace(&mut workspace, "i"), 1);
+        let ij = &workspace.glyphs["ij"];
+        assert!(ij.components.is_empty());
+        assert_eq!(ij.contours.len(), 2);
+        let mut xs: Vec<f64> = ij.contours.iter().map(|c| c.points[0].x).collect();
+        xs.sort_by(f64::total_cmp);
+        assert_eq!(xs, [100.0, 105.0]);
+        // Only direct uses are decomposed
+        assert_eq!(workspace.glyphs["i"].components.len(), 1);
+    }
+}
@@ -0,0 +1,405 @@
+// Copyright 2026 the Runebender Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Structural contour operations on the edit session: breaking a
+//! contour at a point, closing open contours, joining two endpoints,
+//! and turning curve segments into lines by retracting their handles.
+
+use std::sync::Arc;
+
+use crate::path::{CubicPath, HyperPath, Path, PathPoint, PathPoints, PointType, QuadraticPath};
+
+use runebender_core::editing::Selection;
+
+use super::EditSession;
+
+/// Endpoints closer than this are merged when closing or joining
+const MERGE_DISTANCE: f64 = 1e-6;
+
+impl EditSession {
+    /// Break contours at the selected on-curve points. A closed
+    /// contour opens at the point; an open contour splits in two.
+    /// Returns whether anything changed.
+    pub fn break_contours_at_selection(&mut self) -> bool {
+        break_paths(Arc::make_mut(&mut self.paths), &self.selection)
+    }
+
+    /// Close every open contour that has a selected point, merging
+    /// its endpoints when they sit on top of each other.
+    pub fn close_selected_contours(&mut self) -> bool {
+        let mut changed = false;
+        let paths = Arc::make_mut(&mut self.paths);
+        for path in paths.iter_mut() {
+            let selected = path
+                .points()
+                .iter()
+                .any(|pt| self.selection.contains(&pt.id));
+            if path.is_closed() || !selected {
+                continue;
+            }
+            *path = closed_path(path);
+            changed = true;
+        }
+        changed
+    }
+
+    /// Join the two selected endpoints of open contours into one
+    /// contour (or close the contour when both ends are its own).
+    pub fn join_selected_endpoints(&mut self) -> bool {
+        join_paths(Arc::make_mut(&mut self.paths), &self.selection)
+    }
+
+    /// Turn selected curve segments into lines by removing their
+    /// handles. A segment counts as selected when both of its
+    /// on-curve ends or any of its handles are selected.
+    pub fn convert_selection_to_lines(&mut self) -> bool {
+        let mut removed = Vec::new();
+        let paths = Arc::make_mut(&mut self.paths);
+        for path in paths.iter_mut() {
+            match path {
+                Path::Cubic(cubic) => {
+                    let closed = cubic.closed;
+                    removed.extend(retract_handles(
+                        cubic.points.make_mut(),
+                        closed,
+                        &self.selection,
+                    ));
+                }
+                Path::Quadratic(quadratic) => {
+                    let closed = quadratic.closed;
+                    removed.extend(retract_handles(
+                        quadratic.points.make_mut(),
+                        closed,
+                        &self.selection,
+                    ));
+                }
+                // Hyper paths have no user-visible off-curve handles
+                Path::Hyper(_) => {}
+            }
+        }
+        for id in &removed {
+            self.selection.remove(id);
+        }
+        !removed.is_empty()
+    }
+}
+
+/// A copy of `path`'s curve type holding `points`
+fn rebuild(path: &Path, points: Vec<PathPoint>, closed: bool) -> Path {
+    let points = PathPoints::from_vec(points);
+    match path {
+        Path::Cubic(_) => Path::Cubic(CubicPath::new(points, closed)),
+        Path::Quadratic(_) => Path::Quadratic(QuadraticPath::new(points, closed)),
+        Path::Hyper(_) => Path::Hyper(HyperPath::from_points(points, closed)),
+    }
+}
+
+/// A fresh corner point at the same position as `pt`
+fn corner_copy(pt: &PathPoint) -> PathPoint {
+    PathPoint {
+        id: crate::model::EntityId::next(),
+        point: pt.point,
+        typ: PointType::OnCurve { smooth: false },
+    }
+}
+
+fn make_corner(pt: &mut PathPoint) {
+    if pt.is_on_curve() {
+        pt.typ = PointType::OnCurve { smooth: false };
+    }
+}
+
+/// Break paths at selected on-curve points until none are left to
+/// break at
+fn break_paths(paths: &mut Vec<Path>, sel: &Selection) -> bool {
+    let mut changed = false;
+    let mut i = 0;
+    while i < paths.len() {
+        // The broken point ends up at an end of its piece, so the same
+        // index is looked at again for further selected points
+        match break_path(&paths[i], sel) {
+            Some(pieces) => {
+                paths.splice(i..=i, pieces);
+                changed = true;
+            }
+            None => i += 1,
+        }
+    }
+    changed
+}
+
+/// Open a closed path at its first selected on-curve point, or split
+/// an open one at its first selected interior on-curve point
+fn break_path(path: &Path, sel: &Selection) -> Option<Vec<Path>> {
+    let points = path.points().to_vec();
+    let len = points.len();
+    let closed = path.is_closed();
+    let at = (0..len).find(|&i| {
+        points[i].is_on_curve() && sel.contains(&points[i].id) && (closed || (i > 0 && i + 1 < len))
+    })?;
+
+    if closed {
+        let mut open = points;
+        open.rotate_left(at);
+        make_corner(&mut open[0]);
+        let end = corner_copy(&open[0]);
+        open.push(end);
+        return Some(vec![rebuild(path, open, false)]);
+    }
+
+    let mut first = points[..=at].to_vec();
+    make_corner(&mut first[at]);
+    let mut second = vec![corner_copy(&points[at])];
+    second.extend_from_slice(&points[at + 1..]);
+    Some(vec![
+        rebuild(path, first, false),
+        rebuild(path, second, false),
+    ])
+}
+
+/// `path` closed, dropping the last point when it sits on the first
+fn closed_path(path: &Path) -> Path {
+    let mut points = path.points().to_vec();
+    if points.len() > 1 {
+        let first = points[0].point;
+        let last = points[points.len() - 1].point;
+        if (first - last).hypot() < MERGE_DISTANCE {
+            points.pop();
+        }
+    }
+    rebuild(path, points, true)
+}
+
+/// Join the two selected endpoints of open paths. Returns false unless
+/// exactly two endpoints are selected, and their paths are of the same
+/// kind: the joined path can only have one.
+fn join_paths(paths: &mut Vec<Path>, sel: &Selection) -> bool {
+    // (path index, whether the endpoint is the path's start)
+    let mut ends: Vec<(usize, bool)> = Vec::new();
+    for (i, path) in paths.iter().enumerate() {
+        if path.is_closed() || path.points().is_empty() {
+            continue;
+        }
+        let points = path.points().to_vec();
+        if sel.contains(&points[0].id) {
+            ends.push((i, true));
+        }
+        if points.len() > 1 && sel.contains(&points[points.len() - 1].id) {
+            ends.push((i, false));
+        }
+    }
+    let [(a, a_start), (b, b_start)] = ends[..] else {
+        return false;
+    };
+
+    if a == b {
+        paths[a] = closed_path(&paths[a]);
+        return true;
+    }
+    if std::mem::discriminant(&paths[a]) != std::mem::discriminant(&paths[b]) {
+        return false;
+    }
+
+    // Run the first path towards its joined end and the second away
+    // from it
+    let mut joined = paths[a].points().to_vec();
+    if a_start {
+        joined.reverse();
+    }
+    let mut tail = paths[b].points().to_vec();
+    if !b_start {
+        tail.reverse();
+    }
+    if (joined[joined.len() - 1].point - tail[0].point).hypot() < MERGE_DISTANCE {
+        tail.remove(0);
+    }
+    joined.extend(tail);
+
+    paths[a] = rebuild(&paths[a], joined, false);
+    paths.remove(b);
+    true
+}
+
+/// Remove the handles of selected segments in a point list. Returns
+/// the IDs of the removed handles.
+fn retract_handles(
+    points: &mut Vec<PathPoint>,
+    closed: bool,
+    sel: &Selection,
+) -> Vec<crate::model::EntityId> {
+    let len = points.len();
+    let on_curve: Vec<usize> = (0..len).filter(|&i| points[i].is_on_curve()).collect();
+    if on_curve.is_empty() {
+        return Vec::new();
+    }
+
+    let segments = if closed {
+        on_curve.len()
+    } else {
+        on_curve.len() - 1
+    };
+    let mut remove = vec![false; len];
+    let mut ends = Vec::new();
+    for k in 0..segments {
+        let start = on_curve[k];
+        let end = on_curve[(k + 1) % on_curve.len()];
+        let mut handles = Vec::new();
+        let mut j = (start + 1) % len;
+        while j != end {
+            handles.push(j);
+            j = (j + 1) % len;
+        }
+        if handles.is_empty() {
+            continue;
+        }
+
+        let ends_selected = sel.contains(&points[start].id) && sel.contains(&points[end].id);
+        if ends_selected || handles.iter().any(|&h| sel.contains(&points[h].id)) {
+            for h in handles {
+                remove[h] = true;
+            }
+            ends.extend([points[start].id, points[end].id]);
+        }
+    }
+
+    let removed: Vec<_> = (0..len)
+        .filter(|&i| remove[i])
+        .map(|i| points[i].id)
+        .collect();
+    if removed.is_empty() {
+        return removed;
+    }
+    let mut index = 0;
+    points.retain(|_| {
+        let keep = !remove[index];
+        index += 1;
+        keep
+    });
+
+    // A point between two lines can't be smooth
+    let len = points.len();
+    for i in 0..len {
+        if !ends.contains(&points[i].id) {
+            continue;
+        }
+        let prev = (i > 0 || closed).then(|| (i + len - 1) % len);
+        let next = (i + 1 < len || closed).then(|| (i + 1) % len);
+        let has_handle = [prev, next]
+            .into_iter()
+            .flatten()
+            .any(|n| !points[n].is_on_curve());
+        if !has_handle {
+            make_corner(&mut points[i]);
+        }
+    }
+    removed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::EntityId;
+    use kurbo::Point;
+
+    fn point(x: f64, on_curve: bool) -> PathPoint {
+        PathPoint {
+            id: EntityId::next(),
+            point: Point::new(x, 0.0),
+            typ: if on_curve {
+                PointType::OnCurve { smooth: true }
+            } else {
+                PointType::OffCurve { auto: false }
+            },
+        }
+    }
+
+    /// Open cubic: on, off, off, on, off, off, on
+    fn open_path(start: f64) -> Path {
+        let points = [true, false, false, true, false, false, true]
+            .iter()
+            .enumerate()
+            .map(|(i, &on)| point(start + i as f64 * 10.0, on))
+            .collect();
+        Path::Cubic(CubicPath::new(PathPoints::from_vec(points), false))
+    }
+
+    fn select(points: &[&PathPoint]) -> Selection {
+        let mut selection = Selection::new();
+        for pt in points {
+            selection.insert(pt.id);
+        }
+        selection
+    }
+
+    #[test]
+    fn break_splits_open_and_opens_closed() {
+        let open = open_path(0.0);
+        let middle = open.points().to_vec()[3].clone();
+        let mut paths = vec![open.clone()];
+        assert!(break_paths(&mut paths, &select(&[&middle])));
+        assert_eq!(paths.len(), 2);
+        assert_eq!(paths[0].points().len(), 4);
+        assert_eq!(paths[1].points().len(), 4);
+
+        let closed = rebuild(&open, open.points().to_vec(), true);
+        let mut paths = vec![closed];
+        assert!(break_paths(&mut paths, &select(&[&middle])));
+        assert_eq!(paths.len(), 1);
+        assert!(!paths[0].is_closed());
+        let points = paths[0].points().to_vec();
+        assert_eq!(points.len(), 8);
+        assert_eq!(points[0].id, middle.id);
+        assert_eq!(points[7].point, middle.point);
+    }
+
+    #[test]
+    fn join_connects_endpoints_of_two_paths() {
+        let first = open_path(0.0);
+        let second = open_path(60.0);
+        let first_end = first.points().to_vec()[6].clone();
+        let second_start = second.points().to_vec()[0].clone();
+        let mut paths = vec![first, second];
+
+        assert!(join_paths(
+            &mut paths,
+            &select(&[&first_end, &second_start])
+        ));
+        assert_eq!(paths.len(), 1);
+        // The coincident endpoints at x = 60 merge into one point
+        assert_eq!(paths[0].points().len(), 13);
+        assert!(!join_paths(&mut paths, &Selection::new()));
+    }
+
+    #[test]
+    fn join_leaves_paths_of_different_kinds_apart() {
+        let cubic = open_path(0.0);
+        let quadratic = Path::Quadratic(QuadraticPath::new(
+            PathPoints::from_vec(open_path(60.0).points().to_vec()),
+            false,
+        ));
+        let cubic_end = cubic.points().to_vec()[6].clone();
+        let quadratic_start = quadratic.points().to_vec()[0].clone();
+        let mut paths = vec![cubic, quadratic];
+
+        assert!(!join_paths(
+            &mut paths,
+            &select(&[&cubic_end, &quadratic_start])
+        ));
+        assert_eq!(paths.len(), 2);
+        assert!(matches!(paths[1], Path::Quadratic(_)));
+    }
+
+    #[test]
+    fn retract_removes_handles_of_selected_segment() {
+        let mut points = open_path(0.0).points().to_vec();
+        let sel = select(&[&points[0], &points[3]]);
+        let removed = retract_handles(&mut points, false, &sel);
+
+        assert_eq!(removed.len(), 2);
+        assert_eq!(points.len(), 5);
+        assert!(points[1].is_on_curve());
+        assert_eq!(points[0].typ, PointType::OnCurve { smooth: false });
+        // Still has a handle on its outgoing side
+        assert_eq!(points[1].typ, PointType::OnCurve { smooth: true });
+    }
+}
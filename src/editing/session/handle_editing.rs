@@ -0,0 +1,314 @@
+// Copyright 2026 the Runebender Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Handle length, angle and Tunni tension for a single selected
+//! on-curve point, read and edited through the coordinate panel.
+//!
+//! Tension is the handle length as a percentage of the distance from
+//! the on-curve point to the segment's Tunni point (where the two
+//! handle lines cross). Smooth points lock their handles together, so
+//! changing the angle of one rotates the other with it.
+
+use std::sync::Arc;
+
+use kurbo::{Line, Point, Vec2};
+
+use crate::path::{Path, PathPoint, PointType};
+
+use super::EditSession;
+
+/// Which handle of an on-curve point
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HandleSide {
+    /// The handle before the point, ending the previous segment
+    Incoming,
+    /// The handle after the point, starting the next segment
+    Outgoing,
+}
+
+/// Which value of a handle is being edited
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HandleField {
+    Length,
+    Angle,
+    Tension,
+}
+
+/// Measurements of one handle
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HandleReading {
+    /// Distance from the on-curve point in design units
+    pub length: f64,
+    /// Direction in degrees, counter-clockwise from the x axis
+    pub angle: f64,
+    /// Tunni tension in percent, when the handle belongs to a cubic
+    /// segment whose handle lines cross ahead of both points
+    pub tension: Option<f64>,
+}
+
+/// Both handles of the single selected on-curve point
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HandleInfo {
+    pub incoming: Option<HandleReading>,
+    pub outgoing: Option<HandleReading>,
+}
+
+/// Indices of a handle and the points around it
+struct HandleSite {
+    /// The off-curve handle itself
+    handle: usize,
+    /// The neighbour on the other side of the on-curve point
+    opposite: Option<usize>,
+    /// The other handle and far on-curve point of a cubic segment
+    segment: Option<(usize, usize)>,
+}
+
+impl EditSession {
+    /// Handle measurements for the selected point, when exactly one
+    /// on-curve point is selected
+    pub fn selected_handles(&self) -> Option<HandleInfo> {
+        let (path_index, i) = self.single_on_curve()?;
+        let path = &self.paths[path_index];
+        let cubic = matches!(path, Path::Cubic(_));
+        let points = path.points().to_vec();
+
+        let read = |side| {
+            let site = locate(&points, path.is_closed(), cubic, i, side)?;
+            Some(reading(&points, i, &site))
+        };
+        Some(HandleInfo {
+            incoming: read(HandleSide::Incoming),
+            outgoing: read(HandleSide::Outgoing),
+        })
+    }
+
+    /// Set the length, angle or tension of one handle of the selected
+    /// point. Returns whether anything changed.
+    pub fn set_handle(&mut self, side: HandleSide, field: HandleField, value: f64) -> bool {
+        let Some((path_index, i)) = self.single_on_curve() else {
+            return false;
+        };
+        let paths = Arc::make_mut(&mut self.paths);
+        match &mut paths[path_index] {
+            Path::Cubic(cubic) => {
+                let closed = cubic.closed;
+                set_handle_in(cubic.points.make_mut(), closed, true, i, side, field, value)
+            }
+            Path::Quadratic(quadratic) => {
+                let closed = quadratic.closed;
+                set_handle_in(
+                    quadratic.points.make_mut(),
+                    closed,
+                    false,
+                    i,
+                    side,
+                    field,
+                    value,
+                )
+            }
+            // Hyper paths have no user-visible off-curve handles
+            Path::Hyper(_) => false,
+        }
+    }
+
+    /// Path and point index of the only selected point, if it is an
+    /// on-curve point
+    fn single_on_curve(&self) -> Option<(usize, usize)> {
+        if self.selection.len() != 1 {
+            return None;
+        }
+        let id = *self.selection.iter().next()?;
+        self.paths
+            .iter()
+            .enumerate()
+            .find_map(|(path_index, path)| {
+                path.points()
+                    .iter()
+                    .position(|pt| pt.id == id && pt.is_on_curve())
+                    .map(|i| (path_index, i))
+            })
+    }
+}
+
+/// The neighbour of `i`, forward or back, along a contour of `len`
+/// points
+fn step(i: usize, len: usize, closed: bool, forward: bool) -> Option<usize> {
+    match (forward, closed) {
+        (true, _) if i + 1 < len => Some(i + 1),
+        (true, true) => Some(0),
+        (false, _) if i > 0 => Some(i - 1),
+        (false, true) => Some(len - 1),
+        _ => None,
+    }
+}
+
+/// Find the `side` handle of on-curve point `i`, or `None` when that
+/// neighbour is missing or on-curve
+fn locate(
+    points: &[PathPoint],
+    closed: bool,
+    cubic: bool,
+    i: usize,
+    side: HandleSide,
+) -> Option<HandleSite> {
+    let len = points.len();
+    let forward = side == HandleSide::Outgoing;
+    let handle = step(i, len, closed, forward).filter(|&h| !points[h].is_on_curve())?;
+    let opposite = step(i, len, closed, !forward);
+
+    let segment = step(handle, len, closed, forward)
+        .filter(|&h2| cubic && !points[h2].is_on_curve())
+        .and_then(|h2| {
+            step(h2, len, closed, forward)
+                .filter(|&far| points[far].is_on_curve())
+                .map(|far| (h2, far))
+        });
+
+    Some(HandleSite {
+        handle,
+        opposite,
+        segment,
+    })
+}
+
+/// Distance from `p` to the Tunni point of the segment running from
+/// `p` through handles `h` and `h2` to `q`, when the handle lines cross
+/// ahead of both on-curve points
+fn tunni_reach(p: Point, h: Point, h2: Point, q: Point) -> Option<f64> {
+    let tunni = Line::new(p, h).crossing_point(Line::new(q, h2))?;
+    let ahead = (tunni - p).dot(h - p) > 0.0 && (tunni - q).dot(h2 - q) > 0.0;
+    ahead.then(|| (tunni - p).hypot())
+}
+
+/// Distance to the Tunni point for the handle at `site`
+fn site_reach(points: &[PathPoint], i: usize, site: &HandleSite) -> Option<f64> {
+    let (h2, far) = site.segment?;
+    tunni_reach(
+        points[i].point,
+        points[site.handle].point,
+        points[h2].point,
+        points[far].point,
+    )
+}
+
+fn reading(points: &[PathPoint], i: usize, site: &HandleSite) -> HandleReading {
+    let v = points[site.handle].point - points[i].point;
+    let length = v.hypot();
+    HandleReading {
+        length,
+        angle: v.atan2().to_degrees(),
+        tension: site_reach(points, i, site).map(|reach| length / reach * 100.0),
+    }
+}
+
+/// Edit one handle of point `i` in a point list
+fn set_handle_in(
+    points: &mut [PathPoint],
+    closed: bool,
+    cubic: bool,
+    i: usize,
+    side: HandleSide,
+    field: HandleField,
+    value: f64,
+) -> bool {
+    let Some(site) = locate(points, closed, cubic, i, side) else {
+        return false;
+    };
+    let current = reading(points, i, &site);
+    let (length, angle) = match field {
+        HandleField::Length => (value, current.angle),
+        HandleField::Angle => (current.length, value),
+        HandleField::Tension => match site_reach(points, i, &site) {
+            Some(reach) => (reach * value / 100.0, current.angle),
+            None => return false,
+        },
+    };
+    if length < 0.0 {
+        return false;
+    }
+
+    let origin = points[i].point;
+    let direction = Vec2::from_angle(angle.to_radians());
+    let smooth = matches!(points[i].typ, PointType::OnCurve { smooth: true });
+    let rotated = (angle - current.angle).abs() > 1e-9;
+
+    // Smooth points keep both handles on one line: rotate the other
+    // handle along, or refuse when it is pinned by a line segment
+    if smooth && rotated {
+        match site.opposite {
+            Some(o) if points[o].is_on_curve() => return false,
+            Some(o) => {
+                let reach = (points[o].point - origin).hypot();
+                points[o].point = origin - direction * reach;
+            }
+            None => {}
+        }
+    }
+
+    let new_point = origin + direction * length;
+    if (new_point - points[site.handle].point).hypot() < 1e-9 && !rotated {
+        return false;
+    }
+    points[site.handle].point = new_point;
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::EntityId;
+
+    fn point(x: f64, y: f64, typ: PointType) -> PathPoint {
+        PathPoint {
+            id: EntityId::next(),
+            point: Point::new(x, y),
+            typ,
+        }
+    }
+
+    /// Open contour: corner, handle, handle, smooth, handle, handle, corner
+    fn contour() -> Vec<PathPoint> {
+        let corner = PointType::OnCurve { smooth: false };
+        let smooth = PointType::OnCurve { smooth: true };
+        let handle = PointType::OffCurve { auto: false };
+        vec![
+            point(0.0, 0.0, corner),
+            point(0.0, 50.0, handle),
+            point(50.0, 100.0, handle),
+            point(100.0, 100.0, smooth),
+            point(150.0, 100.0, handle),
+            point(200.0, 50.0, handle),
+            point(200.0, 0.0, corner),
+        ]
+    }
+
+    #[test]
+    fn reads_length_angle_and_tension() {
+        let points = contour();
+        let site = locate(&points, false, true, 3, HandleSide::Outgoing).unwrap();
+        let handle = reading(&points, 3, &site);
+
+        assert!((handle.length - 50.0).abs() < 1e-9);
+        assert!(handle.angle.abs() < 1e-9);
+        // The handle lines cross at (200, 100), 100 units away
+        assert!((handle.tension.unwrap() - 50.0).abs() < 1e-9);
+        assert!(locate(&points, false, true, 0, HandleSide::Incoming).is_none());
+    }
+
+    #[test]
+    fn smooth_point_rotates_both_handles() {
+        let mut points = contour();
+        let edit = |points: &mut [PathPoint], field, value| {
+            set_handle_in(points, false, true, 3, HandleSide::Outgoing, field, value)
+        };
+
+        assert!(edit(&mut points, HandleField::Angle, 90.0));
+        assert!((points[4].point - Point::new(100.0, 150.0)).hypot() < 1e-9);
+        assert!((points[2].point - Point::new(100.0, 50.0)).hypot() < 1e-9);
+
+        let mut points = contour();
+        assert!(edit(&mut points, HandleField::Tension, 100.0));
+        assert!((points[4].point - Point::new(200.0, 100.0)).hypot() < 1e-9);
+        assert_eq!(points[2].point, Point::new(50.0, 100.0));
+    }
+}
@@ -9,16 +9,50 @@
 //! they close it, changes are synced back to the shared `Workspace`.
 //!
 //! Sub-modules split the implementation: `text_buffer` handles sort/buffer
-//! creation and shaping, `hit_testing` handles point/segment hit tests, and
-//! `path_editing` handles point movement, deletion, and contour operations.
-
+//! creation and shaping, `hit_testing` handles point/segment hit tests,
+//! `path_editing` handles point movement, deletion, and contour operations,
+//! `contour_ops` handles breaking, closing and joining contours and
+//! turning curves into lines,
+//! `boolean_ops` handles union/subtract/intersect/exclude,
+//! `component_ops` decomposes components, makes them from contours and
+//! edits their transforms,
+//! `selection_ops` handles contour-wise and type-wise selection commands,
+//! `align` handles align and distribute, `numeric_transform` handles
+//! typed-in transforms and repeating them, `handle_editing` reads and
+//! sets handle length, angle and tension, `smooth_detection` sets smooth
+//! flags from tangent collinearity, `smart_guides` gathers
+//! alignment targets for point dragging, and `spacing` edits
+//! sidebearings in spacing mode.
+
+mod align;
+mod boolean_ops;
+mod component_ops;
+mod contour_ops;
 mod curve_ops;
+mod handle_editing;
 mod hit_testing;
+mod numeric_transform;
 mod path_editing;
+mod selection_ops;
+mod smart_guides;
+mod smooth_detection;
+mod spacing;
 mod text_buffer;
 
+pub use align::{AlignEdge, AlignTarget};
+pub use component_ops::{
+    ComponentField, ComponentTransform, decompose_uses_in_workspace, extract_component,
+    unique_part_name,
+};
 pub use curve_ops::paths_to_cubics;
+pub use handle_editing::{HandleField, HandleInfo, HandleReading, HandleSide};
+pub use numeric_transform::{
+    NumericTransform, TransformScope, reanchor, transform_workspace_glyph,
+};
 pub use path_editing::snap_point_to_grid;
+pub use smart_guides::GuideTargets;
+pub use smooth_detection::detect_smooth_in_workspace_glyph;
+pub use spacing::{SidebearingEdit, SidebearingSide, SpacingHandle, adjust_glyph_sidebearing};
 
 use super::background_image::BackgroundImage;
 use super::selection::Selection;
@@ -148,6 +182,19 @@ pub struct EditSession {
     /// Last transform applied (for repeat-last-transform)
     pub last_transform: Option<kurbo::Affine>,
 
+    /// Origin `last_transform` was made about, so it can be repeated
+    /// about another origin
+    pub last_transform_origin: Option<kurbo::Point>,
+
+    /// Values typed into the transform panel's numeric section
+    pub numeric_transform: NumericTransform,
+
+    /// What the numeric transform applies to
+    pub transform_scope: TransformScope,
+
+    /// What the transform panel's align buttons align to
+    pub align_target: AlignTarget,
+
     /// Interpolation compatibility errors for the current
     /// glyph (empty when not editing a designspace or when
     /// all masters are compatible).
@@ -157,6 +204,35 @@ pub struct EditSession {
     /// Used for visual hover feedback before option-click to convert
     /// line → curve.
     pub hovered_segment: Option<crate::path::SegmentInfo>,
+
+    /// Spacing mode: in text mode, the sorts' edges are handles that
+    /// drag (or nudge) their sidebearings
+    pub spacing_mode: bool,
+
+    /// Whether sidebearing changes are replayed on every master
+    pub spacing_all_masters: bool,
+
+    /// Sidebearing handle under the cursor, or last dragged; arrow
+    /// keys nudge it
+    pub spacing_handle: Option<SpacingHandle>,
+
+    /// Sidebearing changes not yet replayed on the other masters
+    pub sidebearing_edits: Vec<SidebearingEdit>,
+
+    /// Sidebearing changes replayed on the other masters so far. Undo
+    /// and redo restore it with the rest of the session, and the
+    /// difference is replayed, so the other masters follow.
+    pub replayed_sidebearing_edits: Vec<SidebearingEdit>,
+
+    /// Bumped whenever the glyph is reloaded from the workspace after
+    /// being changed there directly. The editor drops its undo
+    /// history when it changes, since older snapshots still hold the
+    /// outlines from before the reload.
+    pub reload_generation: u64,
+
+    /// Paragraph mode: the text buffer is set as wrapped paragraphs
+    /// (or a waterfall) for proofing
+    pub paragraph: crate::sort::paragraph::ParagraphSettings,
 }
 
 impl EditSession {
@@ -205,8 +281,19 @@ impl EditSession {
             show_continuity: false,
             background_image: None,
             last_transform: None,
+            last_transform_origin: None,
+            numeric_transform: NumericTransform::default(),
+            transform_scope: TransformScope::default(),
+            align_target: AlignTarget::default(),
             compat_errors: Vec::new(),
             hovered_segment: None,
+            spacing_mode: false,
+            spacing_all_masters: false,
+            spacing_handle: None,
+            sidebearing_edits: Vec::new(),
+            replayed_sidebearing_edits: Vec::new(),
+            reload_generation: 0,
+            paragraph: Default::default(),
         }
     }
 
@@ -268,8 +355,19 @@ impl EditSession {
             show_continuity: false,
             background_image: None,
             last_transform: None,
+            last_transform_origin: None,
+            numeric_transform: NumericTransform::default(),
+            transform_scope: TransformScope::default(),
+            align_target: AlignTarget::default(),
             compat_errors: Vec::new(),
             hovered_segment: None,
+            spacing_mode: false,
+            spacing_all_masters: false,
+            spacing_handle: None,
+            sidebearing_edits: Vec::new(),
+            replayed_sidebearing_edits: Vec::new(),
+            reload_generation: 0,
+            paragraph: Default::default(),
         }
     }
 
@@ -391,6 +489,8 @@ mod tests {
             right_group: None,
             mark_color: None,
             mark_label: None,
+            measurements: Vec::new(),
+            metrics_keys: Default::default(),
         }
     }
 
@@ -0,0 +1,256 @@
+// Copyright 2026 the Runebender Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Numeric transforms for the edit session: scale, rotate, skew and
+//! translate typed into the transform panel, applied about the
+//! coordinate panel's quadrant origin to the selection or the whole
+//! glyph, and repeatable on other glyphs.
+
+use kurbo::{Affine, Point, Rect, Shape, Vec2};
+
+use crate::model::workspace::{Glyph, Workspace};
+use crate::path::{Path, Quadrant};
+
+use super::EditSession;
+
+/// Transform values typed into the transform panel
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NumericTransform {
+    /// Horizontal scale in percent
+    pub scale_x: f64,
+    /// Vertical scale in percent
+    pub scale_y: f64,
+    /// Rotation in degrees, counter-clockwise
+    pub rotate: f64,
+    /// Horizontal skew in degrees
+    pub skew_x: f64,
+    /// Vertical skew in degrees
+    pub skew_y: f64,
+    /// Horizontal translation in design units
+    pub dx: f64,
+    /// Vertical translation in design units
+    pub dy: f64,
+}
+
+impl Default for NumericTransform {
+    fn default() -> Self {
+        Self {
+            scale_x: 100.0,
+            scale_y: 100.0,
+            rotate: 0.0,
+            skew_x: 0.0,
+            skew_y: 0.0,
+            dx: 0.0,
+            dy: 0.0,
+        }
+    }
+}
+
+impl NumericTransform {
+    /// The affine for these values about `origin`: scale, skew and
+    /// rotate around the origin, then translate
+    pub fn affine_about(&self, origin: Point) -> Affine {
+        let skew = Affine::skew(
+            self.skew_x.to_radians().tan(),
+            self.skew_y.to_radians().tan(),
+        );
+        let linear = Affine::rotate(self.rotate.to_radians())
+            * skew
+            * Affine::scale_non_uniform(self.scale_x / 100.0, self.scale_y / 100.0);
+        Affine::translate(origin.to_vec2() + Vec2::new(self.dx, self.dy))
+            * linear
+            * Affine::translate(-origin.to_vec2())
+    }
+}
+
+/// What a numeric transform applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TransformScope {
+    /// The selected points of the active glyph
+    #[default]
+    Selection,
+    /// Every contour and component of the active glyph
+    Glyph,
+    /// Every glyph selected in the glyph grid
+    GridGlyphs,
+}
+
+impl TransformScope {
+    /// The next scope, for cycling through them from the panel
+    pub fn next(self) -> Self {
+        match self {
+            TransformScope::Selection => TransformScope::Glyph,
+            TransformScope::Glyph => TransformScope::GridGlyphs,
+            TransformScope::GridGlyphs => TransformScope::Selection,
+        }
+    }
+
+    /// Human-readable label for the scope button
+    pub fn label(self) -> &'static str {
+        match self {
+            TransformScope::Selection => "Selection",
+            TransformScope::Glyph => "Glyph",
+            TransformScope::GridGlyphs => "Selected Glyphs",
+        }
+    }
+}
+
+/// Move `affine` so it acts about `to` the way it acted about `from`
+pub fn reanchor(affine: Affine, from: Point, to: Point) -> Affine {
+    let shift = to - from;
+    Affine::translate(shift) * affine * Affine::translate(-shift)
+}
+
+impl EditSession {
+    /// Apply the panel's numeric transform to the selection (or the
+    /// whole glyph with `whole_glyph`) about the quadrant origin.
+    /// Returns whether anything was transformed.
+    pub fn apply_numeric_transform(&mut self, whole_glyph: bool) -> bool {
+        let Some(origin) = self.transform_origin(whole_glyph) else {
+            return false;
+        };
+        let affine = self.numeric_transform.affine_about(origin);
+        self.apply_about(affine, origin, whole_glyph);
+        true
+    }
+
+    /// Apply `last_transform` again, re-anchored to the quadrant origin
+    /// of the selection (or the whole glyph with `whole_glyph`)
+    pub fn repeat_last_transform(&mut self, whole_glyph: bool) -> bool {
+        let Some(last) = self.last_transform else {
+            return false;
+        };
+        let Some(origin) = self.transform_origin(whole_glyph) else {
+            return false;
+        };
+        let affine = match self.last_transform_origin {
+            Some(from) => reanchor(last, from, origin),
+            None => last,
+        };
+        self.apply_about(affine, origin, whole_glyph);
+        true
+    }
+
+    /// Transform every contour and component of the glyph
+    pub fn transform_glyph(&mut self, affine: Affine) {
+        let mut all = crate::editing::Selection::new();
+        for path in self.paths.iter() {
+            for pt in path.points().iter() {
+                all.insert(pt.id);
+            }
+        }
+        if !all.is_empty() {
+            let saved = std::mem::replace(&mut self.selection, all);
+            self.transform_selection(affine);
+            self.selection = saved;
+        }
+
+        if !self.glyph.components.is_empty() {
+            let glyph = std::sync::Arc::make_mut(&mut self.glyph);
+            for component in &mut glyph.components {
+                component.transform = affine * component.transform;
+            }
+        }
+    }
+
+    /// Remember a transform (and the origin it was made about) for
+    /// repeating it later
+    pub fn remember_transform(&mut self, affine: Affine, origin: Point) {
+        self.last_transform = Some(affine);
+        self.last_transform_origin = Some(origin);
+    }
+
+    /// Quadrant point of the selection bounds, or of the glyph bounds
+    pub fn transform_origin(&self, whole_glyph: bool) -> Option<Point> {
+        let bounds = if whole_glyph {
+            let components = self.decomposed_components();
+            let component_paths = components.iter().flat_map(|(_, paths)| paths);
+            paths_bounds(self.paths.iter().chain(component_paths))?
+        } else {
+            self.selection_bounding_box()?
+        };
+        Some(self.coord_selection.quadrant.point_in_dspace_rect(bounds))
+    }
+
+    fn apply_about(&mut self, affine: Affine, origin: Point, whole_glyph: bool) {
+        if whole_glyph {
+            self.transform_glyph(affine);
+        } else {
+            self.transform_selection(affine);
+        }
+        self.remember_transform(affine, origin);
+    }
+}
+
+/// Bounding box of a set of paths, or `None` when there are none
+fn paths_bounds<'a>(paths: impl Iterator<Item = &'a Path>) -> Option<Rect> {
+    paths
+        .map(|path| path.to_bezpath().bounding_box())
+        .reduce(|a, b| a.union(b))
+}
+
+/// Transform a glyph stored in the workspace (one that isn't open in
+/// the editor). `affine_for` gets the glyph's quadrant origin.
+pub fn transform_workspace_glyph(
+    workspace: &mut Workspace,
+    name: &str,
+    quadrant: Quadrant,
+    affine_for: impl Fn(Point) -> Affine,
+) -> bool {
+    let Some(bounds) = workspace
+        .get_glyph(name)
+        .and_then(|glyph| glyph_bounds(glyph, workspace))
+    else {
+        return false;
+    };
+    let affine = affine_for(quadrant.point_in_dspace_rect(bounds));
+
+    let Some(glyph) = workspace.get_glyph_mut(name) else {
+        return false;
+    };
+    for contour in &mut glyph.contours {
+        *contour = Path::from_contour(contour).transformed(affine).to_contour();
+    }
+    for component in &mut glyph.components {
+        component.transform = affine * component.transform;
+    }
+    true
+}
+
+/// Bounds of a workspace glyph's contours and components
+fn glyph_bounds(glyph: &Glyph, workspace: &Workspace) -> Option<Rect> {
+    let contours: Vec<Path> = glyph.contours.iter().map(Path::from_contour).collect();
+    let components: Vec<Path> = glyph
+        .components
+        .iter()
+        .flat_map(|component| crate::path::decompose_component(component, workspace))
+        .collect();
+    paths_bounds(contours.iter().chain(&components))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn affine_scales_about_origin_then_translates() {
+        let transform = NumericTransform {
+            scale_x: 200.0,
+            dx: 10.0,
+            ..NumericTransform::default()
+        };
+        let affine = transform.affine_about(Point::new(100.0, 0.0));
+
+        assert_eq!(affine * Point::new(100.0, 50.0), Point::new(110.0, 50.0));
+        assert_eq!(affine * Point::new(150.0, 0.0), Point::new(210.0, 0.0));
+    }
+
+    #[test]
+    fn reanchor_keeps_transform_relative_to_origin() {
+        let rotate = Affine::rotate_about(std::f64::consts::FRAC_PI_2, Point::new(0.0, 0.0));
+        let moved = reanchor(rotate, Point::ZERO, Point::new(100.0, 100.0));
+
+        let p = moved * Point::new(110.0, 100.0);
+        assert!((p - Point::new(100.0, 110.0)).hypot() < 1e-9);
+    }
+}
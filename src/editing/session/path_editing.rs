@@ -255,12 +255,13 @@ impl EditSession {
     // SELECTION BOUNDING BOX
     // ================================================================
 
-    /// Compute the bounding box of all selected points
+    /// Compute the bounding box of all selected points, or of the
+    /// selected component's outline when no points are selected
     ///
     /// Returns None if nothing is selected.
     pub fn selection_bounding_box(&self) -> Option<kurbo::Rect> {
         if self.selection.is_empty() {
-            return None;
+            return self.selected_component_bounds();
         }
 
         let mut min_x = f64::INFINITY;
@@ -301,9 +302,12 @@ impl EditSession {
     ///
     /// Adjacent off-curve handles of selected on-curve points are
     /// also transformed. After the transform, points are snapped to
-    /// grid and smooth constraints are enforced.
+    /// grid and smooth constraints are enforced. With no points
+    /// selected, the selected component's transform is updated.
     pub fn transform_selection(&mut self, affine: kurbo::Affine) {
         if self.selection.is_empty() {
+            // A selected component takes the transform instead
+            self.transform_selected_component(affine);
             return;
         }
 
@@ -385,7 +389,7 @@ impl EditSession {
             .then_translate(-center.to_vec2());
 
         self.transform_selection(affine);
-        self.last_transform = Some(affine);
+        self.remember_transform(affine, center);
     }
 
     /// Flip selected points vertically around the selection center
@@ -400,7 +404,7 @@ impl EditSession {
             .then_translate(-center.to_vec2());
 
         self.transform_selection(affine);
-        self.last_transform = Some(affine);
+        self.remember_transform(affine, center);
     }
 
     /// Rotate selected points by the given angle (in degrees)
@@ -415,7 +419,7 @@ impl EditSession {
         let affine = kurbo::Affine::rotate_about(radians, center);
 
         self.transform_selection(affine);
-        self.last_transform = Some(affine);
+        self.remember_transform(affine, center);
     }
 
     /// Translate every point of every path by `delta`.
@@ -451,7 +455,7 @@ impl EditSession {
             .then_translate(center.to_vec2());
 
         self.transform_selection(affine);
-        self.last_transform = Some(affine);
+        self.remember_transform(affine, center);
     }
 
     /// Skew selected points by (sx, sy) degrees around the
@@ -473,7 +477,7 @@ impl EditSession {
         let affine = back * skew * to_origin;
 
         self.transform_selection(affine);
-        self.last_transform = Some(affine);
+        self.remember_transform(affine, center);
     }
 
     /// Duplicate selected contours
@@ -800,6 +804,8 @@ impl EditSession {
             right_group: self.glyph.right_group.clone(),
             mark_color: self.glyph.mark_color.clone(),
             mark_label: self.glyph.mark_label.clone(),
+            measurements: self.glyph.measurements.clone(),
+            metrics_keys: self.glyph.metrics_keys.clone(),
         }
     }
 
@@ -972,123 +978,6 @@ impl EditSession {
         self.selection = Selection::new();
     }
 
-    /// Apply a boolean operation (union, subtract, intersect, XOR)
-    /// to all contours in the glyph.
-    ///
-    /// For Union: merges all overlapping contours into one.
-    /// For Subtract/Intersect/Exclude: applies the operation
-    /// between the first contour and all remaining contours.
-    pub fn boolean_op(
-        &mut self,
-        op: linesweeper::BinaryOp,
-    ) {
-        use crate::editing::tracing::bezpath_to_cubic;
-
-        if self.paths.len() < 2 {
-            tracing::warn!(
-                "Boolean ops need at least 2 contours"
-            );
-            return;
-        }
-
-        // Convert all paths to kurbo::BezPath
-        let bezpaths: Vec<kurbo::BezPath> = self
-            .paths
-            .iter()
-            .map(|p| p.to_bezpath())
-            .collect();
-
-        // Combine all contours into two BezPaths for the
-        // binary op. For union, we put everything into set_a
-        // and use an empty set_b. For other ops, first contour
-        // is set_a, rest are set_b.
-        let (set_a, set_b) = match op {
-            linesweeper::BinaryOp::Union => {
-                // Merge all contours into one BezPath
-                let mut combined = kurbo::BezPath::new();
-                for bp in &bezpaths {
-                    for el in bp.elements() {
-                        combined.push(*el);
-                    }
-                }
-                (combined, kurbo::BezPath::new())
-            }
-            _ => {
-                // First contour vs all others
-                let mut rest = kurbo::BezPath::new();
-                for bp in bezpaths.iter().skip(1) {
-                    for el in bp.elements() {
-                        rest.push(*el);
-                    }
-                }
-                (bezpaths[0].clone(), rest)
-            }
-        };
-
-        let result = match linesweeper::binary_op(
-            &set_a,
-            &set_b,
-            linesweeper::FillRule::NonZero,
-            op,
-        ) {
-            Ok(contours) => contours,
-            Err(e) => {
-                tracing::error!(
-                    "Boolean operation failed: {e}"
-                );
-                return;
-            }
-        };
-
-        // Collect original on-curve point types so we can
-        // restore them on output points that didn't move.
-        let original_types: Vec<(Point, crate::path::PointType)> =
-            self.paths
-                .iter()
-                .flat_map(|p| match p {
-                    Path::Cubic(c) => c.points().iter().cloned()
-                        .collect::<Vec<_>>(),
-                    Path::Quadratic(q) => q.points().iter()
-                        .cloned().collect::<Vec<_>>(),
-                    Path::Hyper(h) => h.points().iter()
-                        .cloned().collect::<Vec<_>>(),
-                })
-                .filter(|pt| pt.is_on_curve())
-                .map(|pt| (pt.point, pt.typ))
-                .collect();
-
-        // Convert result contours back to our Path type
-        let new_paths: Vec<Path> = result
-            .contours()
-            .map(|contour| {
-                let mut cubic = bezpath_to_cubic(&contour.path);
-                // Restore original point types for points that
-                // match an input position (within tolerance).
-                restore_original_point_types(
-                    &mut cubic,
-                    &original_types,
-                );
-                Path::Cubic(cubic)
-            })
-            .collect();
-
-        if new_paths.is_empty() {
-            tracing::warn!(
-                "Boolean op produced no contours"
-            );
-            return;
-        }
-
-        tracing::info!(
-            "Boolean op: {} contours → {} contours",
-            self.paths.len(),
-            new_paths.len()
-        );
-
-        self.selection = Selection::new();
-        self.paths = Arc::new(new_paths);
-    }
-
     /// Sync current edits to the workspace immediately
     ///
     /// This updates the workspace with the current editing state so that
@@ -1917,36 +1806,3 @@ impl EditSession {
         }
     }
 }
-
-/// Restore original point types on a CubicPath after a boolean op.
-///
-/// Matches output on-curve points to input points by position.
-/// Points that match an original position get their original type
-/// restored; new points (intersections) keep the type assigned by
-/// `bezpath_to_cubic`.
-fn restore_original_point_types(
-    cubic: &mut crate::path::CubicPath,
-    originals: &[(Point, crate::path::PointType)],
-) {
-    // Position tolerance — points within this distance are
-    // considered the same.
-    const TOLERANCE: f64 = 0.5;
-    let tol_sq = TOLERANCE * TOLERANCE;
-
-    let points = cubic.points.make_mut();
-    for pt in points.iter_mut() {
-        if !pt.is_on_curve() {
-            continue;
-        }
-        // Find closest original point
-        if let Some((_, orig_typ)) = originals.iter().find(
-            |(orig_pos, _)| {
-                let dx = pt.point.x - orig_pos.x;
-                let dy = pt.point.y - orig_pos.y;
-                dx * dx + dy * dy < tol_sq
-            },
-        ) {
-            pt.typ = *orig_typ;
-        }
-    }
-}
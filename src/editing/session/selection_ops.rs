@@ -0,0 +1,272 @@
+// Copyright 2026 the Runebender Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Selection commands on the edit session: whole contours, points of
+//! the same type, inverting, growing or shrinking along a contour, and
+//! stepping to the next or previous contour.
+
+use crate::editing::selection::Selection;
+use crate::model::EntityId;
+use crate::path::{Path, PointType};
+
+use super::EditSession;
+
+/// The kind of point matched by "select same type"
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PointKind {
+    Smooth,
+    Corner,
+    OffCurve,
+}
+
+impl PointKind {
+    fn of(typ: PointType) -> Self {
+        match typ {
+            PointType::OnCurve { smooth: true } => PointKind::Smooth,
+            PointType::OnCurve { smooth: false } => PointKind::Corner,
+            PointType::OffCurve { .. } => PointKind::OffCurve,
+        }
+    }
+}
+
+impl EditSession {
+    /// Replace the selection with every point of the contour at
+    /// `path_index`.
+    pub fn select_contour(&mut self, path_index: usize) -> bool {
+        let Some(path) = self.paths.get(path_index) else {
+            return false;
+        };
+        let mut selection = Selection::new();
+        for pt in path.points().iter() {
+            selection.insert(pt.id);
+        }
+        self.selection = selection;
+        self.clear_component_selection();
+        true
+    }
+
+    /// Select every point that is the same type (smooth, corner or
+    /// off-curve) as one of the selected points.
+    pub fn select_same_type(&mut self) -> bool {
+        match same_type_selection(&self.paths, &self.selection) {
+            Some(selection) => {
+                self.selection = selection;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Select every unselected point and deselect the rest.
+    pub fn invert_selection(&mut self) -> bool {
+        let mut selection = Selection::new();
+        for pt in self.paths.iter().flat_map(|path| path.points().iter()) {
+            if !self.selection.contains(&pt.id) {
+                selection.insert(pt.id);
+            }
+        }
+        self.selection = selection;
+        self.clear_component_selection();
+        true
+    }
+
+    /// Extend the selection by one point in both directions along
+    /// each contour.
+    pub fn grow_selection(&mut self) -> bool {
+        let selection = grown_selection(&self.paths, &self.selection);
+        let changed = selection.len() != self.selection.len();
+        self.selection = selection;
+        changed
+    }
+
+    /// Drop the points at both ends of each selected run along a
+    /// contour.
+    pub fn shrink_selection(&mut self) -> bool {
+        let selection = shrunk_selection(&self.paths, &self.selection);
+        let changed = selection.len() != self.selection.len();
+        self.selection = selection;
+        changed
+    }
+
+    /// Select the whole contour after (or before) the selected ones,
+    /// wrapping around at the ends of the contour list.
+    pub fn select_adjacent_contour(&mut self, forward: bool) -> bool {
+        let count = self.paths.len();
+        if count == 0 {
+            return false;
+        }
+
+        let selected: Vec<usize> = self
+            .paths
+            .iter()
+            .enumerate()
+            .filter(|(_, path)| {
+                path.points()
+                    .iter()
+                    .any(|pt| self.selection.contains(&pt.id))
+            })
+            .map(|(i, _)| i)
+            .collect();
+
+        let index = match (selected.first(), selected.last()) {
+            (Some(_), Some(&last)) if forward => (last + 1) % count,
+            (Some(&first), Some(_)) => (first + count - 1) % count,
+            _ if forward => 0,
+            _ => count - 1,
+        };
+        self.select_contour(index)
+    }
+}
+
+/// Every point sharing a type with a selected point, or `None` when
+/// nothing is selected
+fn same_type_selection(paths: &[Path], sel: &Selection) -> Option<Selection> {
+    let points = || paths.iter().flat_map(|path| path.points().iter());
+
+    let kinds: Vec<PointKind> = points()
+        .filter(|pt| sel.contains(&pt.id))
+        .map(|pt| PointKind::of(pt.typ))
+        .collect();
+    if kinds.is_empty() {
+        return None;
+    }
+
+    let mut selection = Selection::new();
+    for pt in points().filter(|pt| kinds.contains(&PointKind::of(pt.typ))) {
+        selection.insert(pt.id);
+    }
+    Some(selection)
+}
+
+/// Index of the neighbours of point `i` in a contour of `len` points
+fn neighbours(i: usize, len: usize, closed: bool) -> [Option<usize>; 2] {
+    let prev = if i > 0 {
+        Some(i - 1)
+    } else if closed {
+        Some(len - 1)
+    } else {
+        None
+    };
+    let next = if i + 1 < len {
+        Some(i + 1)
+    } else if closed {
+        Some(0)
+    } else {
+        None
+    };
+    [prev, next]
+}
+
+/// The selection plus the neighbours of every selected point
+fn grown_selection(paths: &[Path], sel: &Selection) -> Selection {
+    let mut selection = sel.clone();
+    for path in paths {
+        let ids: Vec<EntityId> = path.points().iter().map(|pt| pt.id).collect();
+        for (i, id) in ids.iter().enumerate() {
+            if !sel.contains(id) {
+                continue;
+            }
+            for n in neighbours(i, ids.len(), path.is_closed())
+                .into_iter()
+                .flatten()
+            {
+                selection.insert(ids[n]);
+            }
+        }
+    }
+    selection
+}
+
+/// The selection minus every selected point that borders an
+/// unselected point or the end of an open contour
+fn shrunk_selection(paths: &[Path], sel: &Selection) -> Selection {
+    let mut selection = sel.clone();
+    for path in paths {
+        let ids: Vec<EntityId> = path.points().iter().map(|pt| pt.id).collect();
+        for (i, id) in ids.iter().enumerate() {
+            if !sel.contains(id) {
+                continue;
+            }
+            let on_edge = neighbours(i, ids.len(), path.is_closed())
+                .into_iter()
+                .any(|n| n.is_none_or(|n| !sel.contains(&ids[n])));
+            if on_edge {
+                selection.remove(id);
+            }
+        }
+    }
+    selection
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::path::{CubicPath, PathPoint, PathPoints};
+    use kurbo::Point;
+
+    fn point(x: f64, typ: PointType) -> PathPoint {
+        PathPoint {
+            id: EntityId::next(),
+            point: Point::new(x, 0.0),
+            typ,
+        }
+    }
+
+    fn contour(closed: bool) -> Path {
+        let corner = PointType::OnCurve { smooth: false };
+        let smooth = PointType::OnCurve { smooth: true };
+        let handle = PointType::OffCurve { auto: false };
+        let points = vec![
+            point(0.0, corner),
+            point(10.0, handle),
+            point(20.0, handle),
+            point(30.0, smooth),
+            point(40.0, corner),
+        ];
+        Path::Cubic(CubicPath::new(PathPoints::from_vec(points), closed))
+    }
+
+    fn ids(path: &Path, indices: &[usize]) -> Selection {
+        let mut selection = Selection::new();
+        for &i in indices {
+            selection.insert(path.points().iter().nth(i).unwrap().id);
+        }
+        selection
+    }
+
+    fn assert_selects(selection: &Selection, path: &Path, indices: &[usize]) {
+        let expected = ids(path, indices);
+        assert_eq!(selection.len(), expected.len());
+        assert!(expected.iter().all(|id| selection.contains(id)));
+    }
+
+    #[test]
+    fn grow_and_shrink_follow_the_contour() {
+        let open = contour(false);
+        let paths = [open.clone()];
+
+        let grown = grown_selection(&paths, &ids(&open, &[0]));
+        assert_selects(&grown, &open, &[0, 1]);
+        let grown = grown_selection(&paths, &grown);
+        assert_selects(&grown, &open, &[0, 1, 2]);
+        assert_selects(&shrunk_selection(&paths, &grown), &open, &[1]);
+
+        let closed = contour(true);
+        let paths = [closed.clone()];
+        let grown = grown_selection(&paths, &ids(&closed, &[0]));
+        assert_selects(&grown, &closed, &[0, 1, 4]);
+        assert_selects(&shrunk_selection(&paths, &grown), &closed, &[0]);
+    }
+
+    #[test]
+    fn same_type_matches_selected_kinds() {
+        let path = contour(true);
+        let paths = [path.clone()];
+
+        let selection = same_type_selection(&paths, &ids(&path, &[0])).unwrap();
+        assert_selects(&selection, &path, &[0, 4]);
+        let selection = same_type_selection(&paths, &ids(&path, &[1, 3])).unwrap();
+        assert_selects(&selection, &path, &[1, 2, 3]);
+        assert!(same_type_selection(&paths, &Selection::new()).is_none());
+    }
+}
@@ -0,0 +1,246 @@
+// Copyright 2026 the Runebender Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Smart alignment guides for point dragging
+//!
+//! While points are dragged, the grabbed point snaps to the x or y of
+//! other on-curve points, the font's metrics lines, the advance width
+//! edges and the points of the neighbouring sorts. Everything here is in
+//! the active glyph's coordinates (without the sort's x offset).
+
+use super::EditSession;
+use crate::model::kerning::lookup_kerning;
+use crate::model::read_workspace;
+use crate::model::workspace::{PointType, Workspace};
+use crate::sort::SortKind;
+use kurbo::{Line, Point, Vec2};
+
+/// A coordinate a dragged point can align to
+#[derive(Debug, Clone, Copy)]
+struct GuideTarget {
+    /// The x (vertical guide) or y (horizontal guide) to align to
+    value: f64,
+    /// Extent of the source along the other axis, for drawing the guide
+    span: (f64, f64),
+}
+
+/// Alignment targets, gathered once when a drag starts
+#[derive(Debug, Clone, Default)]
+pub struct GuideTargets {
+    /// Targets for vertical guides (matching x)
+    xs: Vec<GuideTarget>,
+    /// Targets for horizontal guides (matching y)
+    ys: Vec<GuideTarget>,
+}
+
+impl GuideTargets {
+    /// Snap `point` to the nearest targets within `threshold` (design units)
+    ///
+    /// Each axis snaps independently. Returns the correction to apply and
+    /// the guide lines to draw.
+    pub fn snap(&self, point: Point, threshold: f64) -> (Vec2, Vec<Line>) {
+        let dx = nearest_offset(&self.xs, point.x, threshold);
+        let dy = nearest_offset(&self.ys, point.y, threshold);
+        let snapped = point + Vec2::new(dx.unwrap_or(0.0), dy.unwrap_or(0.0));
+
+        let mut guides = Vec::new();
+        if dx.is_some() {
+            let (lo, hi) = matching_span(&self.xs, snapped.x, snapped.y);
+            guides.push(Line::new((snapped.x, lo), (snapped.x, hi)));
+        }
+        if dy.is_some() {
+            let (lo, hi) = matching_span(&self.ys, snapped.y, snapped.x);
+            guides.push(Line::new((lo, snapped.y), (hi, snapped.y)));
+        }
+        (snapped - point, guides)
+    }
+
+    /// Add a point that aligns on both axes
+    fn add_point(&mut self, point: Point) {
+        self.xs.push(GuideTarget {
+            value: point.x,
+            span: (point.y, point.y),
+        });
+        self.ys.push(GuideTarget {
+            value: point.y,
+            span: (point.x, point.x),
+        });
+    }
+}
+
+impl EditSession {
+    /// Gather smart guide targets for dragging the current selection
+    ///
+    /// Selected points are skipped since they move with the drag.
+    pub fn smart_guide_targets(&self) -> GuideTargets {
+        let mut targets = GuideTargets::default();
+
+        // On-curve points of the glyph's contours and components
+        let components = self.decomposed_components();
+        let component_paths = components.iter().flat_map(|(_, paths)| paths);
+        for path in self.paths.iter().chain(component_paths) {
+            for pt in path.points().iter() {
+                if pt.is_on_curve() && !self.selection.contains(&pt.id) {
+                    targets.add_point(pt.point);
+                }
+            }
+        }
+
+        // Metrics lines run across the advance width
+        let width = self.glyph.width;
+        let metrics = [
+            Some(0.0),
+            Some(self.descender),
+            Some(self.ascender),
+            self.x_height,
+            self.cap_height,
+        ];
+        for y in metrics.into_iter().flatten() {
+            targets.ys.push(GuideTarget {
+                value: y,
+                span: (0.0, width),
+            });
+        }
+
+        // Advance width edges run from descender to ascender
+        for x in [0.0, width] {
+            targets.xs.push(GuideTarget {
+                value: x,
+                span: (self.descender, self.ascender),
+            });
+        }
+
+        self.add_neighbour_targets(&mut targets);
+        targets
+    }
+
+    /// Add the on-curve points of the sorts either side of the active one
+    fn add_neighbour_targets(&self, targets: &mut GuideTargets) {
+        let Some(workspace) = self.workspace.as_ref() else {
+            return;
+        };
+        let workspace = read_workspace(workspace);
+
+        for (offset, name) in self.neighbour_sorts(&workspace) {
+            let Some(glyph) = workspace.get_glyph(&name) else {
+                continue;
+            };
+            let shift = Vec2::new(offset, 0.0);
+
+            for pt in glyph.contours.iter().flat_map(|c| &c.points) {
+                if pt.point_type != PointType::OffCurve {
+                    targets.add_point(Point::new(pt.x, pt.y) + shift);
+                }
+            }
+            for component in &glyph.components {
+                for path in crate::path::decompose_component(component, &workspace) {
+                    for pt in path.points().iter().filter(|pt| pt.is_on_curve()) {
+                        targets.add_point(pt.point + shift);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Glyph names of the sorts either side of the active sort, with
+    /// their x offsets relative to it
+    ///
+    /// Sorts are kerned against the active one as the text buffer sets
+    /// them, and follow it leftwards in right-to-left text.
+    fn neighbour_sorts(&self, workspace: &Workspace) -> Vec<(f64, String)> {
+        let (Some(buffer), Some(active)) = (&self.text_buffer, self.active_sort_index) else {
+            return Vec::new();
+        };
+        let active_name = self.glyph.name.as_str();
+        let kern = |first: &str, second: &str| {
+            lookup_kerning(
+                &workspace.kerning,
+                &workspace.groups,
+                first,
+                workspace
+                    .get_glyph(first)
+                    .and_then(|g| g.right_group.as_deref()),
+                second,
+                workspace
+                    .get_glyph(second)
+                    .and_then(|g| g.left_group.as_deref()),
+            )
+        };
+        let rtl = self.text_direction.is_rtl();
+
+        let mut neighbours = Vec::new();
+        if let Some(prev) = active.checked_sub(1).and_then(|i| buffer.get(i))
+            && let SortKind::Glyph {
+                name,
+                advance_width,
+                ..
+            } = &prev.kind
+        {
+            let offset = if rtl {
+                self.glyph.width + kern(name, active_name)
+            } else {
+                -advance_width - kern(name, active_name)
+            };
+            neighbours.push((offset, name.clone()));
+        }
+        if let Some(next) = buffer.get(active + 1)
+            && let SortKind::Glyph {
+                name,
+                advance_width,
+                ..
+            } = &next.kind
+        {
+            let offset = if rtl {
+                -advance_width - kern(active_name, name)
+            } else {
+                self.glyph.width + kern(active_name, name)
+            };
+            neighbours.push((offset, name.clone()));
+        }
+        neighbours
+    }
+}
+
+/// Offset to the nearest target within `threshold`, if any
+fn nearest_offset(targets: &[GuideTarget], value: f64, threshold: f64) -> Option<f64> {
+    targets
+        .iter()
+        .map(|target| target.value - value)
+        .filter(|offset| offset.abs() <= threshold)
+        .min_by(|a, b| a.abs().total_cmp(&b.abs()))
+}
+
+/// Combined extent of every target at `value` and the dragged point
+fn matching_span(targets: &[GuideTarget], value: f64, along: f64) -> (f64, f64) {
+    targets
+        .iter()
+        .filter(|target| (target.value - value).abs() < 1e-6)
+        .fold((along, along), |(lo, hi), target| {
+            (lo.min(target.span.0), hi.max(target.span.1))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snaps_each_axis_to_nearest_target() {
+        let mut targets = GuideTargets::default();
+        targets.add_point(Point::new(100.0, 500.0));
+        targets.ys.push(GuideTarget {
+            value: 0.0,
+            span: (0.0, 600.0),
+        });
+
+        let (correction, guides) = targets.snap(Point::new(103.0, 2.0), 4.0);
+        assert_eq!(correction, Vec2::new(-3.0, -2.0));
+        assert_eq!(guides.len(), 2);
+        assert_eq!(guides[0], Line::new((100.0, 0.0), (100.0, 500.0)));
+        assert_eq!(guides[1], Line::new((0.0, 0.0), (600.0, 0.0)));
+
+        let (correction, guides) = targets.snap(Point::new(110.0, 200.0), 4.0);
+        assert_eq!(correction, Vec2::ZERO);
+        assert!(guides.is_empty());
+    }
+}
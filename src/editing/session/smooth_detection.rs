@@ -0,0 +1,231 @@
+// Copyright 2026 the Runebender Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Smooth flag detection: set each on-curve point's `smooth` flag from
+//! whether its incoming and outgoing tangents are collinear within an
+//! angle tolerance, optionally rotating nearly-collinear handles onto
+//! one exact line. Imported sources often carry inconsistent flags,
+//! which throws off harmonize and the smooth-drag constraints.
+
+use std::sync::Arc;
+
+use kurbo::Vec2;
+
+use crate::model::workspace::Workspace;
+use crate::path::{Path, PathPoint, PointType};
+
+use super::EditSession;
+
+/// Tangents shorter than this are treated as retracted
+const MIN_TANGENT: f64 = 1e-9;
+
+impl EditSession {
+    /// Detect smooth flags across the glyph's contours, snapping
+    /// nearly-collinear handles with `snap`. Returns whether anything
+    /// changed.
+    pub fn detect_smooth_points(&mut self, tolerance: f64, snap: bool) -> bool {
+        let paths = Arc::make_mut(&mut self.paths);
+        let mut changed = 0;
+        for path in paths.iter_mut() {
+            changed += detect_in_path(path, tolerance, snap);
+        }
+        changed > 0
+    }
+}
+
+/// Detect smooth flags in a glyph stored in the workspace (one that
+/// isn't open in the editor). Returns the number of points changed.
+pub fn detect_smooth_in_workspace_glyph(
+    workspace: &mut Workspace,
+    name: &str,
+    tolerance: f64,
+    snap: bool,
+) -> usize {
+    let Some(glyph) = workspace.get_glyph_mut(name) else {
+        return 0;
+    };
+    let mut changed = 0;
+    for contour in &mut glyph.contours {
+        let mut path = Path::from_contour(contour);
+        let count = detect_in_path(&mut path, tolerance, snap);
+        if count > 0 {
+            *contour = path.to_contour();
+            changed += count;
+        }
+    }
+    changed
+}
+
+fn detect_in_path(path: &mut Path, tolerance: f64, snap: bool) -> usize {
+    match path {
+        Path::Cubic(cubic) => {
+            let closed = cubic.closed;
+            detect_in_points(cubic.points.make_mut(), closed, tolerance, snap)
+        }
+        Path::Quadratic(quadratic) => {
+            let closed = quadratic.closed;
+            detect_in_points(quadratic.points.make_mut(), closed, tolerance, snap)
+        }
+        // Hyperbezier points carry their own smooth/corner kind
+        Path::Hyper(_) => 0,
+    }
+}
+
+/// Unit tangent leaving point `i` backwards (`forward` false) or
+/// forwards, skipping control points that sit on top of it. Returns
+/// the tangent and whether its first neighbour is a handle.
+fn tangent(points: &[PathPoint], closed: bool, i: usize, forward: bool) -> Option<(Vec2, bool)> {
+    let len = points.len();
+    let step = |j: usize| match (forward, closed) {
+        (true, _) if j + 1 < len => Some(j + 1),
+        (true, true) => Some(0),
+        (false, _) if j > 0 => Some(j - 1),
+        (false, true) => Some(len - 1),
+        _ => None,
+    };
+
+    let first = step(i)?;
+    let mut j = first;
+    for _ in 0..3 {
+        if j == i {
+            return None;
+        }
+        let v = points[j].point - points[i].point;
+        if v.hypot() > MIN_TANGENT {
+            return Some((v.normalize(), !points[first].is_on_curve()));
+        }
+        if points[j].is_on_curve() {
+            return None;
+        }
+        j = step(j)?;
+    }
+    None
+}
+
+/// Set smooth flags (and snap handles) in one point list. Returns the
+/// number of points changed.
+fn detect_in_points(points: &mut [PathPoint], closed: bool, tolerance: f64, snap: bool) -> usize {
+    let len = points.len();
+    let mut changed = 0;
+    for i in 0..len {
+        let PointType::OnCurve { smooth: was_smooth } = points[i].typ else {
+            continue;
+        };
+        let (Some((back, back_handle)), Some((ahead, ahead_handle))) = (
+            tangent(points, closed, i, false),
+            tangent(points, closed, i, true),
+        ) else {
+            continue;
+        };
+
+        // Tangents are collinear when `back` points opposite `ahead`
+        let angle = (-back).dot(ahead).clamp(-1.0, 1.0).acos().to_degrees();
+        // A point between two lines has nothing to keep smooth
+        let smooth = angle <= tolerance && (back_handle || ahead_handle);
+        if smooth != was_smooth {
+            points[i].typ = PointType::OnCurve { smooth };
+            changed += 1;
+        }
+        if smooth && snap && angle > 0.0 && snap_handles(points, closed, i, back, ahead) {
+            changed += 1;
+        }
+    }
+    changed
+}
+
+/// Rotate the handles next to point `i` onto one line: the line
+/// segment's direction when one side is a line, otherwise the mean of
+/// both tangents
+fn snap_handles(points: &mut [PathPoint], closed: bool, i: usize, back: Vec2, ahead: Vec2) -> bool {
+    let len = points.len();
+    let prev = (i > 0 || closed).then(|| (i + len - 1) % len);
+    let next = (i + 1 < len || closed).then(|| (i + 1) % len);
+    let is_handle =
+        |j: Option<usize>, points: &[PathPoint]| j.filter(|&j| !points[j].is_on_curve());
+    let (prev_handle, next_handle) = (is_handle(prev, points), is_handle(next, points));
+
+    let direction = match (prev_handle, next_handle) {
+        (Some(_), Some(_)) => (ahead - back).normalize(),
+        (None, Some(_)) => -back,
+        (Some(_), None) => ahead,
+        (None, None) => return false,
+    };
+
+    let origin = points[i].point;
+    let mut moved = false;
+    for (handle, sign) in [(prev_handle, -1.0), (next_handle, 1.0)] {
+        let Some(h) = handle else {
+            continue;
+        };
+        let reach = (points[h].point - origin).hypot();
+        if reach <= MIN_TANGENT {
+            continue;
+        }
+        points[h].point = origin + direction * (sign * reach);
+        moved = true;
+    }
+    moved
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::EntityId;
+    use kurbo::Point;
+
+    fn point(x: f64, y: f64, typ: PointType) -> PathPoint {
+        PathPoint {
+            id: EntityId::next(),
+            point: Point::new(x, y),
+            typ,
+        }
+    }
+
+    fn handle(x: f64, y: f64) -> PathPoint {
+        point(x, y, PointType::OffCurve { auto: false })
+    }
+
+    fn corner(x: f64, y: f64) -> PathPoint {
+        point(x, y, PointType::OnCurve { smooth: false })
+    }
+
+    #[test]
+    fn flags_follow_tangent_collinearity() {
+        // Handles either side of (100, 0) are 1° off a straight line,
+        // and the point at (200, 0) joins a line at a right angle
+        let tilt = 50.0 * 1f64.to_radians().tan();
+        let mut points = vec![
+            corner(0.0, 0.0),
+            handle(50.0, 0.0),
+            corner(100.0, 0.0),
+            handle(150.0, tilt),
+            handle(200.0, -50.0),
+            point(200.0, 0.0, PointType::OnCurve { smooth: true }),
+            corner(300.0, 0.0),
+        ];
+
+        assert_eq!(detect_in_points(&mut points, false, 2.0, false), 2);
+        assert_eq!(points[2].typ, PointType::OnCurve { smooth: true });
+        assert_eq!(points[5].typ, PointType::OnCurve { smooth: false });
+        assert_eq!(detect_in_points(&mut points, false, 0.5, false), 1);
+        assert_eq!(points[2].typ, PointType::OnCurve { smooth: false });
+    }
+
+    #[test]
+    fn snap_makes_handles_exactly_collinear() {
+        let mut points = vec![
+            corner(0.0, 0.0),
+            handle(40.0, 0.0),
+            corner(100.0, 0.0),
+            handle(150.0, 1.0),
+            corner(200.0, 0.0),
+        ];
+
+        detect_in_points(&mut points, false, 2.0, true);
+
+        let back = points[1].point - points[2].point;
+        let ahead = points[3].point - points[2].point;
+        assert!(back.cross(ahead).abs() < 1e-9);
+        assert!((back.hypot() - 60.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,212 @@
+// Copyright 2026 the Runebender Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Sidebearing editing for spacing mode
+//!
+//! In spacing mode the left and right edges of the sorts in the text
+//! buffer are handles. Moving a left edge moves the glyph's outlines,
+//! components and measurements the other way and changes the advance
+//! width by the same amount; moving a right edge only changes the
+//! advance width. When spacing applies to every master, each change is
+//! recorded so it can be replayed on the other masters, and kept so
+//! undo and redo can take them back or replay them again.
+
+use super::EditSession;
+use crate::model::workspace::Glyph;
+use crate::sort::SortKind;
+use kurbo::{Affine, Vec2};
+use std::sync::Arc;
+
+/// Which sidebearing of a glyph
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SidebearingSide {
+    Left,
+    Right,
+}
+
+/// A sidebearing handle of a sort in the text buffer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpacingHandle {
+    pub sort_index: usize,
+    pub side: SidebearingSide,
+}
+
+/// A sidebearing change made in the editor
+#[derive(Debug, Clone, PartialEq)]
+pub struct SidebearingEdit {
+    pub glyph: String,
+    pub side: SidebearingSide,
+    /// Units added to the sidebearing (negative narrows it)
+    pub delta: f64,
+}
+
+impl EditSession {
+    /// Widen the active glyph's left or right sidebearing by `delta`
+    /// (or narrow it, when negative), update every sort of the glyph
+    /// in the text buffer, and write the glyph to the workspace.
+    pub fn adjust_sidebearing(&mut self, side: SidebearingSide, delta: f64) -> bool {
+        let Some(name) = self.active_sort_name.clone() else {
+            return false;
+        };
+        if delta.abs() < 1e-9 {
+            return false;
+        }
+
+        if side == SidebearingSide::Left {
+            self.translate_all_paths(Vec2::new(delta, 0.0));
+        }
+        let glyph = Arc::make_mut(&mut self.glyph);
+        if side == SidebearingSide::Left {
+            shift_references(glyph, delta);
+        }
+        glyph.width += delta;
+        let width = glyph.width;
+
+        self.set_sort_advance(&name, width);
+        self.sync_to_workspace();
+        if self.spacing_all_masters {
+            let edit = SidebearingEdit {
+                glyph: name,
+                side,
+                delta,
+            };
+            self.replayed_sidebearing_edits.push(edit.clone());
+            self.sidebearing_edits.push(edit);
+        }
+        true
+    }
+
+    /// After undo or redo put this session in place of one whose
+    /// changes `replayed` the other masters have, queue what brings
+    /// them in line with this one: the changes only `replayed` has,
+    /// taken back last first, then the ones only this session has.
+    pub fn sync_replayed_sidebearings(&mut self, replayed: &[SidebearingEdit]) {
+        let kept = self
+            .replayed_sidebearing_edits
+            .iter()
+            .zip(replayed)
+            .take_while(|(a, b)| a == b)
+            .count();
+        let taken_back = replayed[kept..].iter().rev().map(|edit| SidebearingEdit {
+            delta: -edit.delta,
+            ..edit.clone()
+        });
+        let replayed_again = self.replayed_sidebearing_edits[kept..].iter().cloned();
+        self.sidebearing_edits = taken_back.chain(replayed_again).collect();
+    }
+
+    /// Set the advance width of every sort of a glyph in the text
+    /// buffer, after the glyph's width changed
+    pub fn set_sort_advance(&mut self, name: &str, width: f64) {
+        let Some(buffer) = &mut self.text_buffer else {
+            return;
+        };
+        for index in 0..buffer.len() {
+            if let Some(sort) = buffer.get_mut(index)
+                && let SortKind::Glyph {
+                    name: sort_name,
+                    advance_width,
+                    ..
+                } = &mut sort.kind
+                && sort_name == name
+            {
+                *advance_width = width;
+            }
+        }
+    }
+}
+
+/// Replay a sidebearing change on a glyph stored in a workspace
+pub fn adjust_glyph_sidebearing(glyph: &mut Glyph, edit: &SidebearingEdit) {
+    if edit.side == SidebearingSide::Left {
+        for contour in &mut glyph.contours {
+            for point in &mut contour.points {
+                point.x += edit.delta;
+            }
+        }
+        shift_references(glyph, edit.delta);
+    }
+    glyph.width += edit.delta;
+}
+
+/// Move a glyph's components and measurements horizontally
+fn shift_references(glyph: &mut Glyph, dx: f64) {
+    let shift = Affine::translate((dx, 0.0));
+    for component in &mut glyph.components {
+        component.transform = shift * component.transform;
+    }
+    for line in &mut glyph.measurements {
+        *line = shift * *line;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::editing::session::FontMetrics;
+    use crate::model::workspace::Contour;
+
+    #[test]
+    fn left_side_moves_outline_and_width() {
+        let mut glyph = Glyph {
+            contours: vec![Contour::for_test(&[(50.0, 0.0), (450.0, 0.0)])],
+            ..Glyph::for_test("n", 500.0)
+        };
+        let edit = |side, delta| SidebearingEdit {
+            glyph: "n".to_string(),
+            side,
+            delta,
+        };
+
+        adjust_glyph_sidebearing(&mut glyph, &edit(SidebearingSide::Left, 10.0));
+        assert_eq!(glyph.width, 510.0);
+        assert_eq!(glyph.contours[0].points[0].x, 60.0);
+
+        adjust_glyph_sidebearing(&mut glyph, &edit(SidebearingSide::Right, -20.0));
+        assert_eq!(glyph.width, 490.0);
+        assert_eq!(glyph.contours[0].points[1].x, 460.0);
+    }
+
+    #[test]
+    fn undo_takes_back_replayed_edits() {
+        let edit = |side, delta| SidebearingEdit {
+            glyph: "n".to_string(),
+            side,
+            delta,
+        };
+        let before = vec![
+            edit(SidebearingSide::Left, 10.0),
+            edit(SidebearingSide::Right, 5.0),
+            edit(SidebearingSide::Right, 5.0),
+        ];
+        let mut session = EditSession::new(
+            "n".to_string(),
+            std::path::PathBuf::new(),
+            Glyph::for_test("n", 500.0),
+            FontMetrics {
+                units_per_em: 1000.0,
+                ascender: 800.0,
+                descender: -200.0,
+                x_height: None,
+                cap_height: None,
+            },
+        );
+
+        // Undo back to the first edit
+        session.replayed_sidebearing_edits = before[..1].to_vec();
+        session.sync_replayed_sidebearings(&before);
+        assert_eq!(
+            session.sidebearing_edits,
+            [
+                edit(SidebearingSide::Right, -5.0),
+                edit(SidebearingSide::Right, -5.0)
+            ]
+        );
+
+        // Redo it all
+        let undone = session.replayed_sidebearing_edits.clone();
+        session.replayed_sidebearing_edits = before.clone();
+        session.sync_replayed_sidebearings(&undone);
+        assert_eq!(session.sidebearing_edits, before[1..]);
+    }
+}
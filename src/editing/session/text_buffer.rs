@@ -7,6 +7,7 @@ use super::{EditSession, WorkspaceGlyphProvider};
 use crate::model::read_workspace;
 use crate::path::Path;
 use crate::shaping::{ArabicShaper, TextDirection};
+use crate::sort::{Sort, SortBuffer, SortKind};
 use kurbo::Point;
 use std::sync::Arc;
 
@@ -352,4 +353,114 @@ impl EditSession {
             false
         }
     }
+
+    /// Replace the text buffer with lines of glyphs, by name, and make
+    /// the first glyph the active sort. Names not in the workspace are
+    /// left out. Returns false if the buffer is left as it was.
+    pub fn fill_buffer_with_glyphs(&mut self, lines: &[Vec<String>]) -> bool {
+        let Some(workspace_lock) = self.workspace.clone() else {
+            return false;
+        };
+        let mut buffer = SortBuffer::new();
+        {
+            let workspace = read_workspace(&workspace_lock);
+            for (i, line) in lines.iter().enumerate() {
+                if i > 0 {
+                    buffer.insert(Sort::new_line_break());
+                }
+                for name in line {
+                    if let Some(glyph) = workspace.get_glyph(name) {
+                        let codepoint = glyph.codepoints.first().copied();
+                        buffer.insert(Sort::new_glyph(name.clone(), codepoint, glyph.width, false));
+                    }
+                }
+            }
+        }
+
+        let previous = self.text_buffer.replace(buffer);
+        if self.activate_first_sort() {
+            true
+        } else {
+            self.text_buffer = previous;
+            false
+        }
+    }
+
+    /// Replace the text buffer with text, shaped as if it were typed,
+    /// and make the first glyph the active sort. Newlines start new
+    /// lines; characters without a glyph are left out. Returns false
+    /// if the buffer is left as it was.
+    pub fn fill_buffer_with_text(&mut self, text: &str) -> bool {
+        let previous = self.text_buffer.replace(SortBuffer::new());
+        for c in text.chars() {
+            let sort = if c == '\n' {
+                Some(Sort::new_line_break())
+            } else {
+                self.create_shaped_sort_from_char(c)
+            };
+            if let (Some(sort), Some(buffer)) = (sort, &mut self.text_buffer) {
+                let position = buffer.cursor();
+                buffer.insert(sort);
+                self.reshape_buffer_around(position);
+            }
+        }
+
+        if self.activate_first_sort() {
+            true
+        } else {
+            self.text_buffer = previous;
+            false
+        }
+    }
+
+    /// Make the first glyph sort of the buffer the active sort, loading
+    /// its outline from the workspace
+    fn activate_first_sort(&mut self) -> bool {
+        let Some(buffer) = &self.text_buffer else {
+            return false;
+        };
+        let Some((index, name, codepoint, advance_width)) =
+            buffer
+                .iter()
+                .enumerate()
+                .find_map(|(index, sort)| match &sort.kind {
+                    SortKind::Glyph {
+                        name,
+                        codepoint,
+                        advance_width,
+                    } => Some((index, name.clone(), *codepoint, *advance_width)),
+                    SortKind::LineBreak => None,
+                })
+        else {
+            return false;
+        };
+        // Right to left, lines start at the width of the whole buffer
+        // (as the canvas lays them out)
+        let x_offset = if self.text_direction.is_rtl() {
+            let total: f64 = buffer.iter().filter_map(|sort| sort.advance_width()).sum();
+            total - advance_width
+        } else {
+            0.0
+        };
+        let Some(glyph) = self
+            .workspace
+            .as_ref()
+            .and_then(|ws| read_workspace(ws).get_glyph(&name).cloned())
+        else {
+            return false;
+        };
+
+        self.paths = Arc::new(glyph.contours.iter().map(Path::from_contour).collect());
+        self.glyph = Arc::new(glyph);
+        self.selection = crate::editing::Selection::new();
+        self.spacing_handle = None;
+        self.active_sort_index = Some(index);
+        self.active_sort_name = Some(name);
+        self.active_sort_unicode = codepoint.map(|c| format!("U+{:04X}", c as u32));
+        self.active_sort_x_offset = x_offset;
+        if let Some(buffer) = &mut self.text_buffer {
+            buffer.set_active_sort(index);
+        }
+        true
+    }
 }
@@ -7,7 +7,7 @@ use winit::dpi::LogicalSize;
 use winit::error::EventLoopError;
 use xilem::core::fork;
 use xilem::core::one_of::Either;
-use xilem::view::{indexed_stack, sized_box};
+use xilem::view::{indexed_stack, label, sized_box};
 use xilem::{EventLoopBuilder, WidgetView, WindowView, Xilem, window};
 
 pub mod config;
@@ -24,8 +24,8 @@ mod theme;
 mod tools;
 mod views;
 
-use data::AppState;
-use views::{editor_tab, glyph_grid_tab, welcome};
+use data::{AppState, Tab};
+use views::{editor_tab, glyph_grid_tab, kerning_tab, welcome};
 
 /// Entry point for the Runebender Xilem application
 pub fn run(event_loop: EventLoopBuilder) -> Result<(), EventLoopError> {
@@ -178,9 +178,17 @@ fn tabbed_view(state: &mut AppState) -> impl WidgetView<AppState> + use<> {
     // NOTE: .width()/.height() each RESET the other axis to Auto, so
     // the combined .dims() setter is required here.
     let full = Dimensions::new(Dim::Ratio(1.0), Dim::Ratio(1.0));
+    // The kerning tab lays out and samples a page of pairs, so it's
+    // only built while it's showing
+    let kerning = if state.active_tab == Tab::Kerning {
+        Either::A(kerning_tab(state))
+    } else {
+        Either::B(label(""))
+    };
     let tabs = indexed_stack((
         sized_box(glyph_grid_tab(state)).dims(full),
         sized_box(editor_tab(state)).dims(full),
+        sized_box(kerning).dims(full),
     ));
     tabs.active(state.active_tab as usize)
 }
@@ -0,0 +1,311 @@
+// Copyright 2026 the Runebender Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Kerning suggestions from outline distance.
+//!
+//! Each pair is measured by the horizontal gap between the first
+//! glyph's right edge and the second glyph's left edge, band by band
+//! where both have ink. Gaps are capped at a depth, so open shapes
+//! (the arms of a "T", the counter of a "c") count as no deeper than
+//! that, and the capped gaps are averaged. The target is the average
+//! gap of reference pairs such as "nn" and "oo" as they are spaced now;
+//! every other pair gets the value that brings its average gap to the
+//! target, held back so its outlines never come closer than a minimum
+//! distance.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use super::glyph_profile::{GlyphProfile, PROFILE_BAND, band_gaps, horizontal_gap};
+use super::glyph_renderer::glyph_to_bezpath_with_components;
+use super::kerning::lookup_kerning;
+use super::kerning_table::GroupIndex;
+use super::workspace::Workspace;
+
+/// Suggested values are rounded to a multiple of this
+const ROUNDING: f64 = 5.0;
+
+/// Bisection steps when solving for a pair's value
+const SOLVE_STEPS: usize = 50;
+
+/// Settings of the suggestion engine
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AutoKernOptions {
+    /// Deepest a band's gap counts, in font units
+    pub depth: f64,
+    /// Smallest change from the current value worth suggesting
+    pub threshold: f64,
+    /// Closest the outlines of a kerned pair may come
+    pub min_distance: f64,
+}
+
+impl Default for AutoKernOptions {
+    fn default() -> Self {
+        Self {
+            depth: 150.0,
+            threshold: 5.0,
+            min_distance: 10.0,
+        }
+    }
+}
+
+/// A proposed value for a glyph pair
+#[derive(Debug, Clone, PartialEq)]
+pub struct KernSuggestion {
+    pub first: String,
+    pub second: String,
+    /// What the pair gets now, through groups if not directly
+    pub current: f64,
+    pub value: f64,
+    /// Whether the suggestion will be applied
+    pub accepted: bool,
+}
+
+/// Suggestions for a glyph list, with the target gap they aim for
+#[derive(Debug, Clone, PartialEq)]
+pub struct AutoKern {
+    pub target: f64,
+    pub suggestions: Vec<KernSuggestion>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AutoKernError {
+    /// None of the reference pairs could be measured
+    NoReference,
+    /// The target gap isn't smaller than the depth cap
+    TooShallow { target: f64 },
+}
+
+impl fmt::Display for AutoKernError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AutoKernError::NoReference => write!(f, "No reference pair could be measured"),
+            AutoKernError::TooShallow { target } => {
+                write!(f, "Depth must be larger than the target gap ({target:.0})")
+            }
+        }
+    }
+}
+
+/// Glyph names in a list separated by spaces or commas, leaving out
+/// names not in the workspace and repeats
+pub fn parse_glyph_list(text: &str, workspace: &Workspace) -> Vec<String> {
+    let mut seen = HashSet::new();
+    text.split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|name| workspace.glyphs.contains_key(*name) && seen.insert(*name))
+        .map(String::from)
+        .collect()
+}
+
+/// Glyph pairs in a list such as "nn oo n/o": two characters, or two
+/// glyph names joined by a slash
+pub fn parse_pairs(text: &str, workspace: &Workspace) -> Vec<(String, String)> {
+    let glyph_for = |c: char| {
+        workspace
+            .glyphs
+            .iter()
+            .find(|(_, glyph)| glyph.codepoints.contains(&c))
+            .map(|(name, _)| name.clone())
+    };
+    text.split_whitespace()
+        .filter_map(|token| {
+            let (first, second) = match token.split_once('/') {
+                Some((first, second)) => (first.to_string(), second.to_string()),
+                None => {
+                    let mut chars = token.chars();
+                    let pair = (glyph_for(chars.next()?)?, glyph_for(chars.next()?)?);
+                    if chars.next().is_some() {
+                        return None;
+                    }
+                    pair
+                }
+            };
+            (workspace.glyphs.contains_key(&first) && workspace.glyphs.contains_key(&second))
+                .then_some((first, second))
+        })
+        .collect()
+}
+
+/// Suggest values for every ordered pair of `glyphs`, calibrated on
+/// `references`. Only pairs whose value would change by at least the
+/// threshold are returned, all accepted.
+pub fn suggest_kerning(
+    workspace: &Workspace,
+    glyphs: &[String],
+    references: &[(String, String)],
+    options: AutoKernOptions,
+) -> Result<AutoKern, AutoKernError> {
+    let index = GroupIndex::new(&workspace.groups);
+    let mut profiles = Profiles::new(workspace);
+    let current = |first: &str, second: &str| {
+        lookup_kerning(
+            &workspace.kerning,
+            &workspace.groups,
+            first,
+            index.first_group(first),
+            second,
+            index.second_group(second),
+        )
+    };
+
+    let measured: Vec<f64> = references
+        .iter()
+        .filter_map(|(first, second)| {
+            let offset = workspace.get_glyph(first)?.width + current(first, second);
+            let (left, right) = profiles.pair(first, second)?;
+            mean_gap(left, right, offset, options.depth)
+        })
+        .collect();
+    if measured.is_empty() {
+        return Err(AutoKernError::NoReference);
+    }
+    let target = measured.iter().sum::<f64>() / measured.len() as f64;
+    if target >= options.depth {
+        return Err(AutoKernError::TooShallow { target });
+    }
+
+    let mut suggestions = Vec::new();
+    for first in glyphs {
+        let Some(width) = workspace.get_glyph(first).map(|g| g.width) else {
+            continue;
+        };
+        for second in glyphs {
+            let Some((left, right)) = profiles.pair(first, second) else {
+                continue;
+            };
+            let Some(value) = solve(left, right, width, target, options) else {
+                continue;
+            };
+            let current = current(first, second);
+            if (value - current).abs() >= options.threshold.max(f64::EPSILON) {
+                suggestions.push(KernSuggestion {
+                    first: first.clone(),
+                    second: second.clone(),
+                    current,
+                    value,
+                    accepted: true,
+                });
+            }
+        }
+    }
+    Ok(AutoKern {
+        target,
+        suggestions,
+    })
+}
+
+/// The value that brings the pair's capped average gap to `target`,
+/// clamped to keep `min_distance` between the outlines and rounded.
+/// `None` when the glyphs share no band.
+fn solve(
+    left: &GlyphProfile,
+    right: &GlyphProfile,
+    width: f64,
+    target: f64,
+    options: AutoKernOptions,
+) -> Option<f64> {
+    let gaps: Vec<f64> = band_gaps(left, right, width).collect();
+    if gaps.is_empty() {
+        return None;
+    }
+    let (min, max) = gaps
+        .iter()
+        .fold((f64::MAX, f64::MIN), |(lo, hi), &g| (lo.min(g), hi.max(g)));
+    let mean = |kern: f64| {
+        gaps.iter()
+            .map(|g| (g + kern).min(options.depth))
+            .sum::<f64>()
+            / gaps.len() as f64
+    };
+
+    // Every band is at most the target at `low` and at least the
+    // target at `high`, and the mean only grows with the value
+    let (mut low, mut high) = (target - max, target - min);
+    for _ in 0..SOLVE_STEPS {
+        let mid = (low + high) / 2.0;
+        if mean(mid) < target {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    let closest = horizontal_gap(left, right, width)?;
+    let value = high.max(options.min_distance - closest);
+    // Adding zero turns a rounded -0 into 0
+    Some((value / ROUNDING).round() * ROUNDING + 0.0)
+}
+
+/// Average of the band gaps, each capped at `depth`
+fn mean_gap(left: &GlyphProfile, right: &GlyphProfile, offset: f64, depth: f64) -> Option<f64> {
+    let (sum, count) = band_gaps(left, right, offset)
+        .fold((0.0, 0), |(sum, count), g| (sum + g.min(depth), count + 1));
+    (count > 0).then(|| sum / count as f64)
+}
+
+/// Glyph profiles, built as pairs need them
+struct Profiles<'a> {
+    workspace: &'a Workspace,
+    cache: HashMap<&'a str, GlyphProfile>,
+}
+
+impl<'a> Profiles<'a> {
+    fn new(workspace: &'a Workspace) -> Self {
+        Self {
+            workspace,
+            cache: HashMap::new(),
+        }
+    }
+
+    /// Both profiles of a pair, or `None` if either glyph is missing
+    /// or has no outline
+    fn pair(&mut self, first: &str, second: &str) -> Option<(&GlyphProfile, &GlyphProfile)> {
+        let workspace = self.workspace;
+        for name in [first, second] {
+            let (name, glyph) = workspace.glyphs.get_key_value(name)?;
+            if !self.cache.contains_key(name.as_str()) {
+                let path = glyph_to_bezpath_with_components(glyph, workspace);
+                self.cache
+                    .insert(name, GlyphProfile::new(&path, PROFILE_BAND));
+            }
+        }
+        let (left, right) = (&self.cache[first], &self.cache[second]);
+        (!left.is_empty() && !right.is_empty()).then_some((left, right))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kurbo::{Rect, Shape};
+
+    fn profile(x0: f64, y0: f64, x1: f64, y1: f64) -> GlyphProfile {
+        GlyphProfile::new(&Rect::new(x0, y0, x1, y1).to_path(0.1), 10.0)
+    }
+
+    #[test]
+    fn solves_for_target_gap() {
+        let options = AutoKernOptions::default();
+        // Stems 40 apart when set at the advance width of 100
+        let left = profile(0.0, 0.0, 80.0, 500.0);
+        let right = profile(20.0, 0.0, 100.0, 500.0);
+        assert_eq!(solve(&left, &right, 100.0, 40.0, options), Some(0.0));
+        assert_eq!(solve(&left, &right, 100.0, 60.0, options), Some(20.0));
+        // Never closer than the minimum distance
+        assert_eq!(solve(&left, &right, 100.0, 0.0, options), Some(-30.0));
+
+        // A short glyph beside a tall one is only measured where both
+        // have ink
+        let short = profile(20.0, 0.0, 100.0, 200.0);
+        assert_eq!(solve(&left, &short, 100.0, 40.0, options), Some(0.0));
+        let apart = profile(20.0, 600.0, 100.0, 700.0);
+        assert_eq!(solve(&left, &apart, 100.0, 40.0, options), None);
+    }
+
+    #[test]
+    fn deep_gaps_are_capped() {
+        let left = profile(0.0, 0.0, 80.0, 100.0);
+        let right = profile(20.0, 0.0, 100.0, 100.0);
+        assert_eq!(mean_gap(&left, &right, 100.0, 30.0), Some(30.0));
+        assert_eq!(mean_gap(&left, &right, 100.0, 150.0), Some(40.0));
+    }
+}
@@ -0,0 +1,335 @@
+// Copyright 2026 the Runebender Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Sidebearings from outline area, in the spirit of HT Letterspacer.
+//!
+//! Each side of a glyph is measured in a vertical zone: the height of
+//! its category's reference glyph ("x" for lowercase, "H" for
+//! capitals), extended by an overshoot. Within the zone, the white
+//! between the glyph's outermost ink and its outline is added up band
+//! by band, each band counting no deeper than a depth limit so open
+//! shapes don't read as hollow. The sidebearing is whatever brings the
+//! white on that side, per unit of zone height, to a target area
+//! scaled by the category's factor. Components are measured as
+//! composed, so "Aacute" is spaced like the "A" it shows.
+
+use std::fmt;
+
+use runebender_core::GlyphCategory;
+
+use super::glyph_profile::{GlyphProfile, PROFILE_BAND};
+use super::glyph_renderer::glyph_to_bezpath_with_components;
+use super::metrics_keys::MetricSide;
+use super::workspace::Workspace;
+
+/// x-height assumed when the font doesn't set one
+const DEFAULT_X_HEIGHT: f64 = 500.0;
+
+/// Spacing settings of one glyph category
+#[derive(Clone, PartialEq)]
+pub struct CategorySpacing {
+    pub category: GlyphCategory,
+    /// Glyph whose height sets the measured zone
+    pub reference: String,
+    /// Multiplies the target area: looser above 1, tighter below
+    pub factor: f64,
+}
+
+/// Settings of the spacing engine
+#[derive(Clone, PartialEq)]
+pub struct AutoSpaceOptions {
+    /// Target white per side, in thousands of square units at 1000 UPM
+    pub area: f64,
+    /// Deepest the white is measured, in percent of the x-height
+    pub depth: f64,
+    /// Zone extension above and below the reference glyph, in percent
+    /// of the x-height
+    pub overshoot: f64,
+    pub categories: Vec<CategorySpacing>,
+}
+
+impl Default for AutoSpaceOptions {
+    fn default() -> Self {
+        Self {
+            area: 400.0,
+            depth: 15.0,
+            overshoot: 0.0,
+            categories: default_categories(),
+        }
+    }
+}
+
+impl AutoSpaceOptions {
+    /// Reference glyph and factor for a category. Categories without
+    /// settings are measured like lowercase.
+    fn category(&self, category: GlyphCategory) -> (&str, f64) {
+        self.categories
+            .iter()
+            .find(|c| c.category == category)
+            .map_or(("x", 1.0), |c| (c.reference.as_str(), c.factor))
+    }
+}
+
+/// Settings for every category. Categories are found from a sample
+/// character, so this doesn't depend on how they're named.
+fn default_categories() -> Vec<CategorySpacing> {
+    let samples = [
+        ('x', "x", 1.0),
+        ('H', "H", 1.25),
+        ('0', "zero", 1.0),
+        ('.', "x", 1.4),
+    ];
+    GlyphCategory::all_categories()
+        .iter()
+        .copied()
+        .filter(|category| *category != GlyphCategory::All)
+        .map(|category| {
+            let (reference, factor) = samples
+                .iter()
+                .find(|(c, ..)| GlyphCategory::from_codepoint(*c) == category)
+                .map_or(("x", 1.0), |(_, reference, factor)| (*reference, *factor));
+            CategorySpacing {
+                category,
+                reference: reference.to_string(),
+                factor,
+            }
+        })
+        .collect()
+}
+
+/// Proposed sidebearings for a glyph
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpacingProposal {
+    pub glyph: String,
+    pub current_left: f64,
+    pub current_right: f64,
+    pub left: f64,
+    pub right: f64,
+    /// Whether the proposal will be applied
+    pub accepted: bool,
+}
+
+impl SpacingProposal {
+    pub fn changes(&self) -> bool {
+        self.left != self.current_left || self.right != self.current_right
+    }
+}
+
+/// Why a glyph got no proposal
+#[derive(Debug, Clone, PartialEq)]
+pub enum SkipReason {
+    /// Nothing to measure, even through components
+    NoOutline,
+    /// The reference glyph is missing or empty
+    NoReference(String),
+    /// No ink within the reference zone
+    OutsideZone,
+}
+
+impl fmt::Display for SkipReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SkipReason::NoOutline => write!(f, "no outline"),
+            SkipReason::NoReference(name) => write!(f, "no reference glyph \"{name}\""),
+            SkipReason::OutsideZone => write!(f, "no ink in the reference zone"),
+        }
+    }
+}
+
+/// Proposals for a glyph list, and the glyphs that couldn't be measured
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AutoSpace {
+    pub proposals: Vec<SpacingProposal>,
+    pub skipped: Vec<(String, SkipReason)>,
+}
+
+/// Category of a glyph: from its first codepoint, or else from the
+/// base of its name ("a.sc" goes with "a")
+pub fn glyph_category(workspace: &Workspace, name: &str) -> GlyphCategory {
+    let base = name.split('.').next().unwrap_or(name);
+    [name, base]
+        .iter()
+        .filter_map(|n| workspace.get_glyph(n)?.codepoints.first().copied())
+        .map(GlyphCategory::from_codepoint)
+        .next()
+        .unwrap_or(GlyphCategory::Other)
+}
+
+/// Propose sidebearings for `glyphs`, all accepted. Nothing in the
+/// workspace changes.
+pub fn propose_spacing(
+    workspace: &Workspace,
+    glyphs: &[String],
+    options: &AutoSpaceOptions,
+) -> AutoSpace {
+    let x_height = workspace
+        .x_height
+        .filter(|h| *h > 0.0)
+        .unwrap_or(DEFAULT_X_HEIGHT);
+    let scale = workspace.units_per_em.unwrap_or(1000.0) / 1000.0;
+    let max_depth = options.depth * x_height / 100.0;
+    let overshoot = options.overshoot * x_height / 100.0;
+
+    let mut result = AutoSpace::default();
+    for name in glyphs {
+        let Some(glyph) = workspace.get_glyph(name) else {
+            continue;
+        };
+        let (reference, factor) = options.category(glyph_category(workspace, name));
+        let Some((zone_min, zone_max)) = reference_zone(workspace, reference) else {
+            result
+                .skipped
+                .push((name.clone(), SkipReason::NoReference(reference.to_string())));
+            continue;
+        };
+        let path = glyph_to_bezpath_with_components(glyph, workspace);
+        let profile = GlyphProfile::new(&path, PROFILE_BAND);
+        let (Some(current_left), Some(current_right)) = (
+            workspace.glyph_metric(name, MetricSide::Left),
+            workspace.glyph_metric(name, MetricSide::Right),
+        ) else {
+            result.skipped.push((name.clone(), SkipReason::NoOutline));
+            continue;
+        };
+        let zone = (zone_min - overshoot, zone_max + overshoot);
+        let Some(sides) = measure_sides(&profile, zone, max_depth) else {
+            result.skipped.push((name.clone(), SkipReason::OutsideZone));
+            continue;
+        };
+
+        // The target white per unit of zone height, less what the
+        // outline already has, is the distance to add past the ink in
+        // the zone. Ink outside the zone (a descender's hook) may
+        // reach past that.
+        let target = options.area * 1000.0 * scale * scale * factor / x_height;
+        let height = zone.1 - zone.0;
+        let outline_min = current_left;
+        let outline_max = glyph.width - current_right;
+        let left = outline_min - sides.left_edge + target - sides.left_area / height;
+        let right = sides.right_edge - outline_max + target - sides.right_area / height;
+        result.proposals.push(SpacingProposal {
+            glyph: name.clone(),
+            current_left,
+            current_right,
+            left: left.round(),
+            right: right.round(),
+            accepted: true,
+        });
+    }
+    result
+}
+
+/// Vertical extent of a reference glyph, components included
+fn reference_zone(workspace: &Workspace, reference: &str) -> Option<(f64, f64)> {
+    use kurbo::Shape;
+
+    let glyph = workspace.get_glyph(reference)?;
+    let path = glyph_to_bezpath_with_components(glyph, workspace);
+    if path.elements().is_empty() {
+        return None;
+    }
+    let bounds = path.control_box();
+    Some((bounds.y0, bounds.y1))
+}
+
+/// White beside the outline within a zone
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Sides {
+    /// Outermost ink in the zone
+    left_edge: f64,
+    right_edge: f64,
+    /// White between those edges and the outline, each band capped
+    /// at the depth limit
+    left_area: f64,
+    right_area: f64,
+}
+
+fn measure_sides(profile: &GlyphProfile, zone: (f64, f64), max_depth: f64) -> Option<Sides> {
+    let bands: Vec<_> = profile
+        .bands()
+        .filter(|(y, ..)| *y >= zone.0 && *y <= zone.1)
+        .collect();
+    if bands.is_empty() {
+        return None;
+    }
+    let left_edge = bands.iter().map(|b| b.1).fold(f64::INFINITY, f64::min);
+    let right_edge = bands.iter().map(|b| b.2).fold(f64::NEG_INFINITY, f64::max);
+    let band = profile.band();
+    let white = |depth: f64| depth.min(max_depth) * band;
+    Some(Sides {
+        left_edge,
+        right_edge,
+        left_area: bands.iter().map(|b| white(b.1 - left_edge)).sum(),
+        right_area: bands.iter().map(|b| white(right_edge - b.2)).sum(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::workspace::{Contour, Glyph};
+
+    fn glyph(name: &str, points: &[(f64, f64)], width: f64) -> Glyph {
+        Glyph {
+            contours: vec![Contour::for_test(points)],
+            ..Glyph::for_test(name, width)
+        }
+    }
+
+    /// Settings that measure every glyph against "x"
+    fn options() -> AutoSpaceOptions {
+        AutoSpaceOptions {
+            categories: Vec::new(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn spaces_by_white_area() {
+        let rect = [(0.0, 0.0), (0.0, 500.0), (400.0, 500.0), (400.0, 0.0)];
+        // The lower half of the left side is set in by 100 units,
+        // deeper than the 75 unit depth limit
+        let notched = [
+            (50.0, 250.0),
+            (50.0, 500.0),
+            (450.0, 500.0),
+            (450.0, 0.0),
+            (150.0, 0.0),
+            (150.0, 250.0),
+        ];
+        let ws = Workspace::from_glyphs([glyph("x", &rect, 400.0), glyph("n", &notched, 600.0)]);
+        let names = vec!["n".to_string(), "x".to_string()];
+        let spaced = propose_spacing(&ws, &names, &options());
+
+        assert!(spaced.skipped.is_empty());
+        let n = &spaced.proposals[0];
+        assert_eq!((n.current_left, n.current_right), (50.0, 150.0));
+        // 400k units of white over a 500 unit x-height is 80 units a
+        // side; the notch already gives 75 × 250 / 500 = 37.5
+        assert_eq!((n.left, n.right), (43.0, 80.0));
+        let x = &spaced.proposals[1];
+        assert_eq!((x.left, x.right), (80.0, 80.0));
+    }
+
+    #[test]
+    fn skips_what_cannot_be_measured() {
+        let rect = [(0.0, 0.0), (0.0, 500.0), (400.0, 500.0), (400.0, 0.0)];
+        let high = [(0.0, 600.0), (0.0, 700.0), (100.0, 700.0), (100.0, 600.0)];
+        let ws = Workspace::from_glyphs([
+            glyph("x", &rect, 400.0),
+            glyph("quotesingle", &high, 200.0),
+            Glyph::for_test("space", 250.0),
+        ]);
+        let names = vec!["quotesingle".to_string(), "space".to_string()];
+        let spaced = propose_spacing(&ws, &names, &options());
+
+        assert!(spaced.proposals.is_empty());
+        assert_eq!(
+            spaced.skipped,
+            vec![
+                ("quotesingle".to_string(), SkipReason::OutsideZone),
+                ("space".to_string(), SkipReason::NoOutline),
+            ]
+        );
+    }
+}
@@ -356,6 +356,14 @@ impl DesignspaceProject {
         self.masters[self.active_master].modified = true;
     }
 
+    /// Mark every master as modified, after an edit applied to all of
+    /// them
+    pub fn mark_all_modified(&mut self) {
+        for master in &mut self.masters {
+            master.modified = true;
+        }
+    }
+
     /// Get the display name for this designspace
     pub fn display_name(&self) -> String {
         self.path
@@ -414,6 +422,33 @@ pub fn is_designspace_file(path: &Path) -> bool {
         .unwrap_or(false)
 }
 
+#[cfg(test)]
+impl DesignspaceProject {
+    /// Project with one unsaved master per named workspace, the first
+    /// one active
+    pub fn from_workspaces(masters: Vec<(&str, Workspace)>) -> Self {
+        let masters = masters
+            .into_iter()
+            .map(|(name, workspace)| Master {
+                name: name.to_string(),
+                style_name: name.to_string(),
+                location: HashMap::new(),
+                workspace: Arc::new(RwLock::new(workspace)),
+                ufo_path: PathBuf::from(format!("{name}.ufo")),
+                modified: false,
+            })
+            .collect();
+        Self {
+            path: PathBuf::from("test.designspace"),
+            axes: Vec::new(),
+            masters,
+            active_master: 0,
+            instances: Vec::new(),
+            designspace_doc: DesignSpaceDocument::default(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
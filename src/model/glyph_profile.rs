@@ -0,0 +1,131 @@
+// Copyright 2026 the Runebender Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Horizontal profile of a glyph outline: the leftmost and rightmost
+//! ink in each horizontal band. Two profiles set side by side give the
+//! horizontal gap between the glyphs' outlines.
+
+use std::collections::BTreeMap;
+
+use kurbo::{BezPath, PathEl, Point};
+
+/// Flattening tolerance in font units
+const FLATTEN_TOLERANCE: f64 = 0.5;
+
+/// Profile band height in font units, shared by autokerning, the
+/// kerning audit and autospacing
+pub const PROFILE_BAND: f64 = 5.0;
+
+/// Ink extent per band, keyed by band index (`floor(y / band)`)
+#[derive(Debug, Clone, Default)]
+pub struct GlyphProfile {
+    band: f64,
+    extents: BTreeMap<i64, (f64, f64)>,
+}
+
+impl GlyphProfile {
+    /// Profile an outline in bands `band` units high
+    pub fn new(path: &BezPath, band: f64) -> Self {
+        let mut profile = Self {
+            band,
+            extents: BTreeMap::new(),
+        };
+        let mut start = Point::ZERO;
+        let mut last = Point::ZERO;
+        kurbo::flatten(path, FLATTEN_TOLERANCE, |el| match el {
+            PathEl::MoveTo(p) => {
+                start = p;
+                last = p;
+            }
+            PathEl::LineTo(p) => {
+                profile.add_segment(last, p);
+                last = p;
+            }
+            PathEl::ClosePath => {
+                profile.add_segment(last, start);
+                last = start;
+            }
+            // `flatten` only emits lines
+            _ => {}
+        });
+        profile
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.extents.is_empty()
+    }
+
+    /// Band height in font units
+    pub fn band(&self) -> f64 {
+        self.band
+    }
+
+    /// The middle height, leftmost and rightmost ink of each band
+    /// with ink, bottom to top
+    pub fn bands(&self) -> impl Iterator<Item = (f64, f64, f64)> + '_ {
+        self.extents
+            .iter()
+            .map(|(&i, &(min, max))| ((i as f64 + 0.5) * self.band, min, max))
+    }
+
+    /// Add one flattened segment. Horizontal segments are skipped:
+    /// their ends are shared with the segments either side.
+    fn add_segment(&mut self, p0: Point, p1: Point) {
+        let (low, high) = if p0.y <= p1.y { (p0, p1) } else { (p1, p0) };
+        if high.y - low.y < f64::EPSILON {
+            return;
+        }
+        let x_at = |y: f64| low.x + (high.x - low.x) * (y - low.y) / (high.y - low.y);
+        let first = (low.y / self.band).floor() as i64;
+        let last = ((high.y / self.band).ceil() as i64 - 1).max(first);
+        for i in first..=last {
+            let a = x_at((i as f64 * self.band).max(low.y));
+            let b = x_at(((i + 1) as f64 * self.band).min(high.y));
+            let extent = self.extents.entry(i).or_insert((f64::MAX, f64::MIN));
+            extent.0 = extent.0.min(a.min(b));
+            extent.1 = extent.1.max(a.max(b));
+        }
+    }
+}
+
+/// Smallest horizontal gap between `left` and `right` when `right` is
+/// shifted by `offset`, over the bands where both have ink. Negative
+/// when the outlines overlap; `None` when they share no band.
+pub fn horizontal_gap(left: &GlyphProfile, right: &GlyphProfile, offset: f64) -> Option<f64> {
+    band_gaps(left, right, offset).min_by(f64::total_cmp)
+}
+
+/// The gap between `left` and `right` in each band where both have
+/// ink, bottom to top, with `right` shifted by `offset`
+pub fn band_gaps<'a>(
+    left: &'a GlyphProfile,
+    right: &'a GlyphProfile,
+    offset: f64,
+) -> impl Iterator<Item = f64> + 'a {
+    left.extents.iter().filter_map(move |(i, &(_, left_max))| {
+        let &(right_min, _) = right.extents.get(i)?;
+        Some(right_min + offset - left_max)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kurbo::{Rect, Shape};
+
+    #[test]
+    fn gap_between_boxes() {
+        let square = Rect::new(0.0, 0.0, 100.0, 100.0).to_path(0.1);
+        let low = Rect::new(0.0, -50.0, 100.0, 20.0).to_path(0.1);
+        let left = GlyphProfile::new(&square, 10.0);
+        let right = GlyphProfile::new(&low, 10.0);
+
+        assert_eq!(left.extents.len(), 10);
+        assert_eq!(horizontal_gap(&left, &right, 150.0), Some(50.0));
+        assert_eq!(horizontal_gap(&left, &right, 80.0), Some(-20.0));
+
+        let high = Rect::new(0.0, 200.0, 100.0, 300.0).to_path(0.1);
+        let apart = GlyphProfile::new(&high, 10.0);
+        assert_eq!(horizontal_gap(&left, &apart, 0.0), None);
+    }
+}
@@ -0,0 +1,280 @@
+// Copyright 2026 the Runebender Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Kerning audit: find kerning that is probably wrong.
+//!
+//! Every pair in the kerning is expanded through its groups into glyph
+//! pairs, each set with its effective value (exceptions included), and
+//! checked for outlines that overlap or come closer than a minimum
+//! distance, and for values larger than a share of the narrower
+//! glyph's width. Exceptions that repeat the value their groups would
+//! give anyway are reported as redundant.
+
+use std::collections::{HashMap, HashSet};
+
+use super::glyph_profile::{GlyphProfile, PROFILE_BAND, horizontal_gap};
+use super::glyph_renderer::glyph_to_bezpath_with_components;
+use super::kerning::lookup_kerning;
+use super::kerning_table::{GroupIndex, Kerning, PairKind, classify, representative};
+use super::workspace::Workspace;
+
+/// Audit thresholds
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AuditOptions {
+    /// Closest the outlines of a kerned pair may come, in font units
+    pub min_distance: f64,
+    /// Largest kerning value as a percentage of the narrower glyph's
+    /// advance width
+    pub max_percent: f64,
+}
+
+impl Default for AuditOptions {
+    fn default() -> Self {
+        Self {
+            min_distance: 10.0,
+            max_percent: 25.0,
+        }
+    }
+}
+
+/// What is wrong with a pair
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AuditProblem {
+    /// Outlines come within `distance` of each other (negative when
+    /// they overlap)
+    Collision { distance: f64 },
+    /// The value is `percent` of the narrower glyph's width
+    TooLarge { percent: f64 },
+    /// An exception with the same value as its group pair
+    RedundantException,
+}
+
+impl AuditProblem {
+    pub fn describe(&self) -> String {
+        match self {
+            AuditProblem::Collision { distance } if *distance < 0.0 => {
+                format!("outlines overlap by {:.0}", -distance)
+            }
+            AuditProblem::Collision { distance } => {
+                format!("outlines {distance:.0} apart")
+            }
+            AuditProblem::TooLarge { percent } => {
+                format!("value is {percent:.0}% of glyph width")
+            }
+            AuditProblem::RedundantException => "exception equals group value".to_string(),
+        }
+    }
+}
+
+/// One finding of the audit
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditIssue {
+    /// The kerning entry the value comes from (glyph or group names),
+    /// which is what needs fixing
+    pub first: String,
+    pub second: String,
+    /// Glyphs the problem was found with
+    pub first_glyph: String,
+    pub second_glyph: String,
+    pub value: f64,
+    pub problem: AuditProblem,
+}
+
+/// Audit a workspace's kerning. Issues are sorted by problem, then by
+/// pair.
+pub fn audit_kerning(workspace: &Workspace, options: AuditOptions) -> Vec<AuditIssue> {
+    let index = GroupIndex::new(&workspace.groups);
+    let mut issues = redundant_exceptions(workspace, &index);
+
+    let mut profiles: HashMap<&str, GlyphProfile> = HashMap::new();
+    for (first, second) in expanded_pairs(workspace) {
+        let (Some(left), Some(right)) = (workspace.get_glyph(first), workspace.get_glyph(second))
+        else {
+            continue;
+        };
+        let value = lookup_kerning(
+            &workspace.kerning,
+            &workspace.groups,
+            first,
+            index.first_group(first),
+            second,
+            index.second_group(second),
+        );
+        let (entry_first, entry_second) =
+            source_pair(&workspace.kerning, first, second, &index).unwrap_or((first, second));
+        let issue = |problem| AuditIssue {
+            first: entry_first.to_string(),
+            second: entry_second.to_string(),
+            first_glyph: first.to_string(),
+            second_glyph: second.to_string(),
+            value,
+            problem,
+        };
+
+        let narrower = left.width.min(right.width);
+        if narrower > 0.0 {
+            let percent = value.abs() / narrower * 100.0;
+            if percent > options.max_percent {
+                issues.push(issue(AuditProblem::TooLarge { percent }));
+            }
+        }
+
+        for name in [first, second] {
+            profiles.entry(name).or_insert_with(|| {
+                let glyph = workspace.get_glyph(name).expect("checked above");
+                let path = glyph_to_bezpath_with_components(glyph, workspace);
+                GlyphProfile::new(&path, PROFILE_BAND)
+            });
+        }
+        if let Some(distance) =
+            horizontal_gap(&profiles[first], &profiles[second], left.width + value)
+            && distance < options.min_distance
+        {
+            issues.push(issue(AuditProblem::Collision { distance }));
+        }
+    }
+
+    issues.sort_by(|a, b| {
+        rank(a.problem)
+            .cmp(&rank(b.problem))
+            .then_with(|| (&a.first, &a.second).cmp(&(&b.first, &b.second)))
+    });
+    issues
+}
+
+fn rank(problem: AuditProblem) -> u8 {
+    match problem {
+        AuditProblem::Collision { .. } => 0,
+        AuditProblem::TooLarge { .. } => 1,
+        AuditProblem::RedundantException => 2,
+    }
+}
+
+/// Every glyph pair covered by a kerning pair, once each
+fn expanded_pairs(workspace: &Workspace) -> Vec<(&str, &str)> {
+    fn members<'a>(workspace: &'a Workspace, side: &'a str) -> Vec<&'a str> {
+        match workspace.groups.get(side) {
+            Some(members) => members.iter().map(String::as_str).collect(),
+            None => vec![side],
+        }
+    }
+
+    let mut seen = HashSet::new();
+    let mut pairs = Vec::new();
+    for (first, seconds) in &workspace.kerning {
+        let firsts = members(workspace, first);
+        for second in seconds.keys() {
+            for &a in &firsts {
+                for b in members(workspace, second) {
+                    if seen.insert((a, b)) {
+                        pairs.push((a, b));
+                    }
+                }
+            }
+        }
+    }
+    pairs
+}
+
+/// Exceptions whose value equals what the pair would get without them
+fn redundant_exceptions(workspace: &Workspace, index: &GroupIndex) -> Vec<AuditIssue> {
+    let mut issues = Vec::new();
+    for (first, seconds) in &workspace.kerning {
+        for (second, &value) in seconds {
+            if classify(first, second, index) != PairKind::Exception {
+                continue;
+            }
+            if (group_value(&workspace.kerning, first, second, index) - value).abs() > 1e-9 {
+                continue;
+            }
+            let first_glyph = representative(first, "", &workspace.groups).unwrap_or(first);
+            let second_glyph = representative(second, "", &workspace.groups).unwrap_or(second);
+            issues.push(AuditIssue {
+                first: first.clone(),
+                second: second.clone(),
+                first_glyph: first_glyph.to_string(),
+                second_glyph: second_glyph.to_string(),
+                value,
+                problem: AuditProblem::RedundantException,
+            });
+        }
+    }
+    issues
+}
+
+/// The entries that could kern a pair, in glyph–glyph, glyph–group,
+/// group–glyph, group–group order
+fn candidate_pairs<'a>(
+    first: &'a str,
+    second: &'a str,
+    index: &'a GroupIndex,
+) -> impl Iterator<Item = (&'a str, &'a str)> {
+    let firsts = [Some(first), index.first_group(first)];
+    let seconds = [Some(second), index.second_group(second)];
+    firsts
+        .into_iter()
+        .flatten()
+        .flat_map(move |a| seconds.into_iter().flatten().map(move |b| (a, b)))
+}
+
+/// The kerning entry a glyph pair takes its value from
+fn source_pair<'a>(
+    kerning: &Kerning,
+    first: &'a str,
+    second: &'a str,
+    index: &'a GroupIndex,
+) -> Option<(&'a str, &'a str)> {
+    candidate_pairs(first, second, index).find(|(a, b)| {
+        kerning
+            .get(*a)
+            .is_some_and(|seconds| seconds.contains_key(*b))
+    })
+}
+
+/// The value a pair falls back to when its own entry is removed: the
+/// next pair in glyph–glyph, glyph–group, group–glyph, group–group
+/// order, or zero
+fn group_value(kerning: &Kerning, first: &str, second: &str, index: &GroupIndex) -> f64 {
+    candidate_pairs(first, second, index)
+        .filter(|&pair| pair != (first, second))
+        .find_map(|(a, b)| kerning.get(a)?.get(b).copied())
+        .unwrap_or(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::kerning_table::kerning_for_test;
+
+    fn index() -> (Kerning, GroupIndex) {
+        let (kerning, groups) = kerning_for_test(
+            &[
+                ("public.kern1.O", "public.kern2.O", -10.0),
+                ("D", "public.kern2.O", -10.0),
+                ("D", "O", -20.0),
+            ],
+            &[("public.kern1.O", &["O", "D"]), ("public.kern2.O", &["O"])],
+        );
+        (kerning, GroupIndex::new(&groups))
+    }
+
+    #[test]
+    fn exceptions_fall_back_through_groups() {
+        let (kerning, index) = index();
+        // D/O falls back to D/@O, which falls back to @O/@O
+        assert_eq!(group_value(&kerning, "D", "O", &index), -10.0);
+        assert_eq!(group_value(&kerning, "D", "public.kern2.O", &index), -10.0);
+        assert_eq!(group_value(&kerning, "O", "A", &index), 0.0);
+    }
+
+    #[test]
+    fn glyph_pairs_trace_back_to_their_entry() {
+        let (kerning, index) = index();
+        assert_eq!(
+            source_pair(&kerning, "O", "O", &index),
+            Some(("public.kern1.O", "public.kern2.O"))
+        );
+        assert_eq!(source_pair(&kerning, "D", "O", &index), Some(("D", "O")));
+        assert_eq!(source_pair(&kerning, "O", "A", &index), None);
+    }
+}
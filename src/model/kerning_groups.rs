@@ -0,0 +1,309 @@
+// Copyright 2026 the Runebender Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Kerning group editing for the group manager.
+//!
+//! Groups live in `Workspace::groups` under the `public.kern1.` (first
+//! side) and `public.kern2.` (second side) prefixes. Renaming, merging
+//! and deleting a group rewrite the kerning pairs that name it, so the
+//! kerning never points at a group that no longer exists.
+
+use std::collections::BTreeMap;
+
+use super::kerning_table::{FIRST_GROUP_PREFIX, Groups, Kerning, SECOND_GROUP_PREFIX};
+
+/// Which side of a kerning pair a group stands on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum GroupSide {
+    #[default]
+    First,
+    Second,
+}
+
+impl GroupSide {
+    pub fn prefix(self) -> &'static str {
+        match self {
+            GroupSide::First => FIRST_GROUP_PREFIX,
+            GroupSide::Second => SECOND_GROUP_PREFIX,
+        }
+    }
+
+    /// The side a group name belongs to, if it is a kerning group
+    pub fn of(group: &str) -> Option<Self> {
+        if group.starts_with(FIRST_GROUP_PREFIX) {
+            Some(GroupSide::First)
+        } else if group.starts_with(SECOND_GROUP_PREFIX) {
+            Some(GroupSide::Second)
+        } else {
+            None
+        }
+    }
+
+    /// Full group name for a short name typed by the user; names that
+    /// already carry this side's prefix are kept as they are
+    pub fn group_name(self, name: &str) -> String {
+        let name = name.trim();
+        if name.starts_with(self.prefix()) {
+            name.to_string()
+        } else {
+            format!("{}{name}", self.prefix())
+        }
+    }
+}
+
+/// Why a group edit was refused
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GroupError {
+    EmptyName,
+    Exists(String),
+    Missing(String),
+    /// The groups are on different sides of the pair
+    SideMismatch,
+}
+
+impl std::fmt::Display for GroupError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GroupError::EmptyName => write!(f, "group name is empty"),
+            GroupError::Exists(name) => write!(f, "group {name} already exists"),
+            GroupError::Missing(name) => write!(f, "group {name} does not exist"),
+            GroupError::SideMismatch => write!(f, "groups are on different sides"),
+        }
+    }
+}
+
+/// The kerning groups on one side with their members, by name
+pub fn side_groups(groups: &Groups, side: GroupSide) -> BTreeMap<&str, &[String]> {
+    groups
+        .iter()
+        .filter(|(name, _)| GroupSide::of(name) == Some(side))
+        .map(|(name, members)| (name.as_str(), members.as_slice()))
+        .collect()
+}
+
+/// Glyphs that belong to more than one group on the same side, with
+/// the groups they are in
+pub fn conflicts(groups: &Groups, side: GroupSide) -> BTreeMap<String, Vec<String>> {
+    let mut membership: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for (group, members) in side_groups(groups, side) {
+        for glyph in members {
+            membership
+                .entry(glyph.clone())
+                .or_default()
+                .push(group.to_string());
+        }
+    }
+    membership.retain(|_, groups| groups.len() > 1);
+    membership
+}
+
+/// Create an empty group
+pub fn create_group(
+    groups: &mut Groups,
+    side: GroupSide,
+    name: &str,
+) -> Result<String, GroupError> {
+    let name = checked_name(groups, side, name)?;
+    groups.insert(name.clone(), Vec::new());
+    Ok(name)
+}
+
+/// Rename a group, rewriting kerning pairs that name it
+pub fn rename_group(
+    kerning: &mut Kerning,
+    groups: &mut Groups,
+    old: &str,
+    new: &str,
+) -> Result<String, GroupError> {
+    let side = GroupSide::of(old).ok_or_else(|| GroupError::Missing(old.to_string()))?;
+    let new = checked_name(groups, side, new)?;
+    let members = groups
+        .remove(old)
+        .ok_or_else(|| GroupError::Missing(old.to_string()))?;
+    groups.insert(new.clone(), members);
+    move_pairs(kerning, side, old, &new);
+    Ok(new)
+}
+
+/// Merge `from` into `into`: members are joined and `from`'s pairs
+/// move over, except where `into` already has a pair with the same
+/// other side, whose value is kept
+pub fn merge_groups(
+    kerning: &mut Kerning,
+    groups: &mut Groups,
+    from: &str,
+    into: &str,
+) -> Result<(), GroupError> {
+    let side = GroupSide::of(from).ok_or_else(|| GroupError::Missing(from.to_string()))?;
+    if GroupSide::of(into) != Some(side) {
+        return Err(GroupError::SideMismatch);
+    }
+    if !groups.contains_key(into) {
+        return Err(GroupError::Missing(into.to_string()));
+    }
+    let members = groups
+        .remove(from)
+        .ok_or_else(|| GroupError::Missing(from.to_string()))?;
+    let target = groups.entry(into.to_string()).or_default();
+    for glyph in members {
+        if !target.contains(&glyph) {
+            target.push(glyph);
+        }
+    }
+    move_pairs(kerning, side, from, into);
+    Ok(())
+}
+
+/// Delete a group and every kerning pair that names it
+pub fn delete_group(kerning: &mut Kerning, groups: &mut Groups, name: &str) {
+    if groups.remove(name).is_none() {
+        return;
+    }
+    match GroupSide::of(name) {
+        Some(GroupSide::First) => {
+            kerning.remove(name);
+        }
+        Some(GroupSide::Second) => {
+            for seconds in kerning.values_mut() {
+                seconds.remove(name);
+            }
+            kerning.retain(|_, seconds| !seconds.is_empty());
+        }
+        None => {}
+    }
+}
+
+/// Move a glyph into `group`, taking it out of every other group on
+/// that side (which also resolves a membership conflict)
+pub fn move_glyph(groups: &mut Groups, glyph: &str, group: &str) -> Result<(), GroupError> {
+    let side = GroupSide::of(group).ok_or_else(|| GroupError::Missing(group.to_string()))?;
+    if !groups.contains_key(group) {
+        return Err(GroupError::Missing(group.to_string()));
+    }
+    remove_glyph(groups, glyph, side);
+    if let Some(members) = groups.get_mut(group) {
+        members.push(glyph.to_string());
+    }
+    Ok(())
+}
+
+/// Take a glyph out of every group on one side
+pub fn remove_glyph(groups: &mut Groups, glyph: &str, side: GroupSide) {
+    for (name, members) in groups.iter_mut() {
+        if GroupSide::of(name) == Some(side) {
+            members.retain(|g| g != glyph);
+        }
+    }
+}
+
+fn checked_name(groups: &Groups, side: GroupSide, name: &str) -> Result<String, GroupError> {
+    if name.trim().trim_start_matches(side.prefix()).is_empty() {
+        return Err(GroupError::EmptyName);
+    }
+    let name = side.group_name(name);
+    if groups.contains_key(&name) {
+        return Err(GroupError::Exists(name));
+    }
+    Ok(name)
+}
+
+/// Re-point the pairs naming group `old` at `new`. Pairs `new` already
+/// has keep their value.
+fn move_pairs(kerning: &mut Kerning, side: GroupSide, old: &str, new: &str) {
+    match side {
+        GroupSide::First => {
+            let Some(seconds) = kerning.remove(old) else {
+                return;
+            };
+            let target = kerning.entry(new.to_string()).or_default();
+            for (second, value) in seconds {
+                target.entry(second).or_insert(value);
+            }
+        }
+        GroupSide::Second => {
+            for seconds in kerning.values_mut() {
+                if let Some(value) = seconds.remove(old) {
+                    seconds.entry(new.to_string()).or_insert(value);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::kerning_table::kerning_for_test;
+
+    fn font() -> (Kerning, Groups) {
+        kerning_for_test(
+            &[
+                ("public.kern1.O", "A", -30.0),
+                ("public.kern1.C", "A", -10.0),
+                ("public.kern1.C", "V", -20.0),
+                ("T", "public.kern2.O", -60.0),
+            ],
+            &[
+                ("public.kern1.O", &["O", "D"]),
+                ("public.kern1.C", &["C", "O"]),
+                ("public.kern2.O", &["O", "Q"]),
+            ],
+        )
+    }
+
+    #[test]
+    fn rename_and_merge_rewrite_pairs() {
+        let (mut kerning, mut groups) = font();
+
+        let name = rename_group(&mut kerning, &mut groups, "public.kern2.O", "round").unwrap();
+        assert_eq!(name, "public.kern2.round");
+        assert_eq!(kerning["T"]["public.kern2.round"], -60.0);
+        assert!(!kerning["T"].contains_key("public.kern2.O"));
+
+        merge_groups(
+            &mut kerning,
+            &mut groups,
+            "public.kern1.C",
+            "public.kern1.O",
+        )
+        .unwrap();
+        assert!(!groups.contains_key("public.kern1.C"));
+        assert_eq!(groups["public.kern1.O"], ["O", "D", "C"]);
+        // The target's own pair wins, the source's other pairs move
+        assert_eq!(kerning["public.kern1.O"]["A"], -30.0);
+        assert_eq!(kerning["public.kern1.O"]["V"], -20.0);
+        assert!(!kerning.contains_key("public.kern1.C"));
+
+        assert_eq!(
+            merge_groups(
+                &mut kerning,
+                &mut groups,
+                "public.kern1.O",
+                "public.kern2.round"
+            ),
+            Err(GroupError::SideMismatch)
+        );
+        assert_eq!(
+            create_group(&mut groups, GroupSide::First, "O"),
+            Err(GroupError::Exists("public.kern1.O".to_string()))
+        );
+    }
+
+    #[test]
+    fn moving_a_glyph_resolves_conflicts() {
+        let (mut kerning, mut groups) = font();
+        assert_eq!(
+            conflicts(&groups, GroupSide::First)["O"],
+            ["public.kern1.C", "public.kern1.O"]
+        );
+        assert!(conflicts(&groups, GroupSide::Second).is_empty());
+
+        move_glyph(&mut groups, "O", "public.kern1.O").unwrap();
+        assert!(conflicts(&groups, GroupSide::First).is_empty());
+        assert_eq!(groups["public.kern1.C"], ["C"]);
+
+        delete_group(&mut kerning, &mut groups, "public.kern2.O");
+        assert!(!kerning.contains_key("T"));
+        assert_eq!(groups["public.kern1.O"], ["D", "O"]);
+    }
+}
@@ -0,0 +1,563 @@
+// Copyright 2026 the Runebender Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Kerning import and export as CSV and as an OpenType feature-file
+//! kern block.
+//!
+//! CSV rows are `kind,first,second,value`: `pair` rows carry a kerning
+//! pair, `group` rows a kerning group with its members space-separated
+//! in the third column. Spreadsheets with just `first,second,value`
+//! columns import too. Feature files carry groups as `@class`
+//! definitions and pairs as `pos` / `enum pos` statements.
+//!
+//! An import is previewed as a diff against the master it would be
+//! applied to before anything changes.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt::Write;
+
+use super::kerning_groups::GroupSide;
+use super::kerning_table::{Groups, Kerning};
+
+/// Kerning pairs and kerning groups read from a file
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct KerningData {
+    pub kerning: Kerning,
+    pub groups: Groups,
+    /// Statements or rows that could not be read and were left out
+    pub skipped: usize,
+}
+
+/// How an import combines with the master's kerning
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImportMode {
+    /// Imported pairs and groups are added, replacing ones with the
+    /// same name; everything else is kept
+    #[default]
+    Merge,
+    /// The master's kerning and kerning groups are replaced
+    Replace,
+}
+
+/// A file that could not be imported
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl std::fmt::Display for ImportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+/// One pair that an import adds, changes or removes
+#[derive(Debug, Clone, PartialEq)]
+pub struct PairChange {
+    pub first: String,
+    pub second: String,
+    pub old: Option<f64>,
+    pub new: Option<f64>,
+}
+
+/// One group that an import adds, changes or removes
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroupChange {
+    pub name: String,
+    pub old: Option<Vec<String>>,
+    pub new: Option<Vec<String>>,
+}
+
+/// What applying an import would change
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct KerningDiff {
+    pub pairs: Vec<PairChange>,
+    pub groups: Vec<GroupChange>,
+}
+
+impl KerningDiff {
+    pub fn is_empty(&self) -> bool {
+        self.pairs.is_empty() && self.groups.is_empty()
+    }
+}
+
+fn is_kerning_group(name: &str) -> bool {
+    GroupSide::of(name).is_some()
+}
+
+// ============================================================
+// CSV
+// ============================================================
+
+/// Write kerning groups and pairs as CSV
+pub fn to_csv(kerning: &Kerning, groups: &Groups) -> String {
+    let mut out = String::from("kind,first,second,value\n");
+    let groups: BTreeMap<_, _> = groups
+        .iter()
+        .filter(|(name, _)| is_kerning_group(name))
+        .collect();
+    for (name, members) in groups {
+        let _ = writeln!(
+            out,
+            "group,{},{},",
+            csv_field(name),
+            csv_field(&members.join(" "))
+        );
+    }
+    for (first, second, value) in sorted_pairs(kerning) {
+        let _ = writeln!(
+            out,
+            "pair,{},{},{value}",
+            csv_field(first),
+            csv_field(second)
+        );
+    }
+    out
+}
+
+/// Read kerning from CSV with `kind,first,second,value` or
+/// `first,second,value` columns; a header row is optional
+pub fn from_csv(text: &str) -> Result<KerningData, ImportError> {
+    let mut data = KerningData::default();
+    let mut with_kind = None;
+    for (index, line) in text.lines().enumerate() {
+        let line_number = index + 1;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let fields = csv_fields(line);
+        let fields: Vec<&str> = fields.iter().map(|f| f.trim()).collect();
+
+        let header = fields.iter().any(|f| f.eq_ignore_ascii_case("value"));
+        let kinded = *with_kind.get_or_insert_with(|| {
+            fields.len() >= 4
+                || fields
+                    .first()
+                    .is_some_and(|f| f.eq_ignore_ascii_case("kind"))
+        });
+        if header {
+            continue;
+        }
+
+        let error = |message: &str| ImportError {
+            line: line_number,
+            message: message.to_string(),
+        };
+        let (kind, rest) = if kinded {
+            (fields[0], &fields[1..])
+        } else {
+            ("pair", &fields[..])
+        };
+        match (kind, rest) {
+            ("group", [name, members, ..]) => {
+                if !is_kerning_group(name) {
+                    return Err(error(
+                        "group names need a public.kern1. or public.kern2. prefix",
+                    ));
+                }
+                let members = members.split_whitespace().map(String::from).collect();
+                data.groups.insert(name.to_string(), members);
+            }
+            ("pair", [first, second, value, ..]) => {
+                let value = value
+                    .parse::<f64>()
+                    .map_err(|_| error(&format!("{value:?} is not a number")))?;
+                data.kerning
+                    .entry(first.to_string())
+                    .or_default()
+                    .insert(second.to_string(), value);
+            }
+            ("group" | "pair", _) => return Err(error("missing columns")),
+            _ => data.skipped += 1,
+        }
+    }
+    Ok(data)
+}
+
+/// Quote a CSV field when it needs it
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+/// Split one CSV line, honouring quoted fields
+fn csv_fields(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+// ============================================================
+// Feature syntax
+// ============================================================
+
+/// Write kerning groups as `@class` definitions and pairs as a kern
+/// feature. Glyph pairs come first, then glyph–class and class–glyph
+/// pairs as `enum pos`, then class pairs, so that exceptions take
+/// precedence over the class pairs they override.
+pub fn to_feature(kerning: &Kerning, groups: &Groups) -> String {
+    let mut out = String::new();
+    let groups: BTreeMap<_, _> = groups
+        .iter()
+        .filter(|(name, _)| is_kerning_group(name))
+        .collect();
+    for (name, members) in &groups {
+        let _ = writeln!(out, "{} = [{}];", class_name(name), members.join(" "));
+    }
+    if !groups.is_empty() {
+        out.push('\n');
+    }
+
+    let mut rows: Vec<(u8, String)> = sorted_pairs(kerning)
+        .into_iter()
+        .map(|(first, second, value)| {
+            let (first_class, second_class) = (is_kerning_group(first), is_kerning_group(second));
+            let side = |name: &str, class: bool| {
+                if class {
+                    class_name(name)
+                } else {
+                    name.to_string()
+                }
+            };
+            let statement = format!(
+                "pos {} {} {value};",
+                side(first, first_class),
+                side(second, second_class)
+            );
+            match (first_class, second_class) {
+                (false, false) => (0, statement),
+                (true, true) => (2, statement),
+                _ => (1, format!("enum {statement}")),
+            }
+        })
+        .collect();
+    rows.sort_by_key(|(rank, _)| *rank);
+
+    out.push_str("feature kern {\n");
+    for (_, statement) in rows {
+        let _ = writeln!(out, "    {statement}");
+    }
+    out.push_str("} kern;\n");
+    out
+}
+
+/// Read `@class` definitions and `pos` statements from feature syntax.
+/// `@kern1.*` / `@kern2.*` classes become the matching kerning groups;
+/// other classes become groups on the side they are used on. Pairs
+/// with inline classes, and other statements, are left out.
+pub fn from_feature(text: &str) -> Result<KerningData, ImportError> {
+    let mut data = KerningData::default();
+    let mut classes: HashMap<String, Vec<String>> = HashMap::new();
+    // Classes used in pairs, by side, with the line of first use
+    let mut used: BTreeMap<(String, GroupSide), usize> = BTreeMap::new();
+
+    let mut line = 1;
+    for raw in strip_comments(text).split(';') {
+        // Errors point at the line the statement ends on
+        line += raw.matches('\n').count();
+        // Drop block openers and closers in front of the statement
+        let statement = raw.rsplit(['{', '}']).next().unwrap_or(raw).trim();
+        if statement.is_empty() {
+            continue;
+        }
+
+        if statement.starts_with('@')
+            && let Some((name, members)) = statement.split_once('=')
+        {
+            let members = members.trim().trim_start_matches('[').trim_end_matches(']');
+            classes.insert(
+                name.trim().to_string(),
+                members.split_whitespace().map(String::from).collect(),
+            );
+            continue;
+        }
+
+        let mut tokens: Vec<&str> = statement.split_whitespace().collect();
+        if matches!(tokens.first(), Some(&"enum" | &"enumerate")) {
+            tokens.remove(0);
+        }
+        if !matches!(tokens.first(), Some(&"pos" | &"position")) {
+            continue;
+        }
+        let [_, first, second, value @ ..] = tokens.as_slice() else {
+            data.skipped += 1;
+            continue;
+        };
+        if first.starts_with('[') || second.starts_with('[') {
+            data.skipped += 1;
+            continue;
+        }
+        let value = parse_value(&value.join(" ")).ok_or_else(|| ImportError {
+            line,
+            message: format!("can't read the value of {statement:?}"),
+        })?;
+
+        let mut resolve = |name: &str, side: GroupSide| {
+            if !name.starts_with('@') {
+                return name.to_string();
+            }
+            used.entry((name.to_string(), side)).or_insert(line);
+            group_name(name, side)
+        };
+        let first = resolve(first, GroupSide::First);
+        let second = resolve(second, GroupSide::Second);
+        data.kerning.entry(first).or_default().insert(second, value);
+    }
+
+    for ((class, side), line) in used {
+        let Some(members) = classes.get(&class) else {
+            return Err(ImportError {
+                line,
+                message: format!("class {class} is used but not defined"),
+            });
+        };
+        data.groups
+            .insert(group_name(&class, side), members.clone());
+    }
+    Ok(data)
+}
+
+/// Feature-syntax class name for a kerning group
+fn class_name(group: &str) -> String {
+    let name: String = group
+        .strip_prefix("public.")
+        .unwrap_or(group)
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("@{name}")
+}
+
+/// Kerning group for a feature-syntax class used on one side
+fn group_name(class: &str, side: GroupSide) -> String {
+    let name = class.trim_start_matches('@');
+    let prefixed = format!("public.{name}");
+    match GroupSide::of(&prefixed) {
+        Some(_) => prefixed,
+        None => side.group_name(name),
+    }
+}
+
+/// A `pos` value: a number, or the x advance of a `<x y xAdv yAdv>`
+/// value record
+fn parse_value(text: &str) -> Option<f64> {
+    let text = text.trim();
+    match text.strip_prefix('<').and_then(|t| t.strip_suffix('>')) {
+        Some(record) => {
+            let numbers: Vec<f64> = record
+                .split_whitespace()
+                .map(str::parse)
+                .collect::<Result<_, _>>()
+                .ok()?;
+            match numbers.as_slice() {
+                [_, _, advance, _] | [advance] => Some(*advance),
+                _ => None,
+            }
+        }
+        None => text.parse().ok(),
+    }
+}
+
+fn strip_comments(text: &str) -> String {
+    text.lines()
+        .map(|line| line.split('#').next().unwrap_or(""))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// ============================================================
+// Applying
+// ============================================================
+
+/// Apply an import to a master's kerning and groups
+pub fn apply_import(
+    kerning: &mut Kerning,
+    groups: &mut Groups,
+    data: &KerningData,
+    mode: ImportMode,
+) {
+    if mode == ImportMode::Replace {
+        kerning.clear();
+        groups.retain(|name, _| !is_kerning_group(name));
+    }
+    for (name, members) in &data.groups {
+        groups.insert(name.clone(), members.clone());
+    }
+    for (first, seconds) in &data.kerning {
+        let target = kerning.entry(first.clone()).or_default();
+        for (second, value) in seconds {
+            target.insert(second.clone(), *value);
+        }
+    }
+}
+
+/// What `apply_import` would change, without changing anything
+pub fn diff_import(
+    kerning: &Kerning,
+    groups: &Groups,
+    data: &KerningData,
+    mode: ImportMode,
+) -> KerningDiff {
+    let (mut new_kerning, mut new_groups) = (kerning.clone(), groups.clone());
+    apply_import(&mut new_kerning, &mut new_groups, data, mode);
+
+    let old_pairs: BTreeMap<(&str, &str), f64> = sorted_pairs(kerning)
+        .into_iter()
+        .map(|(a, b, v)| ((a, b), v))
+        .collect();
+    let new_pairs: BTreeMap<(&str, &str), f64> = sorted_pairs(&new_kerning)
+        .into_iter()
+        .map(|(a, b, v)| ((a, b), v))
+        .collect();
+    let keys: BTreeSet<_> = old_pairs.keys().chain(new_pairs.keys()).collect();
+    let pairs = keys
+        .into_iter()
+        .filter_map(|key| {
+            let (old, new) = (old_pairs.get(key).copied(), new_pairs.get(key).copied());
+            (old != new).then(|| PairChange {
+                first: key.0.to_string(),
+                second: key.1.to_string(),
+                old,
+                new,
+            })
+        })
+        .collect();
+
+    let names: BTreeSet<&String> = groups.keys().chain(new_groups.keys()).collect();
+    let groups = names
+        .into_iter()
+        .filter_map(|name| {
+            let (old, new) = (groups.get(name), new_groups.get(name));
+            (old != new).then(|| GroupChange {
+                name: name.clone(),
+                old: old.cloned(),
+                new: new.cloned(),
+            })
+        })
+        .collect();
+
+    KerningDiff { pairs, groups }
+}
+
+fn sorted_pairs(kerning: &Kerning) -> Vec<(&str, &str, f64)> {
+    let mut pairs: Vec<_> = kerning
+        .iter()
+        .flat_map(|(first, seconds)| {
+            seconds
+                .iter()
+                .map(move |(second, &value)| (first.as_str(), second.as_str(), value))
+        })
+        .collect();
+    pairs.sort_by(|a, b| (a.0, a.1).cmp(&(b.0, b.1)));
+    pairs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::kerning_table::kerning_for_test;
+
+    fn font() -> (Kerning, Groups) {
+        kerning_for_test(
+            &[
+                ("public.kern1.O", "public.kern2.O", -10.0),
+                ("public.kern1.O", "A", -30.0),
+                ("T", "a", -80.0),
+            ],
+            &[
+                ("public.kern1.O", &["O", "D"]),
+                ("public.kern2.O", &["O", "C"]),
+                ("accents", &["acute"]),
+            ],
+        )
+    }
+
+    fn kerning_groups(groups: &Groups) -> Groups {
+        groups
+            .iter()
+            .filter(|(name, _)| is_kerning_group(name))
+            .map(|(n, m)| (n.clone(), m.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn csv_and_feature_round_trip() {
+        let (kerning, groups) = font();
+
+        let csv = from_csv(&to_csv(&kerning, &groups)).unwrap();
+        assert_eq!(csv.kerning, kerning);
+        assert_eq!(csv.groups, kerning_groups(&groups));
+
+        let fea = to_feature(&kerning, &groups);
+        assert!(fea.contains("@kern1.O = [O D];"));
+        assert!(fea.contains("enum pos @kern1.O A -30;"));
+        let parsed = from_feature(&fea).unwrap();
+        assert_eq!(parsed.kerning, kerning);
+        assert_eq!(parsed.groups, kerning_groups(&groups));
+    }
+
+    #[test]
+    fn spreadsheets_and_foreign_features_import() {
+        let csv = from_csv("First,Second,Value\nT,o,-50\n\"a,b\",c,10\n").unwrap();
+        assert_eq!(csv.kerning["T"]["o"], -50.0);
+        assert_eq!(csv.kerning["a,b"]["c"], 10.0);
+        assert_eq!(from_csv("T,o,wide").unwrap_err().line, 1);
+
+        let fea = from_feature(
+            "@ROUND = [o e]; # rounds\n\
+             feature kern {\n  pos T @ROUND <0 0 -40 0>;\n  pos [V W] a -5;\n} kern;",
+        )
+        .unwrap();
+        assert_eq!(fea.kerning["T"]["public.kern2.ROUND"], -40.0);
+        assert_eq!(fea.groups["public.kern2.ROUND"], ["o", "e"]);
+        assert_eq!(fea.skipped, 1);
+    }
+
+    #[test]
+    fn diff_shows_merge_and_replace() {
+        let (kerning, groups) = font();
+        let data = from_csv("T,a,-60\nV,a,-20\n").unwrap();
+
+        let merge = diff_import(&kerning, &groups, &data, ImportMode::Merge);
+        assert_eq!(merge.pairs.len(), 2);
+        assert_eq!(
+            (merge.pairs[0].old, merge.pairs[0].new),
+            (Some(-80.0), Some(-60.0))
+        );
+        assert_eq!(
+            (merge.pairs[1].old, merge.pairs[1].new),
+            (None, Some(-20.0))
+        );
+        assert!(merge.groups.is_empty());
+
+        let replace = diff_import(&kerning, &groups, &data, ImportMode::Replace);
+        assert_eq!(replace.pairs.iter().filter(|c| c.new.is_none()).count(), 2);
+        // Only kerning groups are replaced
+        assert_eq!(replace.groups.len(), 2);
+        assert!(replace.groups.iter().all(|c| c.name != "accents"));
+    }
+}
@@ -0,0 +1,240 @@
+// Copyright 2026 the Runebender Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Kerning table rows for the kerning tab.
+//!
+//! Every pair in a workspace's kerning is listed with its kind:
+//! group–group, group–glyph, glyph–group, glyph–glyph, or an exception
+//! (a pair naming a glyph that belongs to a kerning group on that
+//! side, overriding the group's value). Rows can be filtered by glyph
+//! or group on either side; a glyph filter also matches the groups
+//! that glyph belongs to.
+
+use std::collections::HashMap;
+
+/// Kerning pairs: first side, then second side, to value
+pub type Kerning = HashMap<String, HashMap<String, f64>>;
+
+/// Kerning groups by name, with their member glyphs
+pub type Groups = HashMap<String, Vec<String>>;
+
+/// Prefix of first-side (left) kerning groups
+pub const FIRST_GROUP_PREFIX: &str = "public.kern1.";
+/// Prefix of second-side (right) kerning groups
+pub const SECOND_GROUP_PREFIX: &str = "public.kern2.";
+
+/// What each side of a kerning pair names
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PairKind {
+    GroupGroup,
+    GroupGlyph,
+    GlyphGroup,
+    GlyphGlyph,
+    /// A glyph side whose glyph is in a group, overriding the group
+    Exception,
+}
+
+impl PairKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            PairKind::GroupGroup => "group–group",
+            PairKind::GroupGlyph => "group–glyph",
+            PairKind::GlyphGroup => "glyph–group",
+            PairKind::GlyphGlyph => "glyph–glyph",
+            PairKind::Exception => "exception",
+        }
+    }
+}
+
+/// One pair of the kerning table
+#[derive(Debug, Clone, PartialEq)]
+pub struct KernRow {
+    pub first: String,
+    pub second: String,
+    pub value: f64,
+    pub kind: PairKind,
+}
+
+/// Which kerning group each glyph belongs to, per side
+#[derive(Debug, Clone, Default)]
+pub struct GroupIndex {
+    first: HashMap<String, String>,
+    second: HashMap<String, String>,
+}
+
+impl GroupIndex {
+    pub fn new(groups: &Groups) -> Self {
+        let mut index = Self::default();
+        for (group, members) in groups {
+            let side = if group.starts_with(FIRST_GROUP_PREFIX) {
+                &mut index.first
+            } else if group.starts_with(SECOND_GROUP_PREFIX) {
+                &mut index.second
+            } else {
+                continue;
+            };
+            for glyph in members {
+                side.insert(glyph.clone(), group.clone());
+            }
+        }
+        index
+    }
+
+    /// The first-side group of `glyph`
+    pub fn first_group(&self, glyph: &str) -> Option<&str> {
+        self.first.get(glyph).map(String::as_str)
+    }
+
+    /// The second-side group of `glyph`
+    pub fn second_group(&self, glyph: &str) -> Option<&str> {
+        self.second.get(glyph).map(String::as_str)
+    }
+}
+
+/// Classify a pair by what its sides name
+pub fn classify(first: &str, second: &str, index: &GroupIndex) -> PairKind {
+    let first_is_group = first.starts_with(FIRST_GROUP_PREFIX);
+    let second_is_group = second.starts_with(SECOND_GROUP_PREFIX);
+    let overrides_group = (!first_is_group && index.first_group(first).is_some())
+        || (!second_is_group && index.second_group(second).is_some());
+    match (first_is_group, second_is_group) {
+        (true, true) => PairKind::GroupGroup,
+        _ if overrides_group => PairKind::Exception,
+        (true, false) => PairKind::GroupGlyph,
+        (false, true) => PairKind::GlyphGroup,
+        (false, false) => PairKind::GlyphGlyph,
+    }
+}
+
+/// Whether one side of a pair matches a filter: an empty filter, part
+/// of the side's name, or a glyph that is a member of the side's group
+fn side_matches(side: &str, filter: &str, groups: &Groups) -> bool {
+    filter.is_empty()
+        || side.contains(filter)
+        || groups
+            .get(side)
+            .is_some_and(|members| members.iter().any(|g| g == filter))
+}
+
+/// Every kerning pair matching the side filters, sorted by first then
+/// second side
+pub fn kerning_rows(
+    kerning: &Kerning,
+    groups: &Groups,
+    first_filter: &str,
+    second_filter: &str,
+) -> Vec<KernRow> {
+    let index = GroupIndex::new(groups);
+    let mut rows: Vec<KernRow> = kerning
+        .iter()
+        .filter(|(first, _)| side_matches(first, first_filter, groups))
+        .flat_map(|(first, seconds)| {
+            seconds
+                .iter()
+                .filter(|(second, _)| side_matches(second, second_filter, groups))
+                .map(|(second, &value)| KernRow {
+                    first: first.clone(),
+                    second: second.clone(),
+                    value,
+                    kind: classify(first, second, &index),
+                })
+        })
+        .collect();
+    rows.sort_by(|a, b| (&a.first, &a.second).cmp(&(&b.first, &b.second)));
+    rows
+}
+
+/// The glyph a side stands for in a sample: the glyph itself, or for
+/// a group the filter glyph when it is a member, else the group's
+/// first member by name
+pub fn representative<'a>(side: &'a str, filter: &'a str, groups: &'a Groups) -> Option<&'a str> {
+    let Some(members) = groups.get(side) else {
+        return Some(side);
+    };
+    if members.iter().any(|g| g == filter) {
+        return Some(filter);
+    }
+    members.iter().map(String::as_str).min()
+}
+
+/// Kerning and groups from `(first, second, value)` pairs and
+/// `(group, members)` entries
+#[cfg(test)]
+pub(crate) fn kerning_for_test(
+    pairs: &[(&str, &str, f64)],
+    groups: &[(&str, &[&str])],
+) -> (Kerning, Groups) {
+    let mut kerning = Kerning::new();
+    for &(first, second, value) in pairs {
+        kerning
+            .entry(first.to_string())
+            .or_default()
+            .insert(second.to_string(), value);
+    }
+    let groups = groups
+        .iter()
+        .map(|&(group, members)| {
+            let members = members.iter().map(|m| m.to_string()).collect();
+            (group.to_string(), members)
+        })
+        .collect();
+    (kerning, groups)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn font() -> (Kerning, Groups) {
+        kerning_for_test(
+            &[
+                ("public.kern1.O", "public.kern2.O", -10.0),
+                ("public.kern1.O", "A", -30.0),
+                ("T", "public.kern2.O", -60.0),
+                ("T", "a", -80.0),
+                ("D", "A", -20.0),
+            ],
+            &[
+                ("public.kern1.O", &["O", "D", "Q"]),
+                ("public.kern2.O", &["O", "C"]),
+            ],
+        )
+    }
+
+    #[test]
+    fn rows_are_classified_and_sorted() {
+        let (kerning, groups) = font();
+        let rows = kerning_rows(&kerning, &groups, "", "");
+        let kinds: Vec<_> = rows
+            .iter()
+            .map(|r| (r.first.as_str(), r.second.as_str(), r.kind))
+            .collect();
+
+        assert_eq!(
+            kinds,
+            [
+                ("D", "A", PairKind::Exception),
+                ("T", "a", PairKind::GlyphGlyph),
+                ("T", "public.kern2.O", PairKind::GlyphGroup),
+                ("public.kern1.O", "A", PairKind::GroupGlyph),
+                ("public.kern1.O", "public.kern2.O", PairKind::GroupGroup),
+            ]
+        );
+    }
+
+    #[test]
+    fn glyph_filter_matches_its_groups() {
+        let (kerning, groups) = font();
+        let rows = kerning_rows(&kerning, &groups, "Q", "");
+        assert_eq!(rows.len(), 2);
+        assert!(rows.iter().all(|r| r.first == "public.kern1.O"));
+
+        let rows = kerning_rows(&kerning, &groups, "T", "C");
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].second, "public.kern2.O");
+
+        assert_eq!(representative("public.kern1.O", "Q", &groups), Some("Q"));
+        assert_eq!(representative("public.kern1.O", "", &groups), Some("D"));
+        assert_eq!(representative("T", "", &groups), Some("T"));
+    }
+}
@@ -0,0 +1,420 @@
+// Copyright 2026 the Runebender Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Metrics keys: sidebearings and widths linked to other glyphs.
+//!
+//! A glyph can carry a key for its left sidebearing, right sidebearing
+//! and advance width, stored in the glyph lib. A key names the glyph
+//! the value comes from, optionally mirrored and offset:
+//!
+//! - `=n`: the same side of "n" (the width, for the width key)
+//! - `=|o`: the opposite side of "o", for mirrored shapes
+//! - `=H+10`, `=H-10`: the same side of "H", plus or minus 10
+//! - `=40`: a plain value
+//!
+//! Sidebearings are measured from the extent of every point of the
+//! outline, components included, like the values shown in the editor.
+
+use std::collections::{HashSet, VecDeque};
+use std::fmt;
+
+use kurbo::{Affine, Shape};
+
+use super::glyph_renderer::glyph_to_bezpath_with_components;
+use super::workspace::{Glyph, Workspace};
+
+/// Passes of "update all" before giving up on keys that refer to
+/// each other in a loop
+const MAX_PASSES: usize = 8;
+
+/// Differences smaller than this aren't drift
+const TOLERANCE: f64 = 0.5;
+
+/// The metrics keys of a glyph, as typed
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MetricsKeys {
+    pub left: Option<String>,
+    pub right: Option<String>,
+    pub width: Option<String>,
+}
+
+impl MetricsKeys {
+    pub fn get(&self, side: MetricSide) -> Option<&str> {
+        match side {
+            MetricSide::Left => self.left.as_deref(),
+            MetricSide::Right => self.right.as_deref(),
+            MetricSide::Width => self.width.as_deref(),
+        }
+    }
+
+    /// Set a key; an empty one clears it
+    pub fn set(&mut self, side: MetricSide, key: &str) {
+        let key = key.trim();
+        let key = (!key.is_empty()).then(|| key.to_string());
+        match side {
+            MetricSide::Left => self.left = key,
+            MetricSide::Right => self.right = key,
+            MetricSide::Width => self.width = key,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.left.is_none() && self.right.is_none() && self.width.is_none()
+    }
+
+    /// Whether any key takes its value from `name`
+    fn refers_to(&self, name: &str) -> bool {
+        MetricSide::ALL.into_iter().any(|side| {
+            self.get(side)
+                .and_then(|key| MetricsKey::parse(key).ok())
+                .is_some_and(|key| key.glyph.as_deref() == Some(name))
+        })
+    }
+}
+
+/// Which metric a key sets
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MetricSide {
+    Left,
+    Right,
+    Width,
+}
+
+impl MetricSide {
+    pub const ALL: [MetricSide; 3] = [MetricSide::Left, MetricSide::Right, MetricSide::Width];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            MetricSide::Left => "Left",
+            MetricSide::Right => "Right",
+            MetricSide::Width => "Width",
+        }
+    }
+}
+
+/// A parsed metrics key
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetricsKey {
+    /// Glyph the value comes from; `None` for a plain value
+    pub glyph: Option<String>,
+    /// Take the opposite sidebearing of the glyph
+    pub mirrored: bool,
+    pub offset: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MetricsKeyError {
+    Empty,
+    BadOffset(String),
+}
+
+impl fmt::Display for MetricsKeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MetricsKeyError::Empty => write!(f, "Metrics key names no glyph"),
+            MetricsKeyError::BadOffset(offset) => write!(f, "'{offset}' isn't a number"),
+        }
+    }
+}
+
+impl MetricsKey {
+    pub fn parse(text: &str) -> Result<Self, MetricsKeyError> {
+        let text = text.trim();
+        let text = text.strip_prefix('=').unwrap_or(text).trim();
+        let (mirrored, text) = match text.strip_prefix('|') {
+            Some(rest) => (true, rest.trim()),
+            None => (false, text),
+        };
+        if text.is_empty() {
+            return Err(MetricsKeyError::Empty);
+        }
+        if let Ok(value) = text.parse::<f64>() {
+            return Ok(Self {
+                glyph: None,
+                mirrored: false,
+                offset: value,
+            });
+        }
+
+        // The offset follows the last sign, so names with a hyphen
+        // still work as long as no number follows it
+        let (name, offset) = match text.rfind(['+', '-']) {
+            Some(at) if at > 0 => {
+                let (name, offset) = text.split_at(at);
+                let number = offset[1..].trim();
+                match number.parse::<f64>() {
+                    Ok(value) if offset.starts_with('-') => (name.trim(), -value),
+                    Ok(value) => (name.trim(), value),
+                    Err(_) if offset.starts_with('+') || number.is_empty() => {
+                        return Err(MetricsKeyError::BadOffset(number.to_string()));
+                    }
+                    Err(_) => (text, 0.0),
+                }
+            }
+            _ => (text, 0.0),
+        };
+        Ok(Self {
+            glyph: Some(name.to_string()),
+            mirrored,
+            offset,
+        })
+    }
+}
+
+/// A metric that differs from what its key gives
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MetricDrift {
+    pub side: MetricSide,
+    pub current: f64,
+    pub expected: f64,
+}
+
+impl Workspace {
+    /// The current left sidebearing, right sidebearing or width of a
+    /// glyph; `None` for the sidebearings of a glyph with no outline
+    pub fn glyph_metric(&self, name: &str, side: MetricSide) -> Option<f64> {
+        let glyph = self.get_glyph(name)?;
+        match side {
+            MetricSide::Width => Some(glyph.width),
+            MetricSide::Left => Some(self.outline_extent(glyph)?.0),
+            MetricSide::Right => Some(glyph.width - self.outline_extent(glyph)?.1),
+        }
+    }
+
+    /// The value a glyph's key gives for `side`, if it has a key that
+    /// resolves
+    pub fn resolve_metrics_key(&self, name: &str, side: MetricSide) -> Option<f64> {
+        let key = self.get_glyph(name)?.metrics_keys.get(side)?;
+        let key = MetricsKey::parse(key).ok()?;
+        let Some(source) = &key.glyph else {
+            return Some(key.offset);
+        };
+        let source_side = match (side, key.mirrored) {
+            (MetricSide::Left, true) => MetricSide::Right,
+            (MetricSide::Right, true) => MetricSide::Left,
+            (side, _) => side,
+        };
+        Some(self.glyph_metric(source, source_side)? + key.offset)
+    }
+
+    /// The metrics of a glyph that differ from its keys
+    pub fn metrics_drift(&self, name: &str) -> Vec<MetricDrift> {
+        MetricSide::ALL
+            .into_iter()
+            .filter_map(|side| {
+                let expected = self.resolve_metrics_key(name, side)?;
+                let current = self.glyph_metric(name, side)?;
+                ((current - expected).abs() >= TOLERANCE).then_some(MetricDrift {
+                    side,
+                    current,
+                    expected,
+                })
+            })
+            .collect()
+    }
+
+    pub fn has_metrics_drift(&self, name: &str) -> bool {
+        self.get_glyph(name)
+            .is_some_and(|glyph| !glyph.metrics_keys.is_empty())
+            && !self.metrics_drift(name).is_empty()
+    }
+
+    /// Set a glyph's metrics from its keys: the left sidebearing moves
+    /// the outline, then the width key (or else the right key) sets
+    /// the advance width. Returns whether anything changed.
+    pub fn apply_metrics_keys(&mut self, name: &str) -> bool {
+        let resolve = |side| self.resolve_metrics_key(name, side);
+        let (left, right, width) = (
+            resolve(MetricSide::Left),
+            resolve(MetricSide::Right),
+            resolve(MetricSide::Width),
+        );
+        let right = if width.is_some() { None } else { right };
+
+        let mut changed = self.set_sidebearings(name, left, right);
+        if let Some(width) = width
+            && let Some(glyph) = self.get_glyph_mut(name)
+            && (glyph.width - width).abs() >= 1e-9
+        {
+            glyph.width = width;
+            changed = true;
+        }
+        changed
+    }
+
+    /// Set either sidebearing of a glyph, measured like
+    /// `glyph_metric`: the left one moves the outline and keeps the
+    /// right one, the right one changes the advance width. Returns
+    /// whether anything changed.
+    pub fn set_sidebearings(&mut self, name: &str, left: Option<f64>, right: Option<f64>) -> bool {
+        let Some(glyph) = self.get_glyph(name) else {
+            return false;
+        };
+        let Some((min_x, max_x)) = self.outline_extent(glyph) else {
+            return false;
+        };
+        let dx = left.map_or(0.0, |left| left - min_x);
+        let new_width = match right {
+            Some(right) => max_x + dx + right,
+            None => glyph.width + dx,
+        };
+
+        let changed = dx.abs() >= 1e-9 || (new_width - glyph.width).abs() >= 1e-9;
+        if changed && let Some(glyph) = self.get_glyph_mut(name) {
+            shift_glyph(glyph, dx);
+            glyph.width = new_width;
+        }
+        changed
+    }
+
+    /// Apply every glyph's keys, over a few passes so keys that refer
+    /// to keyed glyphs settle. Returns the glyphs that changed.
+    pub fn update_all_metrics(&mut self) -> Vec<String> {
+        let mut keyed: Vec<String> = self
+            .glyphs
+            .values()
+            .filter(|glyph| !glyph.metrics_keys.is_empty())
+            .map(|glyph| glyph.name.clone())
+            .collect();
+        keyed.sort();
+
+        let mut changed = HashSet::new();
+        for _ in 0..MAX_PASSES {
+            let mut settled = true;
+            for name in &keyed {
+                if self.apply_metrics_keys(name) {
+                    changed.insert(name.clone());
+                    settled = false;
+                }
+            }
+            if settled {
+                break;
+            }
+        }
+        let mut changed: Vec<String> = changed.into_iter().collect();
+        changed.sort();
+        changed
+    }
+
+    /// Glyphs with a key that takes its value from `name`
+    pub fn metrics_dependents(&self, name: &str) -> Vec<String> {
+        let mut dependents: Vec<String> = self
+            .glyphs
+            .values()
+            .filter(|glyph| glyph.name != name && glyph.metrics_keys.refers_to(name))
+            .map(|glyph| glyph.name.clone())
+            .collect();
+        dependents.sort();
+        dependents
+    }
+
+    /// Re-apply the keys of every glyph that depends on `name`,
+    /// directly or through other keyed glyphs, after its metrics
+    /// changed. Returns the glyphs that changed.
+    pub fn propagate_metrics(&mut self, name: &str) -> Vec<String> {
+        let mut changed = Vec::new();
+        let mut visited = HashSet::from([name.to_string()]);
+        let mut queue: VecDeque<String> = self.metrics_dependents(name).into();
+        while let Some(dependent) = queue.pop_front() {
+            if !visited.insert(dependent.clone()) {
+                continue;
+            }
+            if self.apply_metrics_keys(&dependent) {
+                queue.extend(self.metrics_dependents(&dependent));
+                changed.push(dependent);
+            }
+        }
+        changed
+    }
+
+    /// Min and max x over every point of a glyph's outline and
+    /// components
+    fn outline_extent(&self, glyph: &Glyph) -> Option<(f64, f64)> {
+        let path = glyph_to_bezpath_with_components(glyph, self);
+        if path.elements().is_empty() {
+            return None;
+        }
+        let bounds = path.control_box();
+        Some((bounds.x0, bounds.x1))
+    }
+}
+
+/// Move a glyph's outline, components and measurements horizontally
+fn shift_glyph(glyph: &mut Glyph, dx: f64) {
+    if dx.abs() < 1e-9 {
+        return;
+    }
+    for point in glyph.contours.iter_mut().flat_map(|c| c.points.iter_mut()) {
+        point.x += dx;
+    }
+    let shift = Affine::translate((dx, 0.0));
+    for component in &mut glyph.components {
+        component.transform = shift * component.transform;
+    }
+    for line in &mut glyph.measurements {
+        *line = shift * *line;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::workspace::Contour;
+
+    fn glyph(name: &str, x0: f64, x1: f64, width: f64) -> Glyph {
+        Glyph {
+            contours: vec![Contour::for_test(&[(x0, 0.0), (x1, 0.0)])],
+            ..Glyph::for_test(name, width)
+        }
+    }
+
+    #[test]
+    fn parses_keys() {
+        let key = |glyph: Option<&str>, mirrored, offset| MetricsKey {
+            glyph: glyph.map(String::from),
+            mirrored,
+            offset,
+        };
+        assert_eq!(MetricsKey::parse("=n"), Ok(key(Some("n"), false, 0.0)));
+        assert_eq!(MetricsKey::parse("=|o"), Ok(key(Some("o"), true, 0.0)));
+        assert_eq!(MetricsKey::parse("=H+10"), Ok(key(Some("H"), false, 10.0)));
+        assert_eq!(
+            MetricsKey::parse("= H - 5"),
+            Ok(key(Some("H"), false, -5.0))
+        );
+        assert_eq!(MetricsKey::parse("=40"), Ok(key(None, false, 40.0)));
+        assert_eq!(
+            MetricsKey::parse("=a-cy"),
+            Ok(key(Some("a-cy"), false, 0.0))
+        );
+        assert_eq!(MetricsKey::parse("="), Err(MetricsKeyError::Empty));
+    }
+
+    #[test]
+    fn applies_and_propagates_keys() {
+        // "o" has sidebearings 30 and 40
+        let mut d = glyph("d", 10.0, 400.0, 450.0);
+        d.metrics_keys.left = Some("=H".to_string());
+        d.metrics_keys.right = Some("=|o+5".to_string());
+        let mut b = glyph("b", 0.0, 100.0, 100.0);
+        b.metrics_keys.width = Some("=d".to_string());
+        let mut ws = Workspace::from_glyphs([
+            glyph("H", 60.0, 540.0, 600.0),
+            glyph("o", 30.0, 460.0, 500.0),
+            d,
+            b,
+        ]);
+
+        assert_eq!(ws.metrics_drift("d").len(), 2);
+        assert_eq!(ws.update_all_metrics(), ["b", "d"]);
+        assert_eq!(ws.glyph_metric("d", MetricSide::Left), Some(60.0));
+        assert_eq!(ws.glyph_metric("d", MetricSide::Right), Some(35.0));
+        assert_eq!(ws.glyphs["b"].width, 485.0);
+        assert!(!ws.has_metrics_drift("d"));
+
+        // Widening "H" carries through "d" to "b"
+        ws.get_glyph_mut("H").unwrap().contours[0].points[0].x = 70.0;
+        assert_eq!(ws.propagate_metrics("H"), ["d", "b"]);
+        assert_eq!(ws.glyphs["b"].width, 495.0);
+    }
+}
@@ -9,8 +9,17 @@
 //! (`workspace`, `designspace`, `glyph_renderer`) stay local until
 //! the xilem-side ecosystem catches up to kurbo 0.13.
 
+pub mod auto_kern;
+pub mod auto_space;
 pub mod designspace;
+pub mod glyph_profile;
 pub mod glyph_renderer;
+pub mod kerning_audit;
+pub mod kerning_groups;
+pub mod kerning_io;
+pub mod kerning_table;
+pub mod metrics_keys;
+pub mod test_strings;
 pub mod workspace;
 
 pub use runebender_core::model::{EntityId, entity_id, kerning};
@@ -0,0 +1,239 @@
+// Copyright 2026 the Runebender Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Test strings for the text buffer: spacing patterns for a glyph list,
+//! kerning pairs set in context, and sample text per script.
+//!
+//! Spacing and kerning strings are lines of glyph names, so unencoded
+//! glyphs take part too. Script samples are plain text, to be typed
+//! into the buffer so they get shaped like typed text.
+
+use runebender_core::GlyphCategory;
+
+use super::auto_space::glyph_category;
+use super::workspace::Workspace;
+
+/// Kerning pairs on each line
+const PAIRS_PER_LINE: usize = 4;
+
+/// What the text buffer can be filled with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TestText {
+    /// Spacing patterns for the glyphs selected in the grid
+    Spacing,
+    /// The kerning pairs of the master that pass the kerning table's
+    /// filters
+    Kerning,
+    Script(Script),
+}
+
+/// Scripts with sample text
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Script {
+    Latin,
+    Cyrillic,
+    Greek,
+    Arabic,
+}
+
+impl Script {
+    pub const ALL: [Script; 4] = [
+        Script::Latin,
+        Script::Cyrillic,
+        Script::Greek,
+        Script::Arabic,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Script::Latin => "Latin",
+            Script::Cyrillic => "Cyrillic",
+            Script::Greek => "Greek",
+            Script::Arabic => "Arabic",
+        }
+    }
+
+    /// Sample text, lines separated by newlines
+    pub fn sample(self) -> &'static str {
+        match self {
+            Script::Latin => {
+                "Hamburgefontsiv HAMBURGEFONTSIV\n\
+                 The quick brown fox jumps over the lazy dog\n\
+                 0123456789 (fig.) [ref] “quoted”, ‘set’; end!"
+            }
+            Script::Cyrillic => {
+                "Съешь же ещё этих мягких французских булок, да выпей чаю\n\
+                 СЪЕШЬ ЖЕ ЕЩЁ ЭТИХ МЯГКИХ ФРАНЦУЗСКИХ БУЛОК"
+            }
+            Script::Greek => {
+                "Ξεσκεπάζω την ψυχοφθόρα βδελυγμία\n\
+                 ΞΕΣΚΕΠΑΖΩ ΤΗΝ ΨΥΧΟΦΘΟΡΑ ΒΔΕΛΥΓΜΙΑ"
+            }
+            Script::Arabic => {
+                "نص حكيم له سر قاطع وذو شأن عظيم\n\
+                 مكتوب على ثوب أخضر ومغلف بجلد أزرق"
+            }
+        }
+    }
+
+    /// Whether the sample is set right to left
+    pub fn is_rtl(self) -> bool {
+        self == Script::Arabic
+    }
+}
+
+/// Spacing patterns, a line for each glyph: the glyph between pairs
+/// of control glyphs, "nnXnn ooXoo" for lowercase, "HHXHH OOXOO" for
+/// capitals, "00X00 11X11" for figures and all four lowercase and
+/// capital controls for anything else. Controls missing from the
+/// font are left out.
+pub fn spacing_lines(workspace: &Workspace, glyphs: &[String]) -> Vec<Vec<String>> {
+    let space = space_glyph(workspace);
+    glyphs
+        .iter()
+        .filter(|name| workspace.glyphs.contains_key(*name))
+        .map(|name| {
+            let controls = controls(workspace, name);
+            if controls.is_empty() {
+                return vec![name.clone()];
+            }
+            let mut line = Vec::new();
+            for (i, control) in controls.iter().enumerate() {
+                if i > 0 {
+                    line.extend(space.clone());
+                }
+                let control = control.to_string();
+                line.extend([control.clone(), control.clone(), name.clone()]);
+                line.extend([control.clone(), control]);
+            }
+            line
+        })
+        .collect()
+}
+
+/// Kerning pairs set between control glyphs, "HHAVHH", a few pairs to
+/// a line. Each side names a glyph or a group; a group is set as its
+/// first member. Pairs with a side that stands for no glyph are left
+/// out.
+pub fn kerning_lines(workspace: &Workspace, pairs: &[(String, String)]) -> Vec<Vec<String>> {
+    let space = space_glyph(workspace);
+    let glyphs: Vec<_> = pairs
+        .iter()
+        .filter_map(|(first, second)| Some((member(workspace, first)?, member(workspace, second)?)))
+        .collect();
+    glyphs
+        .chunks(PAIRS_PER_LINE)
+        .map(|chunk| {
+            let mut line = Vec::new();
+            for (i, (first, second)) in chunk.iter().enumerate() {
+                if i > 0 {
+                    line.extend(space.clone());
+                }
+                let before = controls(workspace, first).first().map(|c| c.to_string());
+                let after = controls(workspace, second).first().map(|c| c.to_string());
+                line.extend(before.clone().into_iter().chain(before));
+                line.extend([first.clone(), second.clone()]);
+                line.extend(after.clone().into_iter().chain(after));
+            }
+            line
+        })
+        .collect()
+}
+
+/// A glyph standing for a kerning side: the glyph itself, or the
+/// first member of a group found in the workspace
+fn member(workspace: &Workspace, side: &str) -> Option<String> {
+    if workspace.glyphs.contains_key(side) {
+        return Some(side.to_string());
+    }
+    workspace
+        .groups
+        .get(side)?
+        .iter()
+        .find(|name| workspace.glyphs.contains_key(*name))
+        .cloned()
+}
+
+/// Control glyphs for a glyph's category that are in the font
+fn controls(workspace: &Workspace, name: &str) -> Vec<&'static str> {
+    let category = glyph_category(workspace, name);
+    let candidates: &[&'static str] = if category == GlyphCategory::from_codepoint('x') {
+        &["n", "o"]
+    } else if category == GlyphCategory::from_codepoint('H') {
+        &["H", "O"]
+    } else if category == GlyphCategory::from_codepoint('0') {
+        &["zero", "one"]
+    } else {
+        &["n", "o", "H", "O"]
+    };
+    candidates
+        .iter()
+        .copied()
+        .filter(|control| workspace.glyphs.contains_key(*control))
+        .collect()
+}
+
+/// The glyph for the space character, if the font has one
+fn space_glyph(workspace: &Workspace) -> Option<String> {
+    workspace
+        .glyphs
+        .iter()
+        .find(|(_, glyph)| glyph.codepoints.contains(&' '))
+        .map(|(name, _)| name.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::workspace::Glyph;
+    use std::collections::HashMap;
+
+    fn workspace() -> Workspace {
+        let glyphs = [
+            ("space", ' '),
+            ("n", 'n'),
+            ("o", 'o'),
+            ("a", 'a'),
+            ("H", 'H'),
+            ("A", 'A'),
+            ("V", 'V'),
+        ];
+        let mut workspace = Workspace::from_glyphs(glyphs.map(|(name, c)| Glyph {
+            codepoints: vec![c],
+            ..Glyph::for_test(name, 500.0)
+        }));
+        workspace.kerning = HashMap::from([(
+            "public.kern1.A".to_string(),
+            HashMap::from([("V".to_string(), -60.0)]),
+        )]);
+        workspace.groups = HashMap::from([(
+            "public.kern1.A".to_string(),
+            vec!["Aacute".to_string(), "A".to_string()],
+        )]);
+        workspace
+    }
+
+    fn names(line: &[String]) -> String {
+        line.join(" ")
+    }
+
+    #[test]
+    fn spaces_glyphs_between_controls() {
+        let ws = workspace();
+        let lines = spacing_lines(&ws, &["a".to_string(), "A".to_string()]);
+        assert_eq!(lines.len(), 2);
+        assert_eq!(names(&lines[0]), "n n a n n space o o a o o");
+        // No "O" in the font
+        assert_eq!(names(&lines[1]), "H H A H H");
+    }
+
+    #[test]
+    fn sets_kerning_pairs_with_group_members() {
+        let pairs = [("public.kern1.A", "V"), ("V", "A"), ("public.kern1.X", "V")]
+            .map(|(first, second)| (first.to_string(), second.to_string()));
+        let lines = kerning_lines(&workspace(), &pairs);
+        assert_eq!(lines.len(), 1);
+        // No glyph for "public.kern1.X"
+        assert_eq!(names(&lines[0]), "H H A V H H space H H V A H H");
+    }
+}
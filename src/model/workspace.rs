@@ -10,13 +10,14 @@
 //! Glyphs are sorted by Unicode codepoint for stable grid display order.
 
 use anyhow::{Context, Result};
-use kurbo::Affine;
+use kurbo::{Affine, Line, Point};
 use norad::{Font, Glyph as NoradGlyph};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
 
 use super::entity_id::EntityId;
+use super::metrics_keys::MetricsKeys;
 
 // ============================================================================
 // DATA STRUCTURES
@@ -42,8 +43,23 @@ pub struct Glyph {
     /// Mark label (com.runebender.markLabel), the palette name the
     /// color means — written beside the color, read first.
     pub mark_label: Option<String>,
+    /// Pinned measurement lines (com.runebender.measurements), in
+    /// glyph coordinates
+    pub measurements: Vec<Line>,
+    /// Keys linking the sidebearings and width to other glyphs
+    pub metrics_keys: MetricsKeys,
 }
 
+/// Glyph lib key for pinned measurement lines, stored as
+/// "x0,y0,x1,y1" entries separated by ";"
+pub const MEASUREMENTS_KEY: &str = "com.runebender.measurements";
+
+/// Glyph lib keys for the metrics keys of the left sidebearing,
+/// right sidebearing and advance width
+pub const LEFT_METRICS_KEY: &str = "com.runebender.leftMetricsKey";
+pub const RIGHT_METRICS_KEY: &str = "com.runebender.rightMetricsKey";
+pub const WIDTH_METRICS_KEY: &str = "com.runebender.widthMetricsKey";
+
 /// A contour is a closed path
 #[derive(Debug, Clone)]
 pub struct Contour {
@@ -323,6 +339,24 @@ impl Workspace {
             .get(runebender_core::theme_oklch::MARK_LABEL_KEY)
             .and_then(|v| v.as_string())
             .map(|s| s.to_string());
+        let measurements = norad_glyph
+            .lib
+            .get(MEASUREMENTS_KEY)
+            .and_then(|v| v.as_string())
+            .map(parse_measurements)
+            .unwrap_or_default();
+        let lib_string = |key: &str| {
+            norad_glyph
+                .lib
+                .get(key)
+                .and_then(|v| v.as_string())
+                .map(|s| s.to_string())
+        };
+        let metrics_keys = MetricsKeys {
+            left: lib_string(LEFT_METRICS_KEY),
+            right: lib_string(RIGHT_METRICS_KEY),
+            width: lib_string(WIDTH_METRICS_KEY),
+        };
 
         Glyph {
             name,
@@ -335,6 +369,8 @@ impl Workspace {
             right_group,
             mark_color,
             mark_label,
+            measurements,
+            metrics_keys,
         }
     }
 
@@ -450,6 +486,19 @@ impl Workspace {
         self.glyphs.insert(glyph_name.to_string(), glyph);
     }
 
+    /// Kerning between two glyphs set side by side, looked up through
+    /// their kerning groups
+    pub fn pair_kerning(&self, first: &str, second: &str) -> f64 {
+        super::kerning::lookup_kerning(
+            &self.kerning,
+            &self.groups,
+            first,
+            self.get_glyph(first).and_then(|g| g.right_group.as_deref()),
+            second,
+            self.get_glyph(second).and_then(|g| g.left_group.as_deref()),
+        )
+    }
+
     /// Save the UFO back to disk
     pub fn save(&self) -> Result<()> {
         // Load the original font to preserve metadata we don't edit
@@ -551,6 +600,24 @@ impl Workspace {
                 mark_label.clone().into(),
             );
         }
+        if !glyph.measurements.is_empty() {
+            norad_glyph.lib.insert(
+                MEASUREMENTS_KEY.to_string(),
+                format_measurements(&glyph.measurements).into(),
+            );
+        }
+        let MetricsKeys { left, right, width } = &glyph.metrics_keys;
+        for (lib_key, metrics_key) in [
+            (LEFT_METRICS_KEY, left),
+            (RIGHT_METRICS_KEY, right),
+            (WIDTH_METRICS_KEY, width),
+        ] {
+            if let Some(metrics_key) = metrics_key {
+                norad_glyph
+                    .lib
+                    .insert(lib_key.to_string(), metrics_key.clone().into());
+            }
+        }
 
         norad_glyph
     }
@@ -615,6 +682,38 @@ impl Workspace {
     }
 }
 
+// ============================================================================
+// MEASUREMENTS
+// ============================================================================
+
+/// Parse pinned measurements from their glyph lib string
+///
+/// Malformed entries are skipped rather than failing the glyph load.
+fn parse_measurements(value: &str) -> Vec<Line> {
+    value
+        .split(';')
+        .filter_map(|entry| {
+            let coords: Vec<f64> = entry
+                .split(',')
+                .map(|n| n.trim().parse().ok())
+                .collect::<Option<_>>()?;
+            match coords[..] {
+                [x0, y0, x1, y1] => Some(Line::new(Point::new(x0, y0), Point::new(x1, y1))),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Format pinned measurements for the glyph lib
+fn format_measurements(lines: &[Line]) -> String {
+    lines
+        .iter()
+        .map(|l| format!("{},{},{},{}", l.p0.x, l.p0.y, l.p1.x, l.p1.y))
+        .collect::<Vec<_>>()
+        .join(";")
+}
+
 // ============================================================================
 // RWLOCK HELPERS
 // ============================================================================
@@ -640,3 +739,95 @@ pub fn write_workspace(ws: &Arc<RwLock<Workspace>>) -> RwLockWriteGuard<'_, Work
         poisoned.into_inner()
     })
 }
+
+// ============================================================================
+// TEST FIXTURES
+// ============================================================================
+
+#[cfg(test)]
+impl Glyph {
+    /// An empty glyph with the given advance width, to fill in with
+    /// struct update syntax
+    pub fn for_test(name: &str, width: f64) -> Self {
+        Self {
+            name: name.to_string(),
+            width,
+            height: None,
+            codepoints: Vec::new(),
+            contours: Vec::new(),
+            components: Vec::new(),
+            left_group: None,
+            right_group: None,
+            mark_color: None,
+            mark_label: None,
+            measurements: Vec::new(),
+            metrics_keys: MetricsKeys::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+impl Contour {
+    /// A contour of corner points joined by lines
+    pub fn for_test(points: &[(f64, f64)]) -> Self {
+        let points = points
+            .iter()
+            .map(|&(x, y)| ContourPoint {
+                x,
+                y,
+                point_type: PointType::Line,
+                smooth: false,
+            })
+            .collect();
+        Self { points }
+    }
+}
+
+#[cfg(test)]
+impl Workspace {
+    /// A workspace holding `glyphs`, without metrics, kerning or groups
+    pub fn from_glyphs(glyphs: impl IntoIterator<Item = Glyph>) -> Self {
+        Self {
+            path: PathBuf::new(),
+            family_name: String::new(),
+            style_name: String::new(),
+            glyphs: glyphs.into_iter().map(|g| (g.name.clone(), g)).collect(),
+            units_per_em: None,
+            ascender: None,
+            descender: None,
+            x_height: None,
+            cap_height: None,
+            kerning: HashMap::new(),
+            groups: HashMap::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measurements_round_trip_through_the_glyph_lib() {
+        let lines = vec![
+            Line::new(Point::new(0.0, 0.0), Point::new(120.5, 0.0)),
+            Line::new(Point::new(-10.0, 500.0), Point::new(30.0, -200.25)),
+        ];
+        let value = format_measurements(&lines);
+        assert_eq!(value, "0,0,120.5,0;-10,500,30,-200.25");
+        assert_eq!(parse_measurements(&value), lines);
+    }
+
+    #[test]
+    fn malformed_measurements_are_skipped() {
+        let lines = parse_measurements("1,2,3,4;1,2,3;a,b,c,d;;5, 6, 7, 8;1,2,3,4,5");
+        assert_eq!(
+            lines,
+            [
+                Line::new(Point::new(1.0, 2.0), Point::new(3.0, 4.0)),
+                Line::new(Point::new(5.0, 6.0), Point::new(7.0, 8.0)),
+            ]
+        );
+        assert!(parse_measurements("").is_empty());
+    }
+}
@@ -25,8 +25,9 @@ pub use quadrant::Quadrant;
 pub use quadratic::QuadraticPath;
 pub use segment::{Segment, SegmentInfo};
 
+use crate::model::EntityId;
 use crate::model::workspace;
-use kurbo::BezPath;
+use kurbo::{Affine, BezPath};
 
 /// A path in a glyph outline
 ///
@@ -90,6 +91,15 @@ impl Path {
         }
     }
 
+    /// Whether this path is closed
+    pub fn is_closed(&self) -> bool {
+        match self {
+            Path::Cubic(cubic) => cubic.closed,
+            Path::Quadratic(quadratic) => quadratic.closed,
+            Path::Hyper(hyper) => hyper.closed,
+        }
+    }
+
     /// Convert this path to a workspace contour (for saving)
     pub fn to_contour(&self) -> workspace::Contour {
         match self {
@@ -98,4 +108,67 @@ impl Path {
             Path::Hyper(hyper) => hyper.to_contour(),
         }
     }
+
+    /// Return a copy of this path with `affine` applied to every point
+    ///
+    /// The copy keeps the curve type but gets fresh entity IDs. Mirroring
+    /// transforms (negative determinant) reverse the point order so the
+    /// contour keeps its winding direction.
+    pub fn transformed(&self, affine: Affine) -> Path {
+        let mut points: Vec<PathPoint> = self
+            .points()
+            .iter()
+            .map(|pt| PathPoint {
+                id: EntityId::next(),
+                point: affine * pt.point,
+                typ: pt.typ,
+            })
+            .collect();
+        if affine.determinant() < 0.0 {
+            points.reverse();
+        }
+        let points = PathPoints::from_vec(points);
+
+        match self {
+            Path::Cubic(cubic) => Path::Cubic(CubicPath::new(points, cubic.closed)),
+            Path::Quadratic(quadratic) => {
+                Path::Quadratic(QuadraticPath::new(points, quadratic.closed))
+            }
+            Path::Hyper(hyper) => Path::Hyper(HyperPath::from_points(points, hyper.closed)),
+        }
+    }
+}
+
+/// Resolve a component into editable paths in the outer glyph's space
+///
+/// Contours of the base glyph are copied with the component transform
+/// applied; nested components are flattened with their combined
+/// transforms. Missing base glyphs are skipped with a warning.
+pub fn decompose_component(
+    component: &workspace::Component,
+    workspace: &workspace::Workspace,
+) -> Vec<Path> {
+    let mut paths = Vec::new();
+    append_component_paths(&mut paths, &component.base, component.transform, workspace);
+    paths
+}
+
+/// Recursively append the contours of `base` (and its components)
+fn append_component_paths(
+    paths: &mut Vec<Path>,
+    base: &str,
+    transform: Affine,
+    workspace: &workspace::Workspace,
+) {
+    let Some(base_glyph) = workspace.glyphs.get(base) else {
+        tracing::warn!("Component base glyph '{}' not found in workspace", base);
+        return;
+    };
+
+    for contour in &base_glyph.contours {
+        paths.push(Path::from_contour(contour).transformed(transform));
+    }
+    for nested in &base_glyph.components {
+        append_component_paths(paths, &nested.base, transform * nested.transform, workspace);
+    }
 }
@@ -45,6 +45,22 @@ const SNAP_TO_GRID_ENABLED: bool = true;
 /// Grid spacing for snapping (design units)
 const SNAP_TO_GRID_SPACING: f64 = 2.0;
 
+// ============================================================================
+// SMART GUIDE SETTINGS
+// ============================================================================
+/// Whether dragged points snap to smart alignment guides
+const SMART_GUIDES_ENABLED: bool = true;
+
+/// Distance (screen pixels) within which a dragged point snaps to a guide
+const SMART_GUIDE_THRESHOLD: f64 = 6.0;
+
+// ============================================================================
+// SMOOTH DETECTION SETTINGS
+// ============================================================================
+/// Largest angle (degrees) between the tangents either side of an
+/// on-curve point for it to be flagged smooth
+const SMOOTH_ANGLE_TOLERANCE: f64 = 2.0;
+
 // ============================================================================
 // NUDGE SETTINGS
 // ============================================================================
@@ -57,6 +73,19 @@ const NUDGE_SHIFT: f64 = 8.0;
 /// Ctrl/Cmd-arrow nudge amount in design units
 const NUDGE_CMD: f64 = 32.0;
 
+// ============================================================================
+// SPACING MODE SETTINGS
+// ============================================================================
+/// Distance (screen pixels) from a sort's edge within which it grabs
+/// the sidebearing
+const SPACING_HANDLE_DISTANCE: f64 = 6.0;
+
+/// Sidebearing nudge in design units (arrow key)
+const SPACING_NUDGE_BASE: f64 = 1.0;
+
+/// Shift-arrow sidebearing nudge in design units
+const SPACING_NUDGE_SHIFT: f64 = 10.0;
+
 // ============================================================================
 // IMG2BEZ TRACING SETTINGS
 // ============================================================================
@@ -129,6 +158,21 @@ pub mod snap {
     pub const SPACING: f64 = super::SNAP_TO_GRID_SPACING;
 }
 
+/// Smart alignment guide settings for point dragging
+pub mod smart_guides {
+    /// Whether smart guides are enabled
+    pub const ENABLED: bool = super::SMART_GUIDES_ENABLED;
+
+    /// Snap distance in screen pixels
+    pub const THRESHOLD: f64 = super::SMART_GUIDE_THRESHOLD;
+}
+
+/// Smooth flag detection settings
+pub mod smooth_detection {
+    /// Tangent angle tolerance in degrees
+    pub const ANGLE_TOLERANCE: f64 = super::SMOOTH_ANGLE_TOLERANCE;
+}
+
 /// Nudge amounts for arrow key point movement
 pub mod nudge {
     /// Base nudge (arrow key alone)
@@ -141,6 +185,18 @@ pub mod nudge {
     pub const CMD: f64 = super::NUDGE_CMD;
 }
 
+/// Spacing mode sidebearing handles
+pub mod spacing {
+    /// Grab distance in screen pixels
+    pub const HANDLE_DISTANCE: f64 = super::SPACING_HANDLE_DISTANCE;
+
+    /// Base nudge (arrow key alone)
+    pub const NUDGE: f64 = super::SPACING_NUDGE_BASE;
+
+    /// Shift-arrow nudge
+    pub const NUDGE_SHIFT: f64 = super::SPACING_NUDGE_SHIFT;
+}
+
 /// img2bez tracing settings (bitmap → bezier conversion)
 pub mod tracing {
     /// Curve-fitting accuracy (Fréchet tolerance in font units)
@@ -9,10 +9,12 @@
 //! - Sort data structures representing individual glyphs or line breaks
 //! - Gap buffer-based text buffer for efficient editing
 //! - Cursor management and text positioning
+//! - Paragraph layout, wrapping the buffer at spaces for proofing
 
 pub mod buffer;
 pub mod cursor;
 pub mod data;
+pub mod paragraph;
 
 pub use buffer::SortBuffer;
 pub use cursor::TextCursor;
@@ -0,0 +1,243 @@
+// Copyright 2026 the Runebender Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Paragraph layout of the text buffer: lines wrapped at spaces to a
+//! line width, for proofing text rather than editing it.
+//!
+//! Line breaks in the buffer always start a new line; within a line,
+//! words move to the next line when they would pass the line width.
+//! Spaces at the end of a wrapped line are dropped. A word wider than
+//! the line is set on a line of its own. Right-to-left lines are set
+//! in the same order and mirrored, so they end flush with the line
+//! width.
+
+use super::{SortBuffer, SortKind};
+
+/// Sizes of the waterfall, in points
+pub const WATERFALL_SIZES: [f64; 9] = [9.0, 10.0, 12.0, 14.0, 18.0, 24.0, 36.0, 48.0, 72.0];
+
+/// How the text buffer is set in paragraph mode
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParagraphSettings {
+    /// Set the buffer as paragraphs instead of editable sorts
+    pub enabled: bool,
+    /// Set the first line of the text once per waterfall size
+    /// instead of the whole text once
+    pub waterfall: bool,
+    /// Em size in points (screen pixels)
+    pub point_size: f64,
+    /// Line width in points
+    pub line_width: f64,
+    /// Distance between baselines, in ems
+    pub line_spacing: f64,
+}
+
+impl Default for ParagraphSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            waterfall: false,
+            point_size: 24.0,
+            line_width: 640.0,
+            line_spacing: 1.25,
+        }
+    }
+}
+
+/// A glyph set on a paragraph line, in font units from the start of
+/// the line
+#[derive(Debug, Clone, PartialEq)]
+pub struct SetGlyph {
+    /// Index of the sort in the buffer
+    pub index: usize,
+    pub name: String,
+    pub x: f64,
+}
+
+/// One line of a paragraph
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ParagraphLine {
+    pub glyphs: Vec<SetGlyph>,
+    /// From the start of the line to the end of its last glyph that
+    /// isn't a space
+    pub width: f64,
+}
+
+/// A glyph sort, ready to set
+struct Item<'a> {
+    index: usize,
+    name: &'a str,
+    advance: f64,
+    is_space: bool,
+}
+
+/// Set the buffer as lines no wider than `max_width` font units.
+/// `kern` gives the kerning between two glyphs by name.
+pub fn layout_paragraph(
+    buffer: &SortBuffer,
+    max_width: f64,
+    rtl: bool,
+    kern: impl Fn(&str, &str) -> f64,
+) -> Vec<ParagraphLine> {
+    let mut lines = Vec::new();
+    let mut hard_line = Vec::new();
+    for (index, sort) in buffer.iter().enumerate() {
+        match &sort.kind {
+            SortKind::Glyph {
+                name,
+                codepoint,
+                advance_width,
+            } => hard_line.push(Item {
+                index,
+                name,
+                advance: *advance_width,
+                is_space: codepoint.is_some_and(char::is_whitespace),
+            }),
+            SortKind::LineBreak => {
+                wrap_line(&hard_line, max_width, &kern, &mut lines);
+                hard_line.clear();
+            }
+        }
+    }
+    wrap_line(&hard_line, max_width, &kern, &mut lines);
+
+    if rtl {
+        for line in &mut lines {
+            mirror_line(line, buffer, max_width);
+        }
+    }
+    lines
+}
+
+/// Wrap one line of the buffer at spaces
+fn wrap_line(
+    items: &[Item<'_>],
+    max_width: f64,
+    kern: &impl Fn(&str, &str) -> f64,
+    lines: &mut Vec<ParagraphLine>,
+) {
+    let mut setter = LineSetter::default();
+    let mut rest = items;
+    while !rest.is_empty() {
+        // A run of spaces and the word after it
+        let spaces = rest.iter().take_while(|i| i.is_space).count();
+        let word = rest[spaces..].iter().take_while(|i| !i.is_space).count();
+        let (segment, tail) = rest.split_at(spaces + word);
+        rest = tail;
+
+        let saved = setter.clone();
+        setter.set(segment, kern);
+        if setter.line.width > max_width && !saved.line.glyphs.is_empty() {
+            // The word goes to a new line, without the spaces before it
+            lines.push(saved.line);
+            setter = LineSetter::default();
+            setter.set(&segment[spaces..], kern);
+        }
+    }
+    lines.push(setter.line);
+}
+
+/// A line being set: the pen position and the glyph before it, for
+/// kerning
+#[derive(Clone, Default)]
+struct LineSetter<'a> {
+    line: ParagraphLine,
+    pen: f64,
+    previous: Option<&'a str>,
+}
+
+impl<'a> LineSetter<'a> {
+    fn set(&mut self, items: &[Item<'a>], kern: &impl Fn(&str, &str) -> f64) {
+        for item in items {
+            if let Some(previous) = self.previous {
+                self.pen += kern(previous, item.name);
+            }
+            self.line.glyphs.push(SetGlyph {
+                index: item.index,
+                name: item.name.to_string(),
+                x: self.pen,
+            });
+            self.pen += item.advance;
+            if !item.is_space {
+                self.line.width = self.pen;
+            }
+            self.previous = Some(item.name);
+        }
+    }
+}
+
+/// Mirror a line so its first glyph is on the right and its end is
+/// flush with the line width
+fn mirror_line(line: &mut ParagraphLine, buffer: &SortBuffer, max_width: f64) {
+    for glyph in &mut line.glyphs {
+        let advance = buffer
+            .get(glyph.index)
+            .and_then(|sort| sort.advance_width())
+            .unwrap_or(0.0);
+        glyph.x = max_width - glyph.x - advance;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sort::Sort;
+
+    /// A buffer with a 100 unit glyph per character; spaces are 50
+    fn buffer(text: &str) -> SortBuffer {
+        let mut buffer = SortBuffer::new();
+        for c in text.chars() {
+            let sort = match c {
+                '\n' => Sort::new_line_break(),
+                ' ' => Sort::new_glyph("space".to_string(), Some(c), 50.0, false),
+                _ => Sort::new_glyph(c.to_string(), Some(c), 100.0, false),
+            };
+            buffer.insert(sort);
+        }
+        buffer
+    }
+
+    fn words(lines: &[ParagraphLine]) -> Vec<String> {
+        lines
+            .iter()
+            .map(|line| {
+                line.glyphs
+                    .iter()
+                    .map(|g| {
+                        if g.name == "space" {
+                            " "
+                        } else {
+                            g.name.as_str()
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn wraps_at_spaces() {
+        let buffer = buffer("ab cd ef\ng");
+        let lines = layout_paragraph(&buffer, 500.0, false, |_, _| 0.0);
+        assert_eq!(words(&lines), ["ab cd", "ef", "g"]);
+        assert_eq!(lines[0].width, 450.0);
+        assert_eq!(lines[1].glyphs[0].x, 0.0);
+
+        // A word wider than the line still gets set
+        let lines = layout_paragraph(&buffer, 150.0, false, |_, _| 0.0);
+        assert_eq!(words(&lines), ["ab", "cd", "ef", "g"]);
+    }
+
+    #[test]
+    fn kerns_and_mirrors() {
+        let buffer = buffer("ab");
+        let kern = |a: &str, b: &str| if (a, b) == ("a", "b") { -20.0 } else { 0.0 };
+        let lines = layout_paragraph(&buffer, 500.0, false, kern);
+        assert_eq!(lines[0].glyphs[1].x, 80.0);
+        assert_eq!(lines[0].width, 180.0);
+
+        let lines = layout_paragraph(&buffer, 500.0, true, kern);
+        assert_eq!(lines[0].glyphs[0].x, 400.0);
+        assert_eq!(lines[0].glyphs[1].x, 320.0);
+    }
+}
@@ -84,6 +84,7 @@ const GRID_CELL_SELECTED_OUTLINE: Color = Color::from_rgb8(0x66, 0xEE, 0x88);
 // Glyph rendering in grid
 const GRID_GLYPH_COLOR: Color = BASE_J;
 const GRID_CELL_TEXT: Color = BASE_H;
+const GRID_CELL_METRICS_DRIFT: Color = MARK_ORANGE;
 
 // ============================================================================
 // PATHS AND OUTLINES
@@ -110,6 +111,12 @@ const METRICS_GUIDE: Color = GRID_CELL_SELECTED_OUTLINE;
 const KERN_ACTIVE_GLYPH: Color = Color::from_rgb8(0x00, 0xFF, 0xCC);
 const KERN_PREVIOUS_GLYPH: Color = Color::from_rgb8(0xFF, 0xAA, 0x33);
 
+// ============================================================================
+// SPACING MODE HANDLES
+// ============================================================================
+const SPACING_EDGE: Color = BASE_K;
+const SPACING_HANDLE: Color = MARK_YELLOW;
+
 // ============================================================================
 // DESIGN GRID (unit grid overlay in editor, visible when zoomed in)
 // ============================================================================
@@ -156,6 +163,11 @@ const SELECTED_POINT_OUTER: Color = Color::from_rgb8(0xff, 0xaa, 0x33);
 // ============================================================================
 const SEGMENT_HOVER: Color = Color::from_rgb8(0xff, 0xaa, 0x33);
 
+// ============================================================================
+// SMART GUIDES (alignment feedback while dragging points)
+// ============================================================================
+const SMART_GUIDE: Color = Color::from_rgb8(0xff, 0x3d, 0x9a);
+
 // ============================================================================
 // SELECTION RECTANGLE (Marquee)
 // ============================================================================
@@ -331,6 +343,8 @@ pub mod grid {
     pub const CELL_SELECTED_OUTLINE: Color = super::GRID_CELL_SELECTED_OUTLINE;
     pub const CELL_TEXT: Color = super::GRID_CELL_TEXT;
     pub const GLYPH_COLOR: Color = super::GRID_GLYPH_COLOR;
+    /// Marks glyphs whose metrics differ from their metrics keys
+    pub const CELL_METRICS_DRIFT: Color = super::GRID_CELL_METRICS_DRIFT;
 }
 
 /// Colors for paths and outlines
@@ -373,6 +387,15 @@ pub mod kerning {
     pub const PREVIOUS_GLYPH: Color = super::KERN_PREVIOUS_GLYPH;
 }
 
+/// Colors for spacing mode sidebearing handles
+pub mod spacing {
+    use super::Color;
+    /// Edges of the sort under the cursor
+    pub const EDGE: Color = super::SPACING_EDGE;
+    /// The edge that drags or nudges
+    pub const HANDLE: Color = super::SPACING_HANDLE;
+}
+
 /// Colors for control point lines (handles)
 pub mod handle {
     use super::Color;
@@ -473,6 +496,15 @@ pub mod segment {
     pub const HOVER_WIDTH: f64 = 3.0;
 }
 
+/// Smart alignment guides shown while dragging points
+pub mod smart_guide {
+    use super::Color;
+    /// Guide line color
+    pub const LINE: Color = super::SMART_GUIDE;
+    /// Guide line width
+    pub const WIDTH: f64 = 1.0;
+}
+
 /// Tool preview styles (for consistent visual feedback across tools)
 pub mod tool_preview {
     use super::Color;
@@ -5,6 +5,18 @@
 //!
 //! Ported from Runebender Druid implementation.
 //!
+//! ## Modes
+//!
+//! A plain drag cuts every contour the knife crosses into open pieces,
+//! adding no new edges. Holding Alt cuts and separates: each crossed
+//! contour is split into closed shapes joined along the knife line.
+//!
+//! Cubic and quadratic contours are split segment by segment and keep
+//! their curve type. Hyperbezier contours are split by inserting corner
+//! points at the cuts, so the pieces stay hyperbeziers. Components the
+//! knife crosses are decomposed first; untouched components are left
+//! alone.
+//!
 //! ## Closing segments
 //!
 //! The segment iterator now emits a proper closing segment for closed
@@ -14,16 +26,23 @@
 
 use crate::editing::{Drag, EditSession, EditType, MouseDelegate, MouseEvent};
 use crate::model::EntityId;
-use crate::path::{CubicPath, Path, PathPoint, PathPoints, PointType, Segment, SegmentInfo};
+use crate::path::{
+    CubicPath, HyperPath, Path, PathPoint, PathPoints, PointType, QuadraticPath, Segment,
+    SegmentInfo,
+};
 use crate::tools::{Tool, ToolId};
 use kurbo::{Affine, CubicBez, Line, ParamCurve, ParamCurveArclen, Point};
 use masonry::imaging::Painter;
 use masonry::kurbo::Stroke;
+use std::collections::HashSet;
 use std::sync::Arc;
 
 /// Maximum recursion depth for slicing paths
 const MAX_RECURSE: usize = 16;
 
+/// Segment parameter tolerance for cuts that land on a point
+const CUT_EPSILON: f64 = 1e-6;
+
 /// The knife tool for cutting paths
 #[derive(Debug, Clone)]
 pub struct KnifeTool {
@@ -33,6 +52,10 @@ pub struct KnifeTool {
     shift_locked: bool,
     /// Cached intersection points (design space)
     intersections: Vec<Point>,
+    /// Whether to cut and separate into closed shapes (Alt held)
+    separate: bool,
+    /// Decomposed component outlines, captured when the gesture starts
+    component_paths: Vec<(EntityId, Vec<Path>)>,
 }
 
 /// The state of the knife gesture
@@ -47,6 +70,17 @@ enum GestureState {
     Finished,
 }
 
+/// A cut position along a path, used when cutting into open pieces
+#[derive(Clone, Copy, Debug)]
+struct Cut {
+    /// Index of the cut segment in iteration order
+    segment: usize,
+    /// Parametric position along the segment (0.0 to 1.0)
+    t: f64,
+    /// The cut point in design space
+    point: Point,
+}
+
 /// A hit point where the knife intersects a path
 #[derive(Clone, Copy, Debug)]
 struct Hit {
@@ -66,6 +100,8 @@ impl Default for KnifeTool {
             gesture: GestureState::Ready,
             shift_locked: false,
             intersections: Vec::new(),
+            separate: false,
+            component_paths: Vec::new(),
         }
     }
 }
@@ -76,6 +112,11 @@ impl KnifeTool {
         self.shift_locked = locked;
     }
 
+    /// Set cut-and-separate mode live from keyboard state (Alt).
+    pub fn set_separate(&mut self, separate: bool) {
+        self.separate = separate;
+    }
+
     /// Get the current line endpoints, applying shift-lock if active
     fn current_points(&self) -> Option<(Point, Point)> {
         if let GestureState::Begun { start, current } = self.gesture {
@@ -108,21 +149,10 @@ impl KnifeTool {
 
         self.intersections.clear();
 
-        for path in data.paths.iter() {
-            match path {
-                Path::Cubic(cubic_path) => {
-                    for seg_info in cubic_path.iter_segments() {
-                        let hits = intersect_line_segment(line, &seg_info.segment);
-                        for (_seg_t, line_t) in hits {
-                            let point = line.eval(line_t);
-                            self.intersections.push(point);
-                        }
-                    }
-                }
-                _ => {
-                    // Skip non-cubic paths
-                }
-            }
+        let component_paths = self.component_paths.iter().flat_map(|(_, paths)| paths);
+        for path in data.paths.iter().chain(component_paths) {
+            let hits = path_hits(path, line);
+            self.intersections.extend(hits.iter().map(|hit| hit.point));
         }
     }
 }
@@ -192,6 +222,8 @@ impl MouseDelegate for KnifeTool {
             current: pt,
         };
         self.shift_locked = event.mods.shift;
+        self.separate = event.mods.alt;
+        self.component_paths = data.decomposed_components();
     }
 
     fn left_drag_began(&mut self, event: MouseEvent, drag: Drag, data: &mut EditSession) {
@@ -221,18 +253,28 @@ impl MouseDelegate for KnifeTool {
         if let Some(line) = self.current_line()
             && !self.intersections.is_empty()
         {
-            let new_paths = slice_paths(&data.paths, line);
-            let paths_vec = Arc::make_mut(&mut data.paths);
-            paths_vec.clear();
-            paths_vec.extend(new_paths);
+            // Only components the knife actually crosses get decomposed
+            let mut sources: Vec<Path> = data.paths.to_vec();
+            let mut cut_components = HashSet::new();
+            for (id, paths) in &self.component_paths {
+                if paths.iter().any(|path| !path_hits(path, line).is_empty()) {
+                    cut_components.insert(*id);
+                    sources.extend(paths.iter().cloned());
+                }
+            }
+
+            data.paths = Arc::new(slice_paths(&sources, line, self.separate));
+            data.remove_consumed_components(&cut_components);
         }
 
+        self.component_paths.clear();
         self.gesture = GestureState::Finished;
     }
 
     fn cancel(&mut self, _data: &mut EditSession) {
         self.gesture = GestureState::Ready;
         self.intersections.clear();
+        self.component_paths.clear();
     }
 }
 
@@ -241,29 +283,268 @@ impl MouseDelegate for KnifeTool {
 /// Slice all paths with a knife line
 ///
 /// Checks for intersection with all paths, modifying old and adding
-/// new paths as necessary.
-fn slice_paths(paths: &[Path], line: Line) -> Vec<Path> {
+/// new paths as necessary. With `separate`, crossed contours become
+/// closed shapes; otherwise they are opened into pieces.
+fn slice_paths(paths: &[Path], line: Line, separate: bool) -> Vec<Path> {
     let mut out = Vec::new();
     for path in paths {
-        match path {
-            Path::Cubic(cubic_path) => {
-                slice_path(cubic_path, line, &mut out);
+        let path = sliceable(path);
+        if separate {
+            slice_path(path, line, &mut out);
+        } else {
+            cut_path(&path, line, &mut out);
+        }
+    }
+    out
+}
+
+/// Prepare a path for slicing
+///
+/// Hyperbezier cuts map each solved segment back to the span between two
+/// on-curve points. If the solver didn't produce one segment per span,
+/// fall back to cutting the expanded cubic outline.
+fn sliceable(path: &Path) -> Path {
+    if let Path::Hyper(hyper) = path {
+        let spans = if hyper.closed && hyper.len() >= 3 {
+            hyper.len()
+        } else {
+            hyper.len().saturating_sub(1)
+        };
+        if hyper.iter_segments().count() != spans {
+            tracing::debug!("Hyper path segments don't match its spans, cutting as cubic");
+            return Path::Cubic(hyper.to_cubic());
+        }
+    }
+    path.clone()
+}
+
+/// Collect the segments of a path of any type
+pub(crate) fn path_segments(path: &Path) -> Vec<SegmentInfo> {
+    match path {
+        Path::Cubic(cubic) => cubic.iter_segments().collect(),
+        Path::Quadratic(quadratic) => quadratic.iter_segments().collect(),
+        Path::Hyper(hyper) => hyper.iter_segments().collect(),
+    }
+}
+
+/// Find all intersections between the knife line and a path
+fn path_hits(path: &Path, line: Line) -> Vec<Hit> {
+    let mut hits = Vec::new();
+    for seg_info in path_segments(path) {
+        for (seg_t, line_t) in intersect_line_segment(line, &seg_info.segment) {
+            hits.push(Hit {
+                line_t,
+                segment_t: seg_t,
+                point: line.eval(line_t),
+                segment_info: seg_info,
+            });
+        }
+    }
+    hits
+}
+
+/// Build a path of the same type as `path` from split points
+fn rebuild_path(path: &Path, points: Vec<PathPoint>, closed: bool) -> Path {
+    let points = PathPoints::from_vec(points);
+    match path {
+        Path::Cubic(_) => Path::Cubic(CubicPath::new(points, closed)),
+        Path::Quadratic(_) => Path::Quadratic(QuadraticPath::new(points, closed)),
+        Path::Hyper(_) => Path::Hyper(HyperPath::from_points(points, closed)),
+    }
+}
+
+// ===== Cut Into Open Pieces =====
+
+/// Cut a single path into open pieces at every knife intersection
+///
+/// Resulting paths are pushed to the `acc` vec.
+fn cut_path(path: &Path, line: Line, acc: &mut Vec<Path>) {
+    let segments = path_segments(path);
+    let cuts = cut_positions(&segments, &path_hits(path, line), path.is_closed());
+    if cuts.is_empty() {
+        acc.push(path.clone());
+        return;
+    }
+
+    let pieces = match path {
+        Path::Hyper(hyper) => cut_hyper_points(hyper, segments.len(), &cuts),
+        _ => cut_segment_points(path, &segments, &cuts),
+    };
+    for piece in pieces {
+        if piece.len() >= 2 {
+            acc.push(rebuild_path(path, piece, false));
+        }
+    }
+}
+
+/// Sort hits into cut positions along the path
+///
+/// A cut through an on-curve point is reported by both adjacent segments,
+/// so it's moved to the start of the later one and deduplicated. Cuts at
+/// the ends of an open path don't split anything and are dropped.
+fn cut_positions(segments: &[SegmentInfo], hits: &[Hit], closed: bool) -> Vec<Cut> {
+    let count = segments.len();
+    let mut cuts = Vec::new();
+    for hit in hits {
+        let Some(mut segment) = segments
+            .iter()
+            .position(|s| s.start_index == hit.segment_info.start_index)
+        else {
+            continue;
+        };
+        let mut t = hit.segment_t;
+        if t > 1.0 - CUT_EPSILON {
+            segment += 1;
+            t = 0.0;
+            if segment == count {
+                if !closed {
+                    continue;
+                }
+                segment = 0;
             }
-            _ => {
-                // HyperPath and QuadraticPath not yet supported
-                out.push(path.clone());
+        } else if t < CUT_EPSILON {
+            t = 0.0;
+            if segment == 0 && !closed {
+                continue;
             }
         }
+        cuts.push(Cut {
+            segment,
+            t,
+            point: hit.point,
+        });
     }
-    out
+
+    cuts.sort_by(|a, b| a.segment.cmp(&b.segment).then(a.t.total_cmp(&b.t)));
+    cuts.dedup_by(|b, a| a.segment == b.segment && (b.t - a.t).abs() < CUT_EPSILON);
+    cuts
 }
 
-/// Slice a single cubic path with a line
+/// Walk order for cutting: closed paths go all the way around from the
+/// first cut, revisiting its segment at the end; open paths run start to end
+fn cut_walk(cuts: &[Cut], count: usize, closed: bool) -> (usize, f64, usize) {
+    if closed {
+        (cuts[0].segment, cuts[0].t, count + 1)
+    } else {
+        (0, 0.0, count)
+    }
+}
+
+/// The cuts within one segment between `from` and `to`
+///
+/// On the first visit the cut at `from` is where the walk starts, so it
+/// is excluded.
+fn cuts_in(
+    cuts: &[Cut],
+    segment: usize,
+    from: f64,
+    to: f64,
+    first_visit: bool,
+) -> impl Iterator<Item = &Cut> {
+    cuts.iter().filter(move |cut| {
+        let after_from = if first_visit {
+            cut.t > from
+        } else {
+            cut.t >= from
+        };
+        cut.segment == segment && after_from && cut.t < to
+    })
+}
+
+/// Split the points of a cubic or quadratic path at the cuts
+fn cut_segment_points(path: &Path, segments: &[SegmentInfo], cuts: &[Cut]) -> Vec<Vec<PathPoint>> {
+    let points: Vec<PathPoint> = path.points().iter().cloned().collect();
+    let count = segments.len();
+    let (first, first_t, steps) = cut_walk(cuts, count, path.is_closed());
+
+    let mut pieces = Vec::new();
+    let mut current = Vec::new();
+    for step in 0..steps {
+        let index = (first + step) % count;
+        let seg_info = &segments[index];
+        let from = if step == 0 { first_t } else { 0.0 };
+        let to = if step == count { first_t } else { 1.0 };
+
+        let mut t = from;
+        for cut in cuts_in(cuts, index, from, to, step == 0) {
+            append_subsegment_points(&mut current, &points, seg_info, t, cut.t);
+            pieces.push(std::mem::take(&mut current));
+            t = cut.t;
+        }
+        append_subsegment_points(&mut current, &points, seg_info, t, to);
+    }
+    pieces.push(current);
+    pieces
+}
+
+/// Split the on-curve points of a hyperbezier path at the cuts
+///
+/// Solved segment `i` spans on-curve points `i` and `i + 1`, so each cut
+/// becomes a new corner point between them.
+fn cut_hyper_points(hyper: &HyperPath, spans: usize, cuts: &[Cut]) -> Vec<Vec<PathPoint>> {
+    let points: Vec<PathPoint> = hyper.points.iter().cloned().collect();
+    let (first, first_t, steps) = cut_walk(cuts, spans, hyper.closed);
+
+    let mut pieces = Vec::new();
+    let mut current = Vec::new();
+    if hyper.closed {
+        push_corner(&mut current, cuts[0].point);
+    } else {
+        current.push(copied_point(&points[0]));
+    }
+
+    for step in 0..steps {
+        let span = (first + step) % spans;
+        let from = if step == 0 { first_t } else { 0.0 };
+        let to = if step == spans { first_t } else { 1.0 };
+
+        for cut in cuts_in(cuts, span, from, to, step == 0) {
+            push_corner(&mut current, cut.point);
+            pieces.push(std::mem::take(&mut current));
+            push_corner(&mut current, cut.point);
+        }
+        if step < spans {
+            current.push(copied_point(&points[(span + 1) % points.len()]));
+        }
+    }
+    if hyper.closed {
+        push_corner(&mut current, cuts[0].point);
+    }
+    pieces.push(current);
+    pieces
+}
+
+/// Push a corner point, or turn the last point into a corner if it's
+/// already at that position
+fn push_corner(dest: &mut Vec<PathPoint>, point: Point) {
+    let typ = PointType::OnCurve { smooth: false };
+    match dest.last_mut() {
+        Some(last) if last.point.distance(point) < CUT_EPSILON => last.typ = typ,
+        _ => dest.push(PathPoint {
+            id: EntityId::next(),
+            point,
+            typ,
+        }),
+    }
+}
+
+/// Copy a point with a fresh entity ID
+fn copied_point(point: &PathPoint) -> PathPoint {
+    PathPoint {
+        id: EntityId::next(),
+        point: point.point,
+        typ: point.typ,
+    }
+}
+
+// ===== Cut And Separate =====
+
+/// Slice a single path into closed shapes
 ///
 /// Resulting paths are pushed to the `acc` vec.
-fn slice_path(path: &CubicPath, line: Line, acc: &mut Vec<Path>) {
+fn slice_path(path: Path, line: Line, acc: &mut Vec<Path>) {
     let mut hits = Vec::new();
-    slice_path_impl(path.clone(), line, acc, &mut hits, 0);
+    slice_path_impl(path, line, acc, &mut hits, 0);
 }
 
 /// Recursive implementation of path slicing
@@ -274,7 +555,7 @@ fn slice_path(path: &CubicPath, line: Line, acc: &mut Vec<Path>) {
 /// - Split the path at those two points
 /// - Recursively slice each new path with the remaining line
 fn slice_path_impl(
-    path: CubicPath,
+    path: Path,
     line: Line,
     acc: &mut Vec<Path>,
     hit_buf: &mut Vec<Hit>,
@@ -282,25 +563,14 @@ fn slice_path_impl(
 ) {
     // Find all intersections
     hit_buf.clear();
-    for seg_info in path.iter_segments() {
-        let hits = intersect_line_segment(line, &seg_info.segment);
-        for (seg_t, line_t) in hits {
-            let point = line.eval(line_t);
-            hit_buf.push(Hit {
-                line_t,
-                segment_t: seg_t,
-                point,
-                segment_info: seg_info,
-            });
-        }
-    }
+    hit_buf.extend(path_hits(&path, line));
 
     // Base case: 0 or 1 intersections, or hit recursion limit
     if hit_buf.len() <= 1 || recurse == MAX_RECURSE {
         if recurse == MAX_RECURSE {
             tracing::debug!("slice_path hit recursion limit");
         }
-        acc.push(Path::Cubic(path));
+        acc.push(path);
         return;
     }
 
@@ -320,28 +590,28 @@ fn slice_path_impl(
     let (start, end) = order_points(&path, start, end);
 
     // Split the path at the two intersection points
-    let (path_one, path_two) = split_path_at_intersections(&path, start, end);
+    let pieces = split_path(&path, start, end);
 
     // Calculate the remaining line to process
     if next_line_start_t >= 1.0 {
         // No more line to process
-        acc.push(Path::Cubic(path_one));
-        acc.push(Path::Cubic(path_two));
+        acc.extend(pieces);
         return;
     }
 
     let remaining_line = line_subsegment(line, next_line_start_t, 1.0);
 
     // Recursively slice each new path
-    slice_path_impl(path_one, remaining_line, acc, hit_buf, recurse + 1);
-    slice_path_impl(path_two, remaining_line, acc, hit_buf, recurse + 1);
+    for piece in pieces {
+        slice_path_impl(piece, remaining_line, acc, hit_buf, recurse + 1);
+    }
 }
 
 /// Order two hit points based on their position in the path
 ///
 /// This ensures we hit the start point first when iterating through segments.
-fn order_points(path: &CubicPath, start: Hit, end: Hit) -> (Hit, Hit) {
-    for seg_info in path.iter_segments() {
+fn order_points(path: &Path, start: Hit, end: Hit) -> (Hit, Hit) {
+    for seg_info in path_segments(path) {
         if seg_info.start_index == start.segment_info.start_index {
             // Special case: both cuts in same segment
             if seg_info.start_index == end.segment_info.start_index
@@ -360,14 +630,69 @@ fn order_points(path: &CubicPath, start: Hit, end: Hit) -> (Hit, Hit) {
 
 /// Split a path at two intersection points
 ///
-/// Returns two new closed paths.
-fn split_path_at_intersections(path: &CubicPath, start: Hit, end: Hit) -> (CubicPath, CubicPath) {
+/// The piece between the cuts is always closed; the rest keeps the
+/// original path's closedness.
+fn split_path(path: &Path, start: Hit, end: Hit) -> Vec<Path> {
+    match path {
+        Path::Hyper(hyper) => split_hyper_at_intersections(hyper, start, end),
+        _ => {
+            let (one_points, two_points) = split_path_at_intersections(path, start, end);
+            vec![
+                rebuild_path(path, one_points, path.is_closed()),
+                rebuild_path(path, two_points, true),
+            ]
+        }
+    }
+}
+
+/// Split a hyperbezier path at two intersection points
+///
+/// The cut points become corner points. The piece between the cuts is
+/// dropped when it has too few points to form a closed shape.
+fn split_hyper_at_intersections(hyper: &HyperPath, start: Hit, end: Hit) -> Vec<Path> {
+    let points: Vec<PathPoint> = hyper.points.iter().cloned().collect();
+    let first = start.segment_info.start_index;
+    let second = end.segment_info.start_index;
+
+    let mut one_points: Vec<PathPoint> = points[..=first].iter().map(copied_point).collect();
+    push_corner(&mut one_points, start.point);
+    push_corner(&mut one_points, end.point);
+    one_points.extend(points[second + 1..].iter().map(copied_point));
+
+    let mut two_points = Vec::new();
+    push_corner(&mut two_points, start.point);
+    two_points.extend(points[first + 1..=second].iter().map(copied_point));
+    push_corner(&mut two_points, end.point);
+
+    let mut pieces = vec![Path::Hyper(HyperPath::from_points(
+        PathPoints::from_vec(one_points),
+        hyper.closed,
+    ))];
+    if two_points.len() >= 3 {
+        pieces.push(Path::Hyper(HyperPath::from_points(
+            PathPoints::from_vec(two_points),
+            true,
+        )));
+    }
+    pieces
+}
+
+/// Split the points of a cubic or quadratic path at two intersection points
+///
+/// Returns the points of the remaining path and of the piece between
+/// the cuts.
+fn split_path_at_intersections(
+    path: &Path,
+    start: Hit,
+    end: Hit,
+) -> (Vec<PathPoint>, Vec<PathPoint>) {
     let mut one_points: Vec<PathPoint> = Vec::new();
     let mut two_points: Vec<PathPoint> = Vec::new();
     let mut two_is_done = false;
 
-    let points: Vec<PathPoint> = path.points.iter().cloned().collect();
-    let segments: Vec<SegmentInfo> = path.iter_segments().collect();
+    let points: Vec<PathPoint> = path.points().iter().cloned().collect();
+    let segments = path_segments(path);
+    let closed = path.is_closed();
 
     // Phase 1: Copy points up to the first cut
     for seg_info in &segments {
@@ -395,7 +720,7 @@ fn split_path_at_intersections(path: &CubicPath, start: Hit, end: Hit) -> (Cubic
             }
 
             // Add closing line point for path two
-            if !path.closed {
+            if !closed {
                 two_points.push(PathPoint {
                     id: EntityId::next(),
                     point: start.point,
@@ -466,11 +791,7 @@ fn split_path_at_intersections(path: &CubicPath, start: Hit, end: Hit) -> (Cubic
         tracing::debug!("  Path2[{}]: {:?} {:?}", i, pt.point, pt.typ);
     }
 
-    // Create the new paths
-    let path1 = CubicPath::new(PathPoints::from_vec(one_points), path.closed);
-    let path2 = CubicPath::new(PathPoints::from_vec(two_points), true);
-
-    (path1, path2)
+    (one_points, two_points)
 }
 
 /// Append all points of a segment to the destination
@@ -674,8 +995,8 @@ fn append_subsegment_points(
             });
         }
         Segment::Quadratic(quad) => {
-            let cubic = quad.raise();
-            let sub = cubic_subsegment(cubic, t_start, t_end);
+            // Keep quadratic curves quadratic for TrueType sources
+            let sub = quad.subsegment(t_start..t_end);
 
             if dest.last().map(|p| p.point) != Some(sub.p0) {
                 dest.push(PathPoint {
@@ -685,18 +1006,13 @@ fn append_subsegment_points(
                 });
             }
             dest.push(PathPoint {
-                    id: EntityId::next(),
+                id: EntityId::next(),
                 point: sub.p1,
                 typ: PointType::OffCurve { auto: false },
             });
             dest.push(PathPoint {
                 id: EntityId::next(),
                 point: sub.p2,
-                typ: PointType::OffCurve { auto: false },
-            });
-            dest.push(PathPoint {
-                id: EntityId::next(),
-                point: sub.p3,
                 typ: end_typ,
             });
         }
@@ -906,3 +1222,94 @@ fn solve_cubic(a: f64, b: f64, c: f64, d: f64) -> Vec<f64> {
 
     roots
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn on(x: f64, y: f64) -> PathPoint {
+        PathPoint {
+            id: EntityId::next(),
+            point: Point::new(x, y),
+            typ: PointType::OnCurve { smooth: false },
+        }
+    }
+
+    fn off(x: f64, y: f64) -> PathPoint {
+        PathPoint {
+            id: EntityId::next(),
+            point: Point::new(x, y),
+            typ: PointType::OffCurve { auto: false },
+        }
+    }
+
+    fn knife() -> Line {
+        Line::new((-50.0, 50.0), (250.0, 50.0))
+    }
+
+    fn off_curve_count(path: &Path) -> usize {
+        path.points().iter().filter(|pt| pt.is_off_curve()).count()
+    }
+
+    #[test]
+    fn separate_keeps_quadratic_curves() {
+        let points = vec![
+            on(100.0, 0.0),
+            off(150.0, 50.0),
+            on(100.0, 100.0),
+            on(0.0, 100.0),
+            on(0.0, 0.0),
+        ];
+        let path = Path::Quadratic(QuadraticPath::new(PathPoints::from_vec(points), true));
+
+        let out = slice_paths(&[path], knife(), true);
+
+        assert_eq!(out.len(), 2);
+        for piece in &out {
+            assert!(matches!(piece, Path::Quadratic(_)));
+            assert!(piece.is_closed());
+            assert_eq!(off_curve_count(piece), 1);
+        }
+    }
+
+    #[test]
+    fn cut_opens_closed_contour() {
+        let points = vec![
+            on(100.0, 0.0),
+            on(100.0, 100.0),
+            on(0.0, 100.0),
+            on(0.0, 0.0),
+        ];
+        let path = Path::Cubic(CubicPath::new(PathPoints::from_vec(points), true));
+
+        let out = slice_paths(&[path], knife(), false);
+
+        assert_eq!(out.len(), 2);
+        for piece in &out {
+            assert!(!piece.is_closed());
+            assert_eq!(piece.points().len(), 4);
+        }
+    }
+
+    #[test]
+    fn hyper_cut_inserts_corner_points() {
+        let points = vec![
+            on(100.0, 0.0),
+            on(100.0, 100.0),
+            on(0.0, 100.0),
+            on(0.0, 0.0),
+        ];
+        let path = Path::Hyper(HyperPath::from_points(PathPoints::from_vec(points), true));
+
+        for piece in slice_paths(&[path], knife(), false) {
+            let Path::Hyper(hyper) = &piece else {
+                panic!("hyper path became {piece:?}");
+            };
+            let first = hyper.points.iter().next().unwrap();
+            let last = hyper.points.iter().last().unwrap();
+            assert!((first.point.y - 50.0).abs() < 1.0);
+            assert!((last.point.y - 50.0).abs() < 1.0);
+            assert_eq!(first.typ, PointType::OnCurve { smooth: false });
+        }
+    }
+}
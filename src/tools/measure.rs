@@ -6,15 +6,31 @@
 //! Measure tool for measuring distances and angles
 //!
 //! Ported from Runebender Druid implementation.
+//!
+//! Dragging measures along a line, reporting the distance between every
+//! crossing with the glyph's contours and components. Releasing a drag
+//! with Alt held pins the measurement into the glyph lib; Alt-clicking a
+//! pinned measurement removes it. While hovering, the tool reads out the
+//! stem thickness perpendicular to the nearest outline segment.
+//!
+//! The active glyph's outline, components included, is built once and
+//! kept until the session's paths or glyph change, rather than on every
+//! pointer move and paint.
 
 use crate::editing::{EditSession, EditType, MouseDelegate, MouseEvent};
+use crate::model::workspace::Glyph;
+use crate::path::Path as EditorPath;
 use crate::theme;
 use crate::tools::{Tool, ToolId};
-use kurbo::{Affine, Circle, Line, Point, Rect, Size};
+use kurbo::{
+    Affine, BezPath, Circle, Line, ParamCurve, ParamCurveDeriv, ParamCurveNearest, PathSeg, Point,
+    Rect, Shape, Size, Vec2,
+};
 use masonry::core::{BrushIndex, StyleProperty, render_text};
 use masonry::imaging::Painter;
 use masonry::peniko::{Brush, Color};
 use parley::{FontContext, LayoutContext};
+use std::sync::Arc;
 use tracing;
 
 // ===== Constants =====
@@ -22,6 +38,21 @@ use tracing;
 /// Tolerance for fuzzy intersection clustering
 const MEASURE_FUZZY_TOLERANCE: f64 = 0.1;
 
+/// Hover distance (screen pixels) within which a stem is read out
+const STEM_HOVER_DISTANCE: f64 = 12.0;
+
+/// How far (design units) to look across a stem for the opposite side
+const STEM_REACH: f64 = 2000.0;
+
+/// Shortest stem (design units), so the ray doesn't hit its own start
+const STEM_MIN: f64 = 0.5;
+
+/// Minimum drag length (screen pixels) for a measurement to be pinned
+const PIN_MIN_LENGTH: f64 = 2.0;
+
+/// Click distance (screen pixels) for removing a pinned measurement
+const PIN_HIT_DISTANCE: f64 = 6.0;
+
 // ===== MeasureTool Struct =====
 
 /// The measure tool - used for measuring distances and angles
@@ -33,6 +64,49 @@ pub struct MeasureTool {
     dragging: bool,
     /// Start point of drag (in screen space)
     drag_start: Option<Point>,
+    /// Stem readout under the cursor (glyph coordinates)
+    stem: Option<Line>,
+    /// The active glyph's outline, built on first use
+    outline: Option<Arc<GlyphOutline>>,
+}
+
+/// The active glyph's outline, with the session state it was built from
+#[derive(Debug, Clone)]
+struct GlyphOutline {
+    /// The session's paths and glyph when the outline was built. Holding
+    /// them means an edit through `Arc::make_mut` makes new ones, so a
+    /// changed pointer is a changed outline.
+    source: (Arc<Vec<EditorPath>>, Arc<Glyph>),
+    /// Editable paths followed by the decomposed components
+    paths: Vec<EditorPath>,
+    /// The same paths as one bezier path, in glyph coordinates
+    bezpath: BezPath,
+}
+
+impl GlyphOutline {
+    fn new(session: &EditSession) -> Self {
+        let components = session.decomposed_components();
+        let paths: Vec<EditorPath> = session
+            .paths
+            .iter()
+            .cloned()
+            .chain(components.into_iter().flat_map(|(_, paths)| paths))
+            .collect();
+        let mut bezpath = BezPath::new();
+        for path in &paths {
+            bezpath.extend(path.to_bezpath());
+        }
+        Self {
+            source: (Arc::clone(&session.paths), Arc::clone(&session.glyph)),
+            paths,
+            bezpath,
+        }
+    }
+
+    /// Whether the outline was built from the session's current state
+    fn is_current(&self, session: &EditSession) -> bool {
+        Arc::ptr_eq(&self.source.0, &session.paths) && Arc::ptr_eq(&self.source.1, &session.glyph)
+    }
 }
 
 // ===== Tool Implementation =====
@@ -43,9 +117,24 @@ impl Tool for MeasureTool {
     }
 
     fn paint(&mut self, painter: &mut Painter<'_>, session: &EditSession, _transform: &Affine) {
+        let outline = self.outline(session);
+
+        // Pinned measurements are stored in glyph coordinates
+        for pinned in &session.glyph.measurements {
+            let line = glyph_line_to_screen(session, *pinned);
+            self.paint_measurement(painter, session, line, &outline.paths);
+        }
+
         // Paint the measurement line and info if present
         if let Some(line) = self.line {
-            self.paint_measurement(painter, session, line);
+            self.paint_measurement(painter, session, line, &outline.paths);
+        }
+
+        if !self.dragging
+            && let Some(stem) = self.stem
+        {
+            let line = glyph_line_to_screen(session, stem);
+            paint_stem(painter, line, stem.length());
         }
     }
 
@@ -67,6 +156,7 @@ impl MouseDelegate for MeasureTool {
         self.dragging = true;
         self.drag_start = Some(event.pos);
         self.line = Some(Line::new(event.pos, event.pos));
+        self.stem = None;
         tracing::debug!("Measure tool: started drag at {:?}", event.pos);
     }
 
@@ -89,16 +179,64 @@ impl MouseDelegate for MeasureTool {
         }
     }
 
-    fn left_up(&mut self, _event: MouseEvent, _data: &mut EditSession) {
+    fn left_up(&mut self, event: MouseEvent, data: &mut EditSession) {
         self.dragging = false;
+
+        // Alt pins the measurement into the glyph
+        if event.mods.alt
+            && let Some(line) = self.line
+            && line.length() >= PIN_MIN_LENGTH
+        {
+            let pinned = screen_line_to_glyph(data, line);
+            Arc::make_mut(&mut data.glyph).measurements.push(pinned);
+            self.line = None;
+            tracing::debug!("Measure tool: pinned {:?}", pinned);
+            return;
+        }
+
         // Keep the line visible after drag ends
         tracing::debug!("Measure tool: finished drag");
     }
 
+    fn left_click(&mut self, event: MouseEvent, data: &mut EditSession) {
+        if !event.mods.alt {
+            return;
+        }
+
+        // Alt-click removes the pinned measurement under the cursor
+        let hit = data
+            .glyph
+            .measurements
+            .iter()
+            .map(|pinned| {
+                let line = glyph_line_to_screen(data, *pinned);
+                line.nearest(event.pos, 1e-6).distance_sq
+            })
+            .enumerate()
+            .filter(|(_, dist_sq)| *dist_sq <= PIN_HIT_DISTANCE * PIN_HIT_DISTANCE)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(i, _)| i);
+        if let Some(index) = hit {
+            Arc::make_mut(&mut data.glyph).measurements.remove(index);
+            tracing::debug!("Measure tool: removed pinned measurement {}", index);
+        }
+    }
+
+    fn mouse_moved(&mut self, event: MouseEvent, data: &mut EditSession) {
+        if self.dragging {
+            return;
+        }
+
+        let pos = screen_point_to_glyph(data, event.pos);
+        let max_distance = STEM_HOVER_DISTANCE / data.viewport.zoom;
+        self.stem = stem_across(&self.outline(data).bezpath, pos, max_distance);
+    }
+
     fn cancel(&mut self, _data: &mut EditSession) {
         self.line = None;
         self.dragging = false;
         self.drag_start = None;
+        self.stem = None;
         tracing::debug!("Measure tool: cancelled");
     }
 }
@@ -106,8 +244,27 @@ impl MouseDelegate for MeasureTool {
 // ===== Helper Methods =====
 
 impl MeasureTool {
+    /// The active glyph's outline, rebuilt if the session's paths or
+    /// glyph changed since it was last built
+    fn outline(&mut self, session: &EditSession) -> Arc<GlyphOutline> {
+        match &self.outline {
+            Some(outline) if outline.is_current(session) => Arc::clone(outline),
+            _ => {
+                let outline = Arc::new(GlyphOutline::new(session));
+                self.outline = Some(Arc::clone(&outline));
+                outline
+            }
+        }
+    }
+
     /// Paint the measurement line and all associated info
-    fn paint_measurement(&self, painter: &mut Painter<'_>, session: &EditSession, line: Line) {
+    fn paint_measurement(
+        &self,
+        painter: &mut Painter<'_>,
+        session: &EditSession,
+        line: Line,
+        paths: &[EditorPath],
+    ) {
         // Draw the measurement line using theme colors (same as pen/knife preview)
         let stroke = kurbo::Stroke::new(crate::theme::tool_preview::LINE_WIDTH).with_dashes(
             crate::theme::tool_preview::LINE_DASH_OFFSET,
@@ -136,7 +293,7 @@ impl MeasureTool {
         let design_len = (design_line.p1 - design_line.p0).hypot();
 
         // Compute intersections with paths
-        let intersections = self.compute_measurement(session, design_line);
+        let intersections = self.compute_measurement(session, design_line, paths);
 
         // Draw dots at intersection points using theme radius
         for t in &intersections {
@@ -160,7 +317,12 @@ impl MeasureTool {
 
     /// Compute measurement intersections with all paths
     /// Returns a sorted list of t values (0.0 to 1.0) along the measurement line
-    fn compute_measurement(&self, session: &EditSession, design_line: Line) -> Vec<f64> {
+    fn compute_measurement(
+        &self,
+        session: &EditSession,
+        design_line: Line,
+        paths: &[EditorPath],
+    ) -> Vec<f64> {
         const T_SCALE: f64 = (1u64 << 63) as f64;
         let mut intersections = vec![0, T_SCALE as u64];
 
@@ -179,12 +341,10 @@ impl MeasureTool {
             ),
         );
 
-        // Find all intersections with active sort paths
-        Self::intersect_session_paths(
-            &local_line,
-            session,
-            &mut intersections,
-        );
+        // Find all intersections with active sort paths and components
+        for path in paths {
+            push_path_intersections(local_line, path, &mut intersections);
+        }
 
         // Also intersect with inactive sort bezpaths
         // from the text buffer
@@ -231,52 +391,6 @@ impl MeasureTool {
         result
     }
 
-    /// Intersect a design-space line with the active
-    /// session's editable paths.
-    fn intersect_session_paths(
-        line: &Line,
-        session: &EditSession,
-        intersections: &mut Vec<u64>,
-    ) {
-        const T_SCALE: f64 = (1u64 << 63) as f64;
-
-        for path in session.paths.iter() {
-            match path {
-                crate::path::Path::Cubic(cubic) => {
-                    for seg in cubic.iter_segments() {
-                        let hits =
-                            crate::tools::knife::intersect_line_segment(
-                                *line,
-                                &seg.segment,
-                            );
-                        for (_, lt) in hits {
-                            intersections.push(
-                                (lt.clamp(0.0, 1.0) * T_SCALE)
-                                    as u64,
-                            );
-                        }
-                    }
-                }
-                crate::path::Path::Hyper(hyper) => {
-                    for seg in hyper.iter_segments() {
-                        let hits =
-                            crate::tools::knife::intersect_line_segment(
-                                *line,
-                                &seg.segment,
-                            );
-                        for (_, lt) in hits {
-                            intersections.push(
-                                (lt.clamp(0.0, 1.0) * T_SCALE)
-                                    as u64,
-                            );
-                        }
-                    }
-                }
-                crate::path::Path::Quadratic(_) => {}
-            }
-        }
-    }
-
     /// Intersect the measurement line with all inactive
     /// sorts in the text buffer. Each sort's glyph is
     /// loaded from the workspace and offset by its
@@ -288,9 +402,6 @@ impl MeasureTool {
         intersections: &mut Vec<u64>,
     ) {
         use crate::model::read_workspace;
-        use crate::path::Path as EditorPath;
-
-        const T_SCALE: f64 = (1u64 << 63) as f64;
 
         let buffer = match &session.text_buffer {
             Some(b) => b,
@@ -360,23 +471,23 @@ impl MeasureTool {
                                     EditorPath::from_contour(
                                         contour,
                                     );
-                                if let EditorPath::Cubic(c) =
-                                    &path
-                                {
-                                    for seg in
-                                        c.iter_segments()
-                                    {
-                                        let hits = crate::tools::knife::intersect_line_segment(
-                                            local_line,
-                                            &seg.segment,
-                                        );
-                                        for (_, lt) in hits
-                                        {
-                                            intersections.push(
-                                                (lt.clamp(0.0, 1.0) * T_SCALE) as u64,
-                                            );
-                                        }
-                                    }
+                                push_path_intersections(
+                                    local_line,
+                                    &path,
+                                    intersections,
+                                );
+                            }
+                            for component in &glyph.components {
+                                let paths = crate::path::decompose_component(
+                                    component,
+                                    &workspace,
+                                );
+                                for path in &paths {
+                                    push_path_intersections(
+                                        local_line,
+                                        path,
+                                        intersections,
+                                    );
                                 }
                             }
                     }
@@ -399,6 +510,88 @@ impl MeasureTool {
 
 // ===== Standalone Helper Functions =====
 
+/// Record where `line` crosses a path, as fixed-point line parameters
+fn push_path_intersections(line: Line, path: &EditorPath, intersections: &mut Vec<u64>) {
+    const T_SCALE: f64 = (1u64 << 63) as f64;
+
+    for seg in crate::tools::knife::path_segments(path) {
+        let hits = crate::tools::knife::intersect_line_segment(line, &seg.segment);
+        for (_, lt) in hits {
+            intersections.push((lt.clamp(0.0, 1.0) * T_SCALE) as u64);
+        }
+    }
+}
+
+/// Convert a screen point to the active glyph's coordinates
+///
+/// Paths are stored without the active sort's x offset.
+fn screen_point_to_glyph(session: &EditSession, pos: Point) -> Point {
+    session.viewport.screen_to_design(pos) - Vec2::new(session.active_sort_x_offset, 0.0)
+}
+
+/// Convert a screen-space line to the active glyph's coordinates
+fn screen_line_to_glyph(session: &EditSession, line: Line) -> Line {
+    Line::new(
+        screen_point_to_glyph(session, line.p0),
+        screen_point_to_glyph(session, line.p1),
+    )
+}
+
+/// Convert a line in the active glyph's coordinates to screen space
+fn glyph_line_to_screen(session: &EditSession, line: Line) -> Line {
+    let offset = Vec2::new(session.active_sort_x_offset, 0.0);
+    Line::new(
+        session.viewport.to_screen(line.p0 + offset),
+        session.viewport.to_screen(line.p1 + offset),
+    )
+}
+
+/// Measure the stem at the outline point nearest `pos`
+///
+/// Casts a ray perpendicular to the nearest segment, toward whichever
+/// side is filled, and returns the line across to the first contour it
+/// meets. Returns `None` if no segment is within `max_distance`.
+fn stem_across(outline: &BezPath, pos: Point, max_distance: f64) -> Option<Line> {
+    let (seg, nearest) = outline
+        .segments()
+        .map(|seg| (seg, seg.nearest(pos, 1e-6)))
+        .min_by(|a, b| a.1.distance_sq.total_cmp(&b.1.distance_sq))?;
+    if nearest.distance_sq > max_distance * max_distance {
+        return None;
+    }
+
+    let start = seg.eval(nearest.t);
+    let tangent = seg.to_cubic().deriv().eval(nearest.t).to_vec2();
+    if tangent.hypot2() < 1e-12 {
+        return None;
+    }
+    let normal = tangent.normalize().turn_90();
+
+    [normal, -normal].into_iter().find_map(|dir| {
+        let ray = Line::new(start, start + dir * STEM_REACH);
+        let t = outline
+            .segments()
+            .flat_map(|s: PathSeg| s.intersect_line(ray))
+            .map(|hit| hit.line_t)
+            .filter(|t| t * STEM_REACH > STEM_MIN)
+            .min_by(f64::total_cmp)?;
+        let end = ray.eval(t);
+        (outline.winding(start.midpoint(end)) != 0).then_some(Line::new(start, end))
+    })
+}
+
+/// Draw the hover stem readout (screen space) with its thickness
+fn paint_stem(painter: &mut Painter<'_>, line: Line, thickness: f64) {
+    let stroke = kurbo::Stroke::new(crate::theme::tool_preview::LINE_WIDTH);
+    let brush = Brush::Solid(crate::theme::tool_preview::LINE_COLOR);
+    painter.stroke(&line, &stroke, &brush).draw();
+    for pt in [line.p0, line.p1] {
+        let circle = Circle::new(pt, crate::theme::tool_preview::DOT_RADIUS);
+        painter.fill(&circle, &brush).draw();
+    }
+    draw_info_bubble(painter, line.midpoint(), format!("{:.1}", thickness));
+}
+
 /// Constrain a point to the horizontal or vertical axis
 /// relative to an origin, whichever is closer to the actual
 /// direction.
@@ -500,3 +693,17 @@ fn draw_info_bubble(painter: &mut Painter<'_>, pos: Point, label: impl Into<Stri
         false, // No hinting
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stem_reads_across_filled_side() {
+        let outline = Rect::new(0.0, 0.0, 80.0, 600.0).to_path(0.1);
+
+        let stem = stem_across(&outline, Point::new(2.0, 300.0), 5.0).unwrap();
+        assert!((stem.length() - 80.0).abs() < 1e-6);
+        assert!(stem_across(&outline, Point::new(40.0, 300.0), 5.0).is_none());
+    }
+}
@@ -2,6 +2,13 @@
 // SPDX-License-Identifier: Apache-2.0
 
 //! Pen tool for drawing new paths
+//!
+//! Besides drawing fresh contours, the pen continues existing open
+//! contours (click one of their endpoints), joins the contour being
+//! drawn onto another open contour's endpoint, and closes onto its own
+//! start. Dragging on the start while closing pulls out the closing
+//! handle, mirrored through the start so it stays smooth. Endpoints
+//! under the cursor are highlighted while hovering.
 
 use crate::editing::{EditSession, EditType, MouseDelegate, MouseEvent};
 use crate::model::EntityId;
@@ -20,6 +27,20 @@ const CLOSE_PATH_DISTANCE: f64 = 20.0;
 /// Distance threshold for snapping to curves (in screen pixels)
 const CURVE_SNAP_DISTANCE: f64 = 10.0;
 
+/// An endpoint of an open contour in the session
+///
+/// The pen continues drawing from these when idle and joins onto them
+/// while drawing.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct OpenEndpoint {
+    /// Index of the contour in `EditSession::paths`
+    path_index: usize,
+    /// Whether this is the contour's first point (otherwise its last)
+    is_start: bool,
+    /// Position in design space
+    point: kurbo::Point,
+}
+
 // ===== PenTool Struct =====
 
 // ============================================================================
@@ -78,6 +99,17 @@ pub struct PenTool {
 
     /// Whether we're currently dragging out handles for a smooth point
     dragging_handles: bool,
+
+    /// Open-contour endpoint under the cursor (continue/join target)
+    hover_endpoint: Option<OpenEndpoint>,
+
+    /// Whether the current click resumed an existing open contour
+    /// (the click itself then adds no point)
+    resumed_on_down: bool,
+
+    /// Whether the current drag pulls the closing handle out of the
+    /// start point (the contour closes when it ends)
+    closing_drag: bool,
 }
 
 // ===== Tool Implementation =====
@@ -102,7 +134,17 @@ impl Tool for PenTool {
         // anymore because we want to show the preview dot even when not
         // drawing
         if self.drawing && !self.current_path_points.is_empty() {
-            self.draw_preview_path(painter, session, &brush, hovering_close);
+            let target = if hovering_close {
+                self.start_point()
+            } else {
+                self.hover_endpoint.map(|ep| ep.point)
+            };
+            self.draw_preview_path(painter, session, &brush, target);
+        }
+
+        // Highlight the open endpoint we would continue from or join
+        if !hovering_close && let Some(endpoint) = self.hover_endpoint {
+            self.draw_endpoint_zone(painter, session, &brush, endpoint.point);
         }
 
         // Draw circles at each point (only when drawing)
@@ -111,9 +153,10 @@ impl Tool for PenTool {
         }
 
         // Draw handle lines when dragging out a smooth point
-        if self.dragging_handles && self.current_path_points.len() >= 3
+        if self.dragging_handles
+            && let Some(handles) = self.drag_handle_points()
         {
-            self.draw_drag_handles(painter, session, &brush);
+            self.draw_drag_handles(painter, session, &brush, handles);
         }
 
         // Draw preview circle at current mouse position (showing where
@@ -143,6 +186,16 @@ impl MouseDelegate for PenTool {
         let design_pos = data.viewport.screen_to_design(event.pos);
         self.drag_origin = Some(design_pos);
         self.dragging_handles = false;
+        self.resumed_on_down = false;
+        self.closing_drag = false;
+
+        // Pressing on an open contour's endpoint continues that contour
+        if !self.drawing
+            && let Some(endpoint) = find_open_endpoint(data, design_pos)
+        {
+            self.resume_path(data, endpoint);
+            self.resumed_on_down = true;
+        }
     }
 
     fn left_click(&mut self, event: MouseEvent, data: &mut EditSession) {
@@ -154,6 +207,14 @@ impl MouseDelegate for PenTool {
             return;
         }
 
+        // The press already resumed an open contour; the click itself
+        // adds nothing
+        if self.resumed_on_down {
+            self.resumed_on_down = false;
+            self.drag_origin = None;
+            return;
+        }
+
         // Check if we're snapped to a curve segment
         // If so, insert a point on the segment instead of starting a
         // new path
@@ -178,6 +239,16 @@ impl MouseDelegate for PenTool {
             return;
         }
 
+        // Clicking another open contour's endpoint joins onto it
+        if self.drawing
+            && let Some(endpoint) = find_open_endpoint(data, design_pos)
+        {
+            tracing::debug!("Pen tool: joining onto open contour");
+            self.join_path(data, endpoint);
+            self.drag_origin = None;
+            return;
+        }
+
         // Create a new corner on-curve point (no drag = no handles)
         let point = PathPoint {
             id: EntityId::next(),
@@ -212,8 +283,15 @@ impl MouseDelegate for PenTool {
             None => return,
         };
 
-        // Check if we should close the path instead
+        // Check if we should close, continue or join instead
+        if self.resumed_on_down {
+            return;
+        }
         if self.should_close_path(origin) {
+            self.begin_closing_drag();
+            return;
+        }
+        if self.drawing && find_open_endpoint(data, origin).is_some() {
             return;
         }
 
@@ -267,6 +345,11 @@ impl MouseDelegate for PenTool {
         };
 
         let design_pos = data.viewport.screen_to_design(event.pos);
+        if self.closing_drag {
+            self.drag_closing_handles(design_pos);
+            return;
+        }
+
         let len = self.current_path_points.len();
         if len < 3 {
             return;
@@ -287,13 +370,24 @@ impl MouseDelegate for PenTool {
         &mut self,
         _event: MouseEvent,
         _drag: crate::editing::Drag,
-        _data: &mut EditSession,
+        data: &mut EditSession,
     ) {
+        // A drag gets no click, so end a resume gesture here
+        self.resumed_on_down = false;
+
         if !self.dragging_handles {
             self.drag_origin = None;
             return;
         }
 
+        if self.closing_drag {
+            tracing::debug!("Pen tool: closing path with a dragged handle");
+            self.closing_drag = false;
+            self.close_path(data);
+            self.drag_origin = None;
+            return;
+        }
+
         tracing::debug!(
             "Pen tool: finished smooth point, total points: {}",
             self.current_path_points.len()
@@ -307,9 +401,13 @@ impl MouseDelegate for PenTool {
         // Track mouse position for hover feedback
         self.mouse_pos = Some(event.pos);
 
+        let design_pos = data.viewport.screen_to_design(event.pos);
+        self.hover_endpoint = find_open_endpoint(data, design_pos);
+
         // Check for curve snapping (only when not actively drawing a
-        // path). This prevents snapping while building a new path
-        if !self.drawing {
+        // path and not over an endpoint). This prevents snapping while
+        // building a new path
+        if !self.drawing && self.hover_endpoint.is_none() {
             // Hit test segments at cursor position
             if let Some((segment_info, t)) = data.hit_test_segments(event.pos, CURVE_SNAP_DISTANCE)
             {
@@ -354,7 +452,9 @@ impl PenTool {
         };
 
         let mouse_design = session.viewport.screen_to_design(mouse_screen);
-        let first_point = self.current_path_points[0].point;
+        let Some(first_point) = self.start_point() else {
+            return false;
+        };
         let distance = ((mouse_design.x - first_point.x).powi(2)
             + (mouse_design.y - first_point.y).powi(2))
         .sqrt();
@@ -368,7 +468,7 @@ impl PenTool {
         painter: &mut Painter<'_>,
         session: &EditSession,
         brush: &masonry::peniko::Brush,
-        hovering_close: bool,
+        target: Option<kurbo::Point>,
     ) {
         use kurbo::{BezPath, Point};
 
@@ -384,16 +484,11 @@ impl PenTool {
             }
         }
 
-        // Draw preview line to current mouse position (or closing line
-        // if hovering near first point)
+        // Draw preview line to current mouse position (or to the first
+        // point / joined endpoint when hovering one)
         if let Some(mouse_screen) = self.mouse_pos {
-            if hovering_close {
-                // Show closing line to first point
-                if let Some(first_pt) = self.current_path_points.first() {
-                    let design_pt = Point::new(first_pt.point.x, first_pt.point.y);
-                    let screen_pt = session.viewport.to_screen(design_pt);
-                    bez_path.line_to(screen_pt);
-                }
+            if let Some(target) = target {
+                bez_path.line_to(session.viewport.to_screen(target));
             } else {
                 // Show preview line to current mouse position
                 bez_path.line_to(mouse_screen);
@@ -439,6 +534,22 @@ impl PenTool {
         }
     }
 
+    /// Draw the hover ring around an open-contour endpoint
+    fn draw_endpoint_zone(
+        &self,
+        painter: &mut Painter<'_>,
+        session: &EditSession,
+        brush: &masonry::peniko::Brush,
+        point: kurbo::Point,
+    ) {
+        let zone = kurbo::Circle::new(
+            session.viewport.to_screen(point),
+            crate::theme::tool_preview::CLOSE_ZONE_RADIUS * session.viewport.zoom,
+        );
+        let zone_stroke = kurbo::Stroke::new(1.0);
+        painter.stroke(&zone, &zone_stroke, brush).draw();
+    }
+
     /// Draw preview dot at current mouse position or snapped position
     fn draw_preview_dot(
         &self,
@@ -488,21 +599,38 @@ impl PenTool {
         }
     }
 
+    /// The incoming handle, on-curve point and outgoing handle being
+    /// dragged: the last point drawn, or the start while closing. A
+    /// start with a line out of it has no outgoing handle and repeats
+    /// the on-curve point instead.
+    fn drag_handle_points(&self) -> Option<[kurbo::Point; 3]> {
+        let points = &self.current_path_points;
+        if self.closing_drag {
+            let start = self.start_index()?;
+            let handle_in = points.get(start.checked_sub(1)?)?;
+            let handle_out = points
+                .get(start + 1)
+                .filter(|pt| pt.is_off_curve())
+                .unwrap_or(&points[start]);
+            return Some([handle_in.point, points[start].point, handle_out.point]);
+        }
+        let [.., handle_in, on_curve, handle_out] = points.as_slice() else {
+            return None;
+        };
+        Some([handle_in.point, on_curve.point, handle_out.point])
+    }
+
     /// Draw handle lines and dots when dragging out a smooth point
     fn draw_drag_handles(
         &self,
         painter: &mut Painter<'_>,
         session: &EditSession,
         brush: &masonry::peniko::Brush,
+        [handle_in, on_curve, handle_out]: [kurbo::Point; 3],
     ) {
-        let len = self.current_path_points.len();
-        let handle_in = &self.current_path_points[len - 3];
-        let on_curve = &self.current_path_points[len - 2];
-        let handle_out = &self.current_path_points[len - 1];
-
-        let p_in = session.viewport.to_screen(handle_in.point);
-        let p_on = session.viewport.to_screen(on_curve.point);
-        let p_out = session.viewport.to_screen(handle_out.point);
+        let p_in = session.viewport.to_screen(handle_in);
+        let p_on = session.viewport.to_screen(on_curve);
+        let p_out = session.viewport.to_screen(handle_out);
 
         // Draw handle lines
         let stroke = kurbo::Stroke::new(1.0);
@@ -524,13 +652,78 @@ impl PenTool {
         painter.fill(&on_circle, brush).draw();
     }
 
+    /// Index of the contour's first on-curve point; a handle dragged
+    /// out of it comes before it
+    fn start_index(&self) -> Option<usize> {
+        self.current_path_points
+            .iter()
+            .position(|pt| pt.is_on_curve())
+    }
+
+    /// Position of the contour's first on-curve point
+    fn start_point(&self) -> Option<kurbo::Point> {
+        self.start_index()
+            .map(|index| self.current_path_points[index].point)
+    }
+
+    /// Start pulling the closing handle out of the start point
+    ///
+    /// The closing handle is the one before the start (a start that
+    /// was dragged out already has one), and the start becomes smooth.
+    fn begin_closing_drag(&mut self) {
+        let Some(mut start) = self.start_index() else {
+            return;
+        };
+        if start == 0 {
+            let anchor = self.current_path_points[0].point;
+            self.current_path_points.insert(0, retracted_handle(anchor));
+            start = 1;
+        }
+        self.current_path_points[start].typ = PointType::OnCurve { smooth: true };
+        self.closing_drag = true;
+        self.dragging_handles = true;
+    }
+
+    /// Move the closing handle to mirror `design_pos` through the
+    /// start point
+    ///
+    /// A start with an outgoing handle has it follow the cursor, as
+    /// when dragging out a new point. A start with a line out of it
+    /// keeps the closing handle on the line's tangent so it stays
+    /// smooth.
+    fn drag_closing_handles(&mut self, design_pos: kurbo::Point) {
+        let Some(start) = self.start_index().filter(|&start| start > 0) else {
+            return;
+        };
+        let points = &mut self.current_path_points;
+        let anchor = points[start].point;
+        let pull = design_pos - anchor;
+        let closing = match points.get(start + 1) {
+            Some(next) if next.is_off_curve() => {
+                points[start + 1].point = design_pos;
+                anchor - pull
+            }
+            Some(next) => {
+                let tangent = anchor - next.point;
+                if tangent.hypot() < f64::EPSILON {
+                    return;
+                }
+                anchor + tangent.normalize() * pull.hypot()
+            }
+            None => return,
+        };
+        points[start - 1].point = closing;
+    }
+
     /// Check if we should close the path (clicking near first point)
     fn should_close_path(&self, design_pos: kurbo::Point) -> bool {
         if self.current_path_points.len() < 3 {
             return false;
         }
 
-        let first_point = self.current_path_points[0].point;
+        let Some(first_point) = self.start_point() else {
+            return false;
+        };
         let distance = ((design_pos.x - first_point.x).powi(2)
             + (design_pos.y - first_point.y).powi(2))
         .sqrt();
@@ -544,8 +737,20 @@ impl PenTool {
             return;
         }
 
+        // Handles dragged out at the ends of an open contour have no
+        // segment to belong to, so drop them
+        let mut points = self.current_path_points.clone();
+        while points.last().is_some_and(|pt| pt.is_off_curve()) {
+            points.pop();
+        }
+        let leading = points.iter().take_while(|pt| pt.is_off_curve()).count();
+        points.drain(..leading);
+        if points.len() < 2 {
+            return;
+        }
+
         // Create a new open path from the points
-        let path_points = PathPoints::from_vec(self.current_path_points.clone());
+        let path_points = PathPoints::from_vec(points);
         let cubic_path = CubicPath {
             points: path_points,
             closed: false,
@@ -569,8 +774,30 @@ impl PenTool {
             return;
         }
 
+        // The closing segment wraps from the trailing handles to the
+        // leading ones. A lone handle on one side gets a retracted
+        // partner so the segment stays a proper cubic and the start
+        // keeps its tangent.
+        let mut points = self.current_path_points.clone();
+        let trailing = points
+            .iter()
+            .rev()
+            .take_while(|pt| pt.is_off_curve())
+            .count();
+        let leading = points.iter().take_while(|pt| pt.is_off_curve()).count();
+        if trailing + leading == 1 {
+            let anchor = if trailing == 1 {
+                points.iter().find(|pt| pt.is_on_curve())
+            } else {
+                points.iter().rev().find(|pt| pt.is_on_curve())
+            };
+            if let Some(anchor) = anchor.map(|pt| pt.point) {
+                points.push(retracted_handle(anchor));
+            }
+        }
+
         // Create a closed path from the points
-        let path_points = PathPoints::from_vec(self.current_path_points.clone());
+        let path_points = PathPoints::from_vec(points);
         let cubic_path = CubicPath {
             points: path_points,
             closed: true, // Mark as closed
@@ -592,6 +819,51 @@ impl PenTool {
         self.drawing = false;
     }
 
+    /// Start drawing from the endpoint of an existing open contour
+    ///
+    /// The contour is taken out of the session and becomes the path
+    /// being drawn, oriented so the pressed endpoint is its last point.
+    fn resume_path(&mut self, data: &mut EditSession, endpoint: OpenEndpoint) {
+        let mut points = take_path_points(data, endpoint.path_index);
+        if endpoint.is_start {
+            points.reverse();
+        }
+        self.current_path_points = points;
+        self.drawing = true;
+        self.snapped_segment = None;
+        self.hover_endpoint = None;
+
+        tracing::debug!(
+            "Pen tool: continuing open contour with {} points",
+            self.current_path_points.len()
+        );
+    }
+
+    /// Append another open contour at the clicked endpoint and finish
+    fn join_path(&mut self, data: &mut EditSession, endpoint: OpenEndpoint) {
+        let mut other = take_path_points(data, endpoint.path_index);
+        if !endpoint.is_start {
+            other.reverse();
+        }
+
+        // A lone handle pulled out of our last point needs a partner
+        // to form a cubic into the joined contour
+        let trailing = self
+            .current_path_points
+            .iter()
+            .rev()
+            .take_while(|pt| pt.is_off_curve())
+            .count();
+        if trailing == 1 {
+            let handle = retracted_handle(endpoint.point);
+            self.current_path_points.push(handle);
+        }
+
+        self.current_path_points.extend(other);
+        self.hover_endpoint = None;
+        self.finish_path(data);
+    }
+
     /// Finish drawing and reset for next path (called on Escape or tool
     /// change)
     pub fn finish_path(&mut self, data: &mut EditSession) {
@@ -604,3 +876,221 @@ impl PenTool {
         self.drawing = false;
     }
 }
+
+/// Find the open-contour endpoint nearest to `design_pos`, if one is
+/// within closing distance
+///
+/// Only cubic contours with at least two points take part, since the
+/// pen draws cubic paths.
+fn find_open_endpoint(session: &EditSession, design_pos: kurbo::Point) -> Option<OpenEndpoint> {
+    let mut best: Option<(f64, OpenEndpoint)> = None;
+    for (path_index, path) in session.paths.iter().enumerate() {
+        let Path::Cubic(cubic) = path else {
+            continue;
+        };
+        if cubic.closed || cubic.points.len() < 2 {
+            continue;
+        }
+        let first = cubic.points.iter().next().map(|pt| (true, pt.point));
+        let last = cubic.points.iter().last().map(|pt| (false, pt.point));
+        for (is_start, point) in first.into_iter().chain(last) {
+            let distance = (point - design_pos).hypot();
+            if distance >= CLOSE_PATH_DISTANCE || best.is_some_and(|(d, _)| d <= distance) {
+                continue;
+            }
+            let endpoint = OpenEndpoint {
+                path_index,
+                is_start,
+                point,
+            };
+            best = Some((distance, endpoint));
+        }
+    }
+    best.map(|(_, endpoint)| endpoint)
+}
+
+/// Remove a contour from the session and return its points
+fn take_path_points(data: &mut EditSession, path_index: usize) -> Vec<PathPoint> {
+    let mut paths = (*data.paths).clone();
+    let path = paths.remove(path_index);
+    data.paths = Arc::new(paths);
+    path.points().to_vec()
+}
+
+/// Zero-length off-curve handle sitting on `point`
+fn retracted_handle(point: kurbo::Point) -> PathPoint {
+    PathPoint {
+        id: EntityId::next(),
+        point,
+        typ: PointType::OffCurve { auto: false },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::editing::FontMetrics;
+    use crate::model::workspace::Glyph;
+    use kurbo::Point;
+
+    fn on(x: f64, y: f64) -> PathPoint {
+        PathPoint {
+            id: EntityId::next(),
+            point: Point::new(x, y),
+            typ: PointType::OnCurve { smooth: false },
+        }
+    }
+
+    fn off(x: f64, y: f64) -> PathPoint {
+        PathPoint {
+            id: EntityId::next(),
+            point: Point::new(x, y),
+            typ: PointType::OffCurve { auto: false },
+        }
+    }
+
+    fn cubic(points: Vec<PathPoint>, closed: bool) -> Path {
+        Path::Cubic(CubicPath::new(PathPoints::from_vec(points), closed))
+    }
+
+    fn session(paths: Vec<Path>) -> EditSession {
+        let metrics = FontMetrics {
+            units_per_em: 1000.0,
+            ascender: 800.0,
+            descender: -200.0,
+            x_height: None,
+            cap_height: None,
+        };
+        let mut session = EditSession::new(
+            "a".to_string(),
+            std::path::PathBuf::from("/test.ufo"),
+            Glyph::for_test("a", 500.0),
+            metrics,
+        );
+        session.paths = Arc::new(paths);
+        session
+    }
+
+    fn positions(points: &[PathPoint]) -> Vec<(f64, f64)> {
+        points.iter().map(|pt| (pt.point.x, pt.point.y)).collect()
+    }
+
+    #[test]
+    fn finds_nearest_open_endpoint() {
+        let square = vec![on(0.0, 0.0), on(0.0, 100.0), on(100.0, 100.0)];
+        let data = session(vec![
+            cubic(square, true),
+            cubic(vec![on(0.0, 0.0), on(200.0, 0.0)], false),
+            cubic(vec![on(205.0, 0.0), on(400.0, 0.0)], false),
+        ]);
+
+        // The closed contour's points don't count
+        let start = find_open_endpoint(&data, Point::new(5.0, 0.0)).unwrap();
+        assert_eq!((start.path_index, start.is_start), (1, true));
+
+        // Of two endpoints in range, the nearer one wins
+        let end = find_open_endpoint(&data, Point::new(201.0, 0.0)).unwrap();
+        assert_eq!((end.path_index, end.is_start), (1, false));
+        let next = find_open_endpoint(&data, Point::new(204.0, 0.0)).unwrap();
+        assert_eq!((next.path_index, next.is_start), (2, true));
+
+        assert!(find_open_endpoint(&data, Point::new(300.0, 0.0)).is_none());
+    }
+
+    #[test]
+    fn resumes_from_start_reversed() {
+        let points = vec![on(0.0, 0.0), off(30.0, 0.0), off(70.0, 0.0), on(100.0, 0.0)];
+        let mut data = session(vec![cubic(points, false)]);
+        let mut pen = PenTool::default();
+
+        let endpoint = find_open_endpoint(&data, Point::new(0.0, 0.0)).unwrap();
+        pen.resume_path(&mut data, endpoint);
+
+        assert!(data.paths.is_empty());
+        assert!(pen.is_drawing());
+        assert_eq!(
+            positions(&pen.current_path_points),
+            [(100.0, 0.0), (70.0, 0.0), (30.0, 0.0), (0.0, 0.0)]
+        );
+    }
+
+    #[test]
+    fn joins_onto_other_contour_end() {
+        let mut data = session(vec![cubic(vec![on(200.0, 0.0), on(300.0, 0.0)], false)]);
+        let mut pen = PenTool {
+            // A point with a handle dragged out of it
+            current_path_points: vec![on(0.0, 0.0), off(50.0, 50.0)],
+            drawing: true,
+            ..Default::default()
+        };
+
+        let endpoint = find_open_endpoint(&data, Point::new(300.0, 0.0)).unwrap();
+        pen.join_path(&mut data, endpoint);
+
+        assert!(!pen.is_drawing());
+        assert_eq!(data.paths.len(), 1);
+        let points = data.paths[0].points().to_vec();
+        assert!(!data.paths[0].is_closed());
+        // The joined contour is reversed so its clicked end meets ours,
+        // and the lone handle gets a retracted partner there
+        assert_eq!(
+            positions(&points),
+            [
+                (0.0, 0.0),
+                (50.0, 50.0),
+                (300.0, 0.0),
+                (300.0, 0.0),
+                (200.0, 0.0)
+            ]
+        );
+        assert_eq!(points.iter().filter(|pt| pt.is_off_curve()).count(), 2);
+    }
+
+    #[test]
+    fn closing_drag_pulls_smooth_handle() {
+        let mut data = session(Vec::new());
+        let mut pen = PenTool {
+            current_path_points: vec![on(0.0, 0.0), on(100.0, 0.0), on(100.0, 100.0)],
+            drawing: true,
+            ..Default::default()
+        };
+
+        // The start has a line out of it, so the closing handle stays
+        // on the line's tangent however the cursor moves
+        pen.begin_closing_drag();
+        pen.drag_closing_handles(Point::new(30.0, 40.0));
+        assert_eq!(pen.current_path_points[0].point, Point::new(-50.0, 0.0));
+        assert!(matches!(
+            pen.current_path_points[1].typ,
+            PointType::OnCurve { smooth: true }
+        ));
+
+        pen.close_path(&mut data);
+        let points = data.paths[0].points().to_vec();
+        assert!(data.paths[0].is_closed());
+        assert_eq!(points.len(), 5);
+        assert_eq!(points.iter().filter(|pt| pt.is_off_curve()).count(), 2);
+    }
+
+    #[test]
+    fn closing_drag_mirrors_through_start() {
+        let mut pen = PenTool {
+            current_path_points: vec![
+                off(-20.0, 0.0),
+                on(0.0, 0.0),
+                off(20.0, 0.0),
+                on(100.0, 0.0),
+                on(100.0, 100.0),
+            ],
+            drawing: true,
+            ..Default::default()
+        };
+
+        pen.begin_closing_drag();
+        pen.drag_closing_handles(Point::new(30.0, 10.0));
+        assert_eq!(
+            positions(&pen.current_path_points[..3]),
+            [(-30.0, -10.0), (0.0, 0.0), (30.0, 10.0)]
+        );
+    }
+}
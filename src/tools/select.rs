@@ -2,14 +2,26 @@
 // SPDX-License-Identifier: Apache-2.0
 
 //! Select tool for selecting and moving points
-
+//!
+//! Dragging on empty space draws a marquee rectangle; holding Alt
+//! draws a freeform lasso instead. Shift adds to the selection.
+//!
+//! Dragged points snap to the design grid, and to smart guides that
+//! align the grabbed point with other points, metrics lines, the advance
+//! width edges and neighbouring sorts.
+
+use crate::editing::session::GuideTargets;
 use crate::editing::{Drag, EditSession, EditType, MouseDelegate, MouseEvent, Selection};
+use crate::model::EntityId;
 use crate::path::Segment;
+use crate::settings;
 use crate::tools::{Tool, ToolId};
 use crate::theme;
 use kurbo::Affine;
+use kurbo::BezPath;
 use kurbo::Point;
 use kurbo::Rect;
+use kurbo::Shape;
 use kurbo::Vec2;
 use masonry::imaging::Painter;
 use masonry::peniko::Brush;
@@ -36,6 +48,12 @@ enum State {
     DraggingPoints {
         /// Last mouse position in design space
         last_pos: Point,
+        /// The point under the cursor when the drag started
+        anchor: EntityId,
+        /// Smart guide targets gathered at drag start
+        targets: GuideTargets,
+        /// Smart guides currently matched (glyph coordinates)
+        guides: Vec<kurbo::Line>,
     },
     /// Dragging a selected component
     DraggingComponent {
@@ -51,6 +69,15 @@ enum State {
         /// Whether shift is held (toggle mode)
         toggle: bool,
     },
+    /// Lasso selection (dragging out a freeform outline)
+    LassoSelect {
+        /// Selection before this lasso started (for shift+toggle mode)
+        previous_selection: Selection,
+        /// The lasso outline in screen space
+        points: Vec<Point>,
+        /// Whether shift is held (toggle mode)
+        toggle: bool,
+    },
 }
 
 // ===== Tool Implementation =====
@@ -116,19 +143,39 @@ impl Tool for SelectTool {
             }
         }
 
-        // Draw selection rectangle if in marquee mode
-        let State::MarqueeSelect { rect, .. } = &self.state else {
-            return;
-        };
-
-        
-        // Fill the selection rectangle with semi-transparent orange
-        painter.fill(rect, theme::selection::RECT_FILL).draw();
+        // Draw smart guides while dragging points
+        if let State::DraggingPoints { guides, .. } = &self.state {
+            let stroke = kurbo::Stroke::new(theme::smart_guide::WIDTH);
+            let brush = Brush::Solid(theme::smart_guide::LINE);
+            let offset = Vec2::new(session.active_sort_x_offset, 0.0);
+            for guide in guides {
+                let screen_line = kurbo::Line::new(
+                    session.viewport.to_screen(guide.p0 + offset),
+                    session.viewport.to_screen(guide.p1 + offset),
+                );
+                painter.stroke(&screen_line, &stroke, &brush).draw();
+            }
+        }
 
-        // Stroke the selection rectangle with dashed bright orange
+        // Draw the marquee rectangle or lasso outline while selecting
         let stroke = kurbo::Stroke::new(1.5).with_dashes(0.0, [4.0, 4.0]);
         let brush = Brush::Solid(theme::selection::RECT_STROKE);
-        painter.stroke(rect, &stroke, &brush).draw();
+        match &self.state {
+            State::MarqueeSelect { rect, .. } => {
+                // Fill the selection rectangle with semi-transparent orange
+                painter.fill(rect, theme::selection::RECT_FILL).draw();
+
+                // Stroke the selection rectangle with dashed bright orange
+                painter.stroke(rect, &stroke, &brush).draw();
+            }
+            State::LassoSelect { points, .. } => {
+                // Same look as the marquee, closed back to the start
+                let lasso = lasso_path(points);
+                painter.fill(&lasso, theme::selection::RECT_FILL).draw();
+                painter.stroke(&lasso, &stroke, &brush).draw();
+            }
+            _ => {}
+        }
     }
 
     fn edit_type(&self) -> Option<EditType> {
@@ -195,14 +242,32 @@ impl MouseDelegate for SelectTool {
             return;
         }
 
-        // Start marquee selection
-        self.start_marquee_selection(event, drag, data);
+        // Alt starts a freeform lasso, otherwise a marquee rectangle
+        if event.mods.alt {
+            self.start_lasso_selection(event, drag, data);
+        } else {
+            self.start_marquee_selection(event, drag, data);
+        }
     }
 
     fn left_drag_changed(&mut self, event: MouseEvent, drag: Drag, data: &mut EditSession) {
         match &mut self.state {
-            State::DraggingPoints { last_pos } => {
+            State::DraggingPoints {
+                last_pos,
+                anchor,
+                targets,
+                guides,
+            } => {
                 handle_dragging_points(event, data, last_pos);
+                let correction = snap_to_smart_guides(data, *anchor, targets, guides);
+                if correction != Vec2::ZERO {
+                    if event.mods.alt {
+                        data.move_selection_independent(correction);
+                    } else {
+                        data.move_selection(correction);
+                    }
+                    *last_pos += correction;
+                }
             }
             State::DraggingComponent { last_pos } => {
                 handle_dragging_component(event, data, last_pos);
@@ -214,6 +279,13 @@ impl MouseDelegate for SelectTool {
             } => {
                 handle_marquee_selection(drag, data, previous_selection, rect, *toggle);
             }
+            State::LassoSelect {
+                previous_selection,
+                points,
+                toggle,
+            } => {
+                handle_lasso_selection(drag, data, previous_selection, points, *toggle);
+            }
             State::Ready => {}
         }
     }
@@ -235,6 +307,14 @@ impl MouseDelegate for SelectTool {
                 // Update coordinate selection after marquee
                 data.update_coord_selection();
             }
+            State::LassoSelect { .. } => {
+                tracing::debug!(
+                    "Select tool: finished lasso selection, \
+                     selected {} points",
+                    data.selection.len()
+                );
+                data.update_coord_selection();
+            }
             State::Ready => {}
         }
 
@@ -258,9 +338,13 @@ impl MouseDelegate for SelectTool {
     }
 
     fn cancel(&mut self, data: &mut EditSession) {
-        // If we were in marquee mode, restore the previous selection
+        // If we were in marquee or lasso mode, restore the previous
+        // selection
         if let State::MarqueeSelect {
             previous_selection, ..
+        }
+        | State::LassoSelect {
+            previous_selection, ..
         } = &self.state
         {
             data.selection = previous_selection.clone();
@@ -329,8 +413,16 @@ impl SelectTool {
 
         // We're dragging a selected point
         let design_pos = data.viewport.screen_to_design(event.pos);
+        let targets = if settings::smart_guides::ENABLED {
+            data.smart_guide_targets()
+        } else {
+            GuideTargets::default()
+        };
         self.state = State::DraggingPoints {
             last_pos: design_pos,
+            anchor: hit.entity,
+            targets,
+            guides: Vec::new(),
         };
         tracing::debug!(
             "Select tool: started dragging {} selected point(s)",
@@ -382,6 +474,19 @@ impl SelectTool {
             toggle: event.mods.shift,
         };
     }
+
+    /// Start lasso selection
+    fn start_lasso_selection(&mut self, event: MouseEvent, drag: Drag, data: &mut EditSession) {
+        tracing::debug!(
+            "Select tool: started lasso selection, toggle={}",
+            event.mods.shift
+        );
+        self.state = State::LassoSelect {
+            previous_selection: data.selection.clone(),
+            points: vec![drag.start, drag.current],
+            toggle: event.mods.shift,
+        };
+    }
 }
 
 // ===== Drag Handling Helpers =====
@@ -473,6 +578,35 @@ fn find_snap_correction(data: &EditSession) -> Vec2 {
     Vec2::ZERO
 }
 
+/// Snap the grabbed point to smart guides, after grid snapping
+///
+/// Guides win over the grid on whichever axis they catch. Updates the
+/// matched guides for painting and returns the correction to apply to
+/// the selection.
+fn snap_to_smart_guides(
+    data: &EditSession,
+    anchor: EntityId,
+    targets: &GuideTargets,
+    guides: &mut Vec<kurbo::Line>,
+) -> Vec2 {
+    guides.clear();
+
+    let Some(anchor_pos) = data
+        .paths
+        .iter()
+        .flat_map(|path| path.points().iter())
+        .find(|pt| pt.id == anchor)
+        .map(|pt| pt.point)
+    else {
+        return Vec2::ZERO;
+    };
+
+    let threshold = settings::smart_guides::THRESHOLD / data.viewport.zoom;
+    let (correction, matched) = targets.snap(anchor_pos, threshold);
+    *guides = matched;
+    correction
+}
+
 /// Handle dragging component (during drag)
 fn handle_dragging_component(event: MouseEvent, data: &mut EditSession, last_pos: &mut Point) {
     // Convert current mouse position to design space
@@ -503,65 +637,73 @@ fn handle_marquee_selection(
     update_selection_for_marquee(data, previous_selection, *rect, toggle);
 }
 
-// ===== Marquee Selection Helper =====
+/// Handle lasso selection (during drag)
+fn handle_lasso_selection(
+    drag: Drag,
+    data: &mut EditSession,
+    previous_selection: &Selection,
+    points: &mut Vec<Point>,
+    toggle: bool,
+) {
+    // Extend the lasso outline
+    points.push(drag.current);
+
+    // Update selection based on points inside the closed outline
+    let lasso = lasso_path(points);
+    update_selection_in_region(data, previous_selection, toggle, |pos| {
+        lasso.winding(pos) != 0
+    });
+}
+
+/// Close the lasso outline into a path for painting and hit testing
+fn lasso_path(points: &[Point]) -> BezPath {
+    let mut path = BezPath::new();
+    let mut points = points.iter();
+    if let Some(&first) = points.next() {
+        path.move_to(first);
+        for &pt in points {
+            path.line_to(pt);
+        }
+        path.close_path();
+    }
+    path
+}
+
+// ===== Region Selection Helpers =====
 
 /// Update selection based on points in the marquee rectangle
-///
-/// This filters all points to find those within the rectangle (in screen
-/// space), and applies toggle logic if shift is held.
 fn update_selection_for_marquee(
     data: &mut EditSession,
     previous_selection: &Selection,
     rect: Rect,
     toggle: bool,
 ) {
-    use crate::path::Path;
-    use kurbo::Point;
+    update_selection_in_region(data, previous_selection, toggle, |pos| rect.contains(pos));
+}
 
-    // Collect all points that are within the selection rectangle
+/// Update selection based on points inside a screen-space region
+///
+/// This filters all points to find those the region contains (in screen
+/// space), and applies toggle logic if shift is held.
+fn update_selection_in_region(
+    data: &mut EditSession,
+    previous_selection: &Selection,
+    toggle: bool,
+    contains: impl Fn(Point) -> bool,
+) {
+    // Collect all points that are within the region
     let mut new_selection = Selection::new();
 
     // Get the active sort's x-offset to apply to hit-testing
     let offset_x = data.active_sort_x_offset;
 
-    for path in data.paths.iter() {
-        match path {
-            Path::Cubic(cubic) => {
-                for pt in cubic.points.iter() {
-                    // Apply x-offset in design space before converting to screen
-                    let offset_point = Point::new(pt.point.x + offset_x, pt.point.y);
-                    let screen_pos = data.viewport.to_screen(offset_point);
-
-                    // Check if point is inside the rectangle
-                    if rect.contains(screen_pos) {
-                        new_selection.insert(pt.id);
-                    }
-                }
-            }
-            Path::Quadratic(quadratic) => {
-                for pt in quadratic.points.iter() {
-                    // Apply x-offset in design space before converting to screen
-                    let offset_point = Point::new(pt.point.x + offset_x, pt.point.y);
-                    let screen_pos = data.viewport.to_screen(offset_point);
-
-                    // Check if point is inside the rectangle
-                    if rect.contains(screen_pos) {
-                        new_selection.insert(pt.id);
-                    }
-                }
-            }
-            Path::Hyper(hyper) => {
-                for pt in hyper.points.iter() {
-                    // Apply x-offset in design space before converting to screen
-                    let offset_point = Point::new(pt.point.x + offset_x, pt.point.y);
-                    let screen_pos = data.viewport.to_screen(offset_point);
-
-                    // Check if point is inside the rectangle
-                    if rect.contains(screen_pos) {
-                        new_selection.insert(pt.id);
-                    }
-                }
-            }
+    for pt in data.paths.iter().flat_map(|path| path.points().iter()) {
+        // Apply x-offset in design space before converting to screen
+        let offset_point = Point::new(pt.point.x + offset_x, pt.point.y);
+        let screen_pos = data.viewport.to_screen(offset_point);
+
+        if contains(screen_pos) {
+            new_selection.insert(pt.id);
         }
     }
 
@@ -574,7 +716,7 @@ fn update_selection_for_marquee(
         }
         data.selection = result;
     } else {
-        // Normal mode: replace selection with points in rectangle
+        // Normal mode: replace selection with points in region
         data.selection = new_selection;
     }
 }
@@ -4,9 +4,15 @@
 //! Shapes tool for creating geometric primitives
 //!
 //! This tool provides a unified interface for creating various shapes:
-//! - Rectangle
+//! - Rectangle and rounded rectangle (corner radius)
 //! - Ellipse
-//! - (Future: RoundedRect, Star, etc.)
+//! - Regular polygon (side count) and star (point count, inner radius)
+//! - Stroke line (a straight bar of fixed width)
+//!
+//! Shift constrains the drag (square bounds, or 45° steps for stroke
+//! lines) and Alt draws from the center. Every shape is emitted as a
+//! closed counter-clockwise contour, the PostScript direction for
+//! outer contours.
 //!
 //! Ported from Runebender Druid implementation.
 
@@ -14,12 +20,16 @@ use crate::editing::{EditSession, EditType, MouseDelegate, MouseEvent};
 use crate::model::EntityId;
 use crate::path::{CubicPath, Path, PathPoint, PathPoints, PointType};
 use crate::tools::{Tool, ToolId};
-use kurbo::{Affine, Point, Rect, Shape};
+use kurbo::{Affine, BezPath, PathEl, Point, Rect, Shape, Vec2};
 use masonry::imaging::Painter;
 use masonry::peniko::Brush;
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
 use std::sync::Arc;
 use tracing;
 
+/// Handle length factor for approximating a quarter circle with a cubic
+const KAPPA: f64 = 0.552_284_749_8;
+
 // ===== Shape Type =====
 
 /// Type of shape being drawn
@@ -27,8 +37,52 @@ use tracing;
 pub enum ShapeType {
     /// Rectangle shape
     Rectangle,
+    /// Rectangle with rounded corners
+    RoundedRect,
     /// Ellipse/circle shape
     Ellipse,
+    /// Regular polygon
+    Polygon,
+    /// Star with alternating outer and inner points
+    Star,
+    /// Straight bar of fixed width along the drag
+    StrokeLine,
+}
+
+/// Parameters for the parametric shapes, edited in the shapes toolbar
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShapeOptions {
+    /// Corner radius of rounded rectangles (design units)
+    pub corner_radius: f64,
+    /// Number of sides (polygon) or points (star)
+    pub sides: usize,
+    /// Star inner radius as a fraction of the outer radius
+    pub inner_ratio: f64,
+    /// Width of stroke lines (design units)
+    pub stroke_width: f64,
+}
+
+impl Default for ShapeOptions {
+    fn default() -> Self {
+        Self {
+            corner_radius: 40.0,
+            sides: 5,
+            inner_ratio: 0.5,
+            stroke_width: 80.0,
+        }
+    }
+}
+
+impl ShapeOptions {
+    /// Clamp every option into its usable range
+    fn clamped(self) -> Self {
+        Self {
+            corner_radius: self.corner_radius.max(0.0),
+            sides: self.sides.clamp(3, 64),
+            inner_ratio: self.inner_ratio.clamp(0.05, 0.95),
+            stroke_width: self.stroke_width.max(1.0),
+        }
+    }
 }
 
 // ===== Gesture State =====
@@ -54,18 +108,24 @@ enum GestureState {
 pub struct ShapesTool {
     /// Current shape type being drawn
     shape_type: ShapeType,
+    /// Parameters for rounded rects, polygons, stars and stroke lines
+    options: ShapeOptions,
     /// Drawing gesture state
     gesture: GestureState,
     /// Whether shift key is locked for constraining shapes
     shift_locked: bool,
+    /// Whether alt key is held to draw from the center
+    centered: bool,
 }
 
 impl Default for ShapesTool {
     fn default() -> Self {
         Self {
             shape_type: ShapeType::Rectangle,
+            options: ShapeOptions::default(),
             gesture: GestureState::Ready,
             shift_locked: false,
+            centered: false,
         }
     }
 }
@@ -79,8 +139,8 @@ impl Tool for ShapesTool {
 
     fn paint(&mut self, painter: &mut Painter<'_>, session: &EditSession, _transform: &Affine) {
         // Paint preview of the shape being drawn
-        if let Some(rect) = self.current_drag_rect(session) {
-            self.paint_shape_preview(painter, rect);
+        if let GestureState::Begun { start, current } = self.gesture {
+            self.paint_shape_preview(painter, session, start, current);
         }
     }
 
@@ -144,26 +204,17 @@ impl MouseDelegate for ShapesTool {
         if let GestureState::Begun { start, .. } = self.gesture {
             let current = data.viewport.screen_to_design(drag.current);
 
-            // Note: shift_locked is controlled by keyboard events, not mouse events
-            // The final shape uses whatever shift state was set by keyboard
-
-            // Get the final rectangle
-            let (p0, p1) = self.pts_for_rect(start, current);
-            let rect = Rect::from_points(p0, p1);
-
-            // Create path based on shape type
-            let path = match self.shape_type {
-                ShapeType::Rectangle => self.make_rect_path(rect),
-                ShapeType::Ellipse => self.make_ellipse_path(rect),
-            };
-
-            // Add to session (Arc pattern - clone, modify, reassign)
-            let mut paths = (*data.paths).clone();
-            paths.push(path);
-            data.paths = Arc::new(paths);
+            // Note: shift/alt state comes from keyboard events, not
+            // mouse events. Degenerate (zero-size) drags add nothing.
+            if let Some(outline) = self.outline(start, current) {
+                // Add to session (Arc pattern - clone, modify, reassign)
+                let mut paths = (*data.paths).clone();
+                paths.push(path_from_outline(&outline));
+                data.paths = Arc::new(paths);
+                tracing::debug!("Shapes tool: created {:?}", self.shape_type);
+            }
 
             self.gesture = GestureState::Finished;
-            tracing::debug!("Shapes tool: created {:?}", self.shape_type);
         }
     }
 
@@ -191,194 +242,365 @@ impl ShapesTool {
         self.shape_type
     }
 
+    /// Get the shape parameters
+    pub fn options(&self) -> ShapeOptions {
+        self.options
+    }
+
+    /// Set the shape parameters (clamped to usable ranges)
+    pub fn set_options(&mut self, options: ShapeOptions) {
+        self.options = options.clamped();
+    }
+
     /// Set the shift-lock state (for keyboard-driven constraint toggling)
     pub fn set_shift_locked(&mut self, locked: bool) {
         self.shift_locked = locked;
     }
 
-    /// Get points for rectangle, applying shift-lock constraint if needed
-    fn pts_for_rect(&self, start: Point, current: Point) -> (Point, Point) {
-        if self.shift_locked {
-            // Constrain to square/circle
-            let delta = current - start;
-            let size = delta.x.abs().max(delta.y.abs());
-            let constrained = Point::new(
-                start.x + size * delta.x.signum(),
-                start.y + size * delta.y.signum(),
-            );
-            tracing::debug!(
-                "pts_for_rect: CONSTRAINED - start={:?}, current={:?}, delta={:?}, size={}, constrained={:?}",
-                start,
-                current,
-                delta,
-                size,
-                constrained
-            );
-            (start, constrained)
-        } else {
-            tracing::debug!(
-                "pts_for_rect: unconstrained - start={:?}, current={:?}",
-                start,
-                current
-            );
-            (start, current)
-        }
+    /// Set whether shapes are drawn from their center (Alt held)
+    pub fn set_centered(&mut self, centered: bool) {
+        self.centered = centered;
     }
 
-    /// Get the current drag rectangle in screen space for preview
-    fn current_drag_rect(&self, session: &EditSession) -> Option<Rect> {
-        if let GestureState::Begun { start, current } = self.gesture {
-            let (p0, p1) = self.pts_for_rect(start, current);
-
-            // Convert to screen space
-            let screen_p0 = session.viewport.to_screen(p0);
-            let screen_p1 = session.viewport.to_screen(p1);
+    /// Build the design-space outline for a drag from `start` to
+    /// `current`, or None if the drag is degenerate
+    fn outline(&self, start: Point, current: Point) -> Option<BezPath> {
+        if self.shape_type == ShapeType::StrokeLine {
+            let (p0, p1) = line_endpoints(start, current, self.shift_locked, self.centered);
+            return stroke_line_outline(p0, p1, self.options.stroke_width);
+        }
 
-            Some(Rect::from_points(screen_p0, screen_p1))
-        } else {
-            None
+        let rect = shape_bounds(start, current, self.shift_locked, self.centered);
+        if rect.width() <= 0.0 || rect.height() <= 0.0 {
+            return None;
         }
+
+        let opts = self.options;
+        Some(match self.shape_type {
+            ShapeType::Rectangle => rect.to_path(0.1),
+            ShapeType::RoundedRect => rounded_rect_outline(rect, opts.corner_radius),
+            ShapeType::Ellipse => ellipse_outline(rect),
+            ShapeType::Polygon => star_outline(rect, opts.sides, 1.0),
+            ShapeType::Star => star_outline(rect, opts.sides, opts.inner_ratio),
+            ShapeType::StrokeLine => unreachable!("handled above"),
+        })
     }
 
     /// Paint shape preview during drag
-    fn paint_shape_preview(&self, painter: &mut Painter<'_>, rect: Rect) {
+    fn paint_shape_preview(
+        &self,
+        painter: &mut Painter<'_>,
+        session: &EditSession,
+        start: Point,
+        current: Point,
+    ) {
         let brush = Brush::Solid(crate::theme::tool_preview::LINE_COLOR);
 
-        match self.shape_type {
-            ShapeType::Rectangle => {
-                // Draw dashed rectangle outline
-                let stroke = kurbo::Stroke::new(crate::theme::tool_preview::LINE_WIDTH)
-                    .with_dashes(
-                        crate::theme::tool_preview::LINE_DASH_OFFSET,
-                        crate::theme::tool_preview::LINE_DASH,
-                    );
-                painter.stroke(&rect, &stroke, &brush).draw();
-            }
-            ShapeType::Ellipse => {
-                // Draw dashed ellipse outline
-                let ellipse = kurbo::Ellipse::from_rect(rect);
-                let stroke = kurbo::Stroke::new(crate::theme::tool_preview::LINE_WIDTH)
-                    .with_dashes(
-                        crate::theme::tool_preview::LINE_DASH_OFFSET,
-                        crate::theme::tool_preview::LINE_DASH,
-                    );
-                painter.stroke(&ellipse, &stroke, &brush).draw();
-            }
+        // Draw the dashed outline of the shape being drawn
+        if let Some(outline) = self.outline(start, current) {
+            let stroke = kurbo::Stroke::new(crate::theme::tool_preview::LINE_WIDTH).with_dashes(
+                crate::theme::tool_preview::LINE_DASH_OFFSET,
+                crate::theme::tool_preview::LINE_DASH,
+            );
+            let screen_outline = session.viewport.affine() * outline;
+            painter.stroke(&screen_outline, &stroke, &brush).draw();
         }
 
-        // Draw corner dots
+        // Draw dots at the drag start and end
         let dot_radius = crate::theme::tool_preview::DOT_RADIUS;
-        for &pt in &[rect.origin(), rect.origin() + rect.size().to_vec2()] {
-            let circle = kurbo::Circle::new(pt, dot_radius);
+        for pt in [start, current] {
+            let circle = kurbo::Circle::new(session.viewport.to_screen(pt), dot_radius);
             painter.fill(&circle, &brush).draw();
         }
     }
+}
 
-    /// Create a rectangle path from a rect
-    fn make_rect_path(&self, rect: Rect) -> Path {
-        // Rectangle has 4 corners, all on-curve (line points)
-        let p0 = rect.origin();
-        let p1 = Point::new(rect.max_x(), rect.min_y());
-        let p2 = Point::new(rect.max_x(), rect.max_y());
-        let p3 = Point::new(rect.min_x(), rect.max_y());
-
-        // Create path points (all on-curve corners for straight lines)
-        let points = vec![
-            PathPoint {
-                id: EntityId::next(),
-                point: p0,
-                typ: PointType::OnCurve { smooth: false },
-            },
-            PathPoint {
-                id: EntityId::next(),
-                point: p1,
-                typ: PointType::OnCurve { smooth: false },
-            },
-            PathPoint {
-                id: EntityId::next(),
-                point: p2,
-                typ: PointType::OnCurve { smooth: false },
-            },
-            PathPoint {
-                id: EntityId::next(),
-                point: p3,
-                typ: PointType::OnCurve { smooth: false },
-            },
-        ];
+// ===== Geometry =====
+
+/// Bounds of a box-like shape, applying the constrain (square) and
+/// centered (drag from center) modifiers
+fn shape_bounds(start: Point, current: Point, constrain: bool, centered: bool) -> Rect {
+    let mut delta = current - start;
+    if constrain {
+        let size = delta.x.abs().max(delta.y.abs());
+        delta = Vec2::new(size * delta.x.signum(), size * delta.y.signum());
+    }
+    if centered {
+        Rect::from_points(start - delta, start + delta)
+    } else {
+        Rect::from_points(start, start + delta)
+    }
+}
+
+/// Endpoints of a stroke line, applying the constrain (45° steps) and
+/// centered (line through the start point) modifiers
+fn line_endpoints(start: Point, current: Point, constrain: bool, centered: bool) -> (Point, Point) {
+    let mut delta = current - start;
+    if constrain {
+        let angle = (delta.atan2() / FRAC_PI_4).round() * FRAC_PI_4;
+        delta = Vec2::from_angle(angle) * delta.hypot();
+    }
+    if centered {
+        (start - delta, start + delta)
+    } else {
+        (start, start + delta)
+    }
+}
 
-        let path_points = PathPoints::from_vec(points);
-        let cubic = CubicPath {
-            points: path_points,
-            closed: true,
-            id: EntityId::next(),
+/// Rectangle with quarter-circle corners, radius clamped to fit
+fn rounded_rect_outline(rect: Rect, radius: f64) -> BezPath {
+    let r = radius.min(rect.width() / 2.0).min(rect.height() / 2.0);
+    if r <= 0.0 {
+        return rect.to_path(0.1);
+    }
+    let k = r * KAPPA;
+    let (x0, y0, x1, y1) = (rect.x0, rect.y0, rect.x1, rect.y1);
+
+    let mut bez = BezPath::new();
+    bez.move_to((x0 + r, y0));
+    line_to_if_moved(&mut bez, Point::new(x1 - r, y0));
+    bez.curve_to((x1 - r + k, y0), (x1, y0 + r - k), (x1, y0 + r));
+    line_to_if_moved(&mut bez, Point::new(x1, y1 - r));
+    bez.curve_to((x1, y1 - r + k), (x1 - r + k, y1), (x1 - r, y1));
+    line_to_if_moved(&mut bez, Point::new(x0 + r, y1));
+    bez.curve_to((x0 + r - k, y1), (x0, y1 - r + k), (x0, y1 - r));
+    line_to_if_moved(&mut bez, Point::new(x0, y0 + r));
+    bez.curve_to((x0, y0 + r - k), (x0 + r - k, y0), (x0 + r, y0));
+    bez.close_path();
+    bez
+}
+
+/// Ellipse inscribed in `rect`, with on-curve points at the extrema
+fn ellipse_outline(rect: Rect) -> BezPath {
+    let c = rect.center();
+    let (rx, ry) = (rect.width() / 2.0, rect.height() / 2.0);
+    let (kx, ky) = (rx * KAPPA, ry * KAPPA);
+
+    let mut bez = BezPath::new();
+    bez.move_to((c.x + rx, c.y));
+    bez.curve_to((c.x + rx, c.y + ky), (c.x + kx, c.y + ry), (c.x, c.y + ry));
+    bez.curve_to((c.x - kx, c.y + ry), (c.x - rx, c.y + ky), (c.x - rx, c.y));
+    bez.curve_to((c.x - rx, c.y - ky), (c.x - kx, c.y - ry), (c.x, c.y - ry));
+    bez.curve_to((c.x + kx, c.y - ry), (c.x + rx, c.y - ky), (c.x + rx, c.y));
+    bez.close_path();
+    bez
+}
+
+/// Add a line segment unless it would have zero length
+fn line_to_if_moved(bez: &mut BezPath, to: Point) {
+    let current = bez.elements().last().and_then(|el| el.end_point());
+    if current.is_none_or(|p| p.distance(to) > 1e-9) {
+        bez.line_to(to);
+    }
+}
+
+/// Star (or, with `inner_ratio` 1.0, regular polygon) inscribed in the
+/// ellipse of `rect`, with the first point at the top
+fn star_outline(rect: Rect, points: usize, inner_ratio: f64) -> BezPath {
+    let center = rect.center();
+    let (rx, ry) = (rect.width() / 2.0, rect.height() / 2.0);
+    let is_star = inner_ratio < 1.0;
+    let vertex_count = if is_star { points * 2 } else { points };
+    let step = 2.0 * PI / vertex_count as f64;
+
+    let mut bez = BezPath::new();
+    for i in 0..vertex_count {
+        let scale = if is_star && i % 2 == 1 {
+            inner_ratio
+        } else {
+            1.0
         };
+        let angle = FRAC_PI_2 + i as f64 * step;
+        let pt = Point::new(
+            center.x + rx * scale * angle.cos(),
+            center.y + ry * scale * angle.sin(),
+        );
+        if i == 0 {
+            bez.move_to(pt);
+        } else {
+            bez.line_to(pt);
+        }
+    }
+    bez.close_path();
+    bez
+}
+
+/// Bar of the given width centered on the segment `p0`–`p1`
+fn stroke_line_outline(p0: Point, p1: Point, width: f64) -> Option<BezPath> {
+    let dir = p1 - p0;
+    if dir.hypot() < 1e-9 {
+        return None;
+    }
+    let offset = dir.normalize().turn_90() * (width / 2.0);
+
+    let mut bez = BezPath::new();
+    bez.move_to(p0 - offset);
+    bez.line_to(p1 - offset);
+    bez.line_to(p1 + offset);
+    bez.line_to(p0 + offset);
+    bez.close_path();
+    Some(bez)
+}
 
-        Path::Cubic(cubic)
-    }
-
-    /// Create an ellipse path from a rect (bounding box)
-    fn make_ellipse_path(&self, rect: Rect) -> Path {
-        // Use kurbo's ellipse-to-path conversion
-        let ellipse = kurbo::Ellipse::from_rect(rect);
-        let bez_path = ellipse.to_path(0.1); // tolerance for curve approximation
-
-        // Convert BezPath to PathPoints
-        let mut points = Vec::new();
-        let mut off_curve_buffer: Vec<Point> = Vec::new();
-
-        for el in bez_path.elements() {
-            match el {
-                kurbo::PathEl::MoveTo(p) => {
-                    // First point is typically a move - create as smooth on-curve point
-                    points.push(PathPoint {
-                        id: EntityId::next(),
-                        point: *p,
-                        typ: PointType::OnCurve { smooth: true },
-                    });
-                }
-                kurbo::PathEl::LineTo(p) => {
-                    points.push(PathPoint {
-                        id: EntityId::next(),
-                        point: *p,
-                        typ: PointType::OnCurve { smooth: false },
-                    });
-                }
-                kurbo::PathEl::QuadTo(_p1, _p2) => {
-                    // Quadratics not typically generated by ellipse.to_path()
-                    // but handle just in case
-                }
-                kurbo::PathEl::CurveTo(p1, p2, p3) => {
-                    // Add off-curve points
-                    off_curve_buffer.push(*p1);
-                    off_curve_buffer.push(*p2);
-                    // Add on-curve point
-                    // Flush off-curve points first
-                    for off_p in off_curve_buffer.drain(..) {
-                        points.push(PathPoint {
-                            id: EntityId::next(),
-                            point: off_p,
-                            typ: PointType::OffCurve { auto: false },
-                        });
-                    }
-                    points.push(PathPoint {
-                        id: EntityId::next(),
-                        point: *p3,
-                        typ: PointType::OnCurve { smooth: true },
-                    });
-                }
-                kurbo::PathEl::ClosePath => {
-                    // Path will be marked as closed
-                }
+// ===== Path Conversion =====
+
+/// Convert a closed shape outline into an editable cubic path
+///
+/// The outline is made counter-clockwise, the closing on-curve point
+/// that duplicates the start is dropped, and on-curve points with a
+/// continuous tangent are marked smooth.
+fn path_from_outline(outline: &BezPath) -> Path {
+    let outline = if outline.area() < 0.0 {
+        outline.reverse_subpaths()
+    } else {
+        outline.clone()
+    };
+
+    let on_curve = |point| PathPoint {
+        id: EntityId::next(),
+        point,
+        typ: PointType::OnCurve { smooth: false },
+    };
+    let off_curve = |point| PathPoint {
+        id: EntityId::next(),
+        point,
+        typ: PointType::OffCurve { auto: false },
+    };
+
+    let mut points = Vec::new();
+    for el in outline.elements() {
+        match *el {
+            PathEl::MoveTo(p) | PathEl::LineTo(p) => points.push(on_curve(p)),
+            PathEl::QuadTo(p1, p2) => {
+                points.push(off_curve(p1));
+                points.push(on_curve(p2));
+            }
+            PathEl::CurveTo(p1, p2, p3) => {
+                points.push(off_curve(p1));
+                points.push(off_curve(p2));
+                points.push(on_curve(p3));
             }
+            PathEl::ClosePath => {}
         }
+    }
 
-        let path_points = PathPoints::from_vec(points);
-        let cubic = CubicPath {
-            points: path_points,
-            closed: true,
-            id: EntityId::next(),
-        };
+    // Shapes that curve back to the start repeat the start point
+    if points.len() > 1
+        && let (Some(first), Some(last)) = (points.first(), points.last())
+        && last.is_on_curve()
+        && last.point.distance(first.point) < 1e-9
+    {
+        points.pop();
+    }
+
+    mark_smooth_points(&mut points);
+
+    // CubicPath convention: the first point of a closed path is last
+    points.rotate_left(1);
+
+    Path::Cubic(CubicPath::new(PathPoints::from_vec(points), true))
+}
+
+/// Mark on-curve points smooth where the incoming and outgoing
+/// directions line up and at least one side is a curve
+fn mark_smooth_points(points: &mut [PathPoint]) {
+    let len = points.len();
+    for i in 0..len {
+        if !points[i].is_on_curve() {
+            continue;
+        }
+        let prev = &points[(i + len - 1) % len];
+        let next = &points[(i + 1) % len];
+        if prev.is_on_curve() && next.is_on_curve() {
+            continue;
+        }
+
+        let incoming = points[i].point - prev.point;
+        let outgoing = next.point - points[i].point;
+        let (a, b) = (incoming.hypot(), outgoing.hypot());
+        if a < 1e-9 || b < 1e-9 {
+            continue;
+        }
+        let aligned =
+            incoming.cross(outgoing).abs() / (a * b) < 1e-6 && incoming.dot(outgoing) > 0.0;
+        points[i].typ = PointType::OnCurve { smooth: aligned };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tool(shape_type: ShapeType) -> ShapesTool {
+        let mut tool = ShapesTool::default();
+        tool.set_shape_type(shape_type);
+        tool
+    }
+
+    fn counts(path: &Path) -> (usize, usize) {
+        let on = path.points().iter().filter(|pt| pt.is_on_curve()).count();
+        (on, path.points().len() - on)
+    }
+
+    #[test]
+    fn all_shapes_are_counter_clockwise() {
+        let shapes = [
+            ShapeType::Rectangle,
+            ShapeType::RoundedRect,
+            ShapeType::Ellipse,
+            ShapeType::Polygon,
+            ShapeType::Star,
+            ShapeType::StrokeLine,
+        ];
+        // Drag right-to-left and top-to-bottom to exercise reversal
+        for shape in shapes {
+            let outline = tool(shape)
+                .outline(Point::new(300.0, 400.0), Point::new(0.0, 0.0))
+                .unwrap();
+            let path = path_from_outline(&outline);
+            assert!(path.to_bezpath().area() > 0.0, "{shape:?} is clockwise");
+        }
+    }
+
+    #[test]
+    fn point_counts_match_shape_parameters() {
+        let start = Point::new(0.0, 0.0);
+        let end = Point::new(200.0, 100.0);
+        let path = |t: ShapesTool| path_from_outline(&t.outline(start, end).unwrap());
+
+        assert_eq!(counts(&path(tool(ShapeType::Rectangle))), (4, 0));
+        assert_eq!(counts(&path(tool(ShapeType::RoundedRect))), (8, 8));
+        assert_eq!(counts(&path(tool(ShapeType::Ellipse))), (4, 8));
+        assert_eq!(counts(&path(tool(ShapeType::Polygon))), (5, 0));
+        assert_eq!(counts(&path(tool(ShapeType::Star))), (10, 0));
+        assert_eq!(counts(&path(tool(ShapeType::StrokeLine))), (4, 0));
+    }
+
+    #[test]
+    fn rounded_rect_corners_join_smoothly() {
+        let outline = rounded_rect_outline(Rect::new(0.0, 0.0, 200.0, 100.0), 20.0);
+        let path = path_from_outline(&outline);
+        assert!(
+            path.points()
+                .iter()
+                .filter(|pt| pt.is_on_curve())
+                .all(|pt| pt.typ == PointType::OnCurve { smooth: true })
+        );
+    }
+
+    #[test]
+    fn modifiers_constrain_and_center() {
+        let start = Point::new(100.0, 100.0);
+        let current = Point::new(160.0, 120.0);
+
+        let square = shape_bounds(start, current, true, false);
+        assert_eq!(square, Rect::new(100.0, 100.0, 160.0, 160.0));
+
+        let centered = shape_bounds(start, current, false, true);
+        assert_eq!(centered.center(), start);
 
-        Path::Cubic(cubic)
+        let (p0, p1) = line_endpoints(start, Point::new(200.0, 110.0), true, false);
+        assert_eq!(p0, start);
+        assert!((p1.y - start.y).abs() < 1e-9);
     }
 }
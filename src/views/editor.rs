@@ -29,10 +29,13 @@ use crate::components::{
     text_direction_toolbar_view, transform_panel, workspace_toolbar_view,
 };
 use crate::data::AppState;
+use crate::editing::session::{AlignEdge, NumericTransform, TransformScope};
+use crate::model::metrics_keys::{MetricDrift, MetricSide, MetricsKeys};
 use crate::model::read_workspace;
+use crate::sort::paragraph::ParagraphSettings;
 use crate::theme;
 use crate::theme::size::{UI_PANEL_GAP, UI_PANEL_MARGIN};
-use crate::tools::shapes::ShapeType;
+use crate::tools::shapes::{ShapeOptions, ShapeType};
 use crate::tools::{ToolBox, ToolId};
 
 // ===== Editor Tab View =====
@@ -51,12 +54,13 @@ pub fn editor_tab(state: &mut AppState) -> impl WidgetView<AppState> + use<> {
         .unwrap_or_else(|| "".to_string());
     let session_arc = Arc::new(session.clone());
 
-    // Get current shape type if shapes tool is selected
-    let current_shape = if let ToolBox::Shapes(shapes_tool) = &session.current_tool {
-        shapes_tool.shape_type()
-    } else {
-        ShapeType::Rectangle // Default
-    };
+    // Get current shape type and options if shapes tool is selected
+    let (current_shape, shape_options) =
+        if let ToolBox::Shapes(shapes_tool) = &session.current_tool {
+            (shapes_tool.shape_type(), shapes_tool.options())
+        } else {
+            (ShapeType::Rectangle, ShapeOptions::default()) // Default
+        };
 
     // Get current text direction
     let current_text_direction = session.text_direction;
@@ -89,19 +93,40 @@ pub fn editor_tab(state: &mut AppState) -> impl WidgetView<AppState> + use<> {
         // Foreground: tool sub-toolbar (Shapes / Text direction);
         // the tool palette itself lives in the top row
         transformed(if show_shapes_toolbar {
-            Either::A(shapes_toolbar_view(
-                current_shape,
-                |state: &mut AppState, shape_type| {
-                    state.set_shape_type(shape_type);
-                },
-            ))
+            Either::A(
+                flex_col((
+                    shapes_toolbar_view(
+                        current_shape,
+                        |state: &mut AppState, shape_type| {
+                            state.set_shape_type(shape_type);
+                        },
+                    ),
+                    shape_options_strip(current_shape, shape_options),
+                ))
+                .cross_axis_alignment(xilem::view::CrossAxisAlignment::End)
+                .gap(UI_PANEL_GAP.px()),
+            )
         } else if show_text_direction_toolbar {
-            Either::B(Either::A(text_direction_toolbar_view(
-                current_text_direction,
-                |state: &mut AppState, direction| {
-                    state.set_text_direction(direction);
-                },
-            )))
+            Either::B(Either::A(
+                flex_col((
+                    text_direction_toolbar_view(
+                        current_text_direction,
+                        |state: &mut AppState, direction| {
+                            state.set_text_direction(direction);
+                        },
+                        |state: &mut AppState, text| {
+                            state.fill_text_buffer(text);
+                        },
+                    ),
+                    spacing_strip(
+                        session.spacing_mode,
+                        session.spacing_all_masters,
+                    ),
+                    paragraph_strip(session.paragraph),
+                ))
+                .cross_axis_alignment(xilem::view::CrossAxisAlignment::End)
+                .gap(UI_PANEL_GAP.px()),
+            ))
         } else {
             Either::B(Either::B(label("")))
         })
@@ -134,12 +159,25 @@ pub fn editor_tab(state: &mut AppState) -> impl WidgetView<AppState> + use<> {
         // Right side: transform panel
         transformed(if session.panels_visible {
             let has_selection = !session.selection.is_empty();
-            let contour_count = session.paths.len();
-            Either::A(transform_panel(
-                has_selection,
-                contour_count,
-                apply_transform,
-            ))
+            // Components take part in boolean ops (decomposed)
+            let contour_count =
+                session.paths.len() + session.glyph.components.len();
+            Either::A(
+                flex_col((
+                    transform_panel(
+                        has_selection,
+                        contour_count,
+                        session.align_target,
+                        apply_transform,
+                    ),
+                    numeric_transform_panel(
+                        session.numeric_transform,
+                        session.transform_scope,
+                    ),
+                ))
+                .cross_axis_alignment(xilem::view::CrossAxisAlignment::End)
+                .gap(UI_PANEL_GAP.px()),
+            )
         } else {
             Either::B(sized_box(label("")).width(0.px()).height(0.px()))
         })
@@ -148,7 +186,18 @@ pub fn editor_tab(state: &mut AppState) -> impl WidgetView<AppState> + use<> {
         // Left side: curve-quality panel (harmonize / balance /
         // optimize, shared geometry in runebender-core)
         transformed(if session.panels_visible {
-            Either::A(curve_panel())
+            Either::A(
+                flex_col((
+                    curve_panel(),
+                    component_panel(selected_component_base(session)),
+                    metrics_keys_panel(
+                        session.glyph.metrics_keys.clone(),
+                        active_metrics_drift(state, &glyph_name),
+                    ),
+                ))
+                .cross_axis_alignment(xilem::view::CrossAxisAlignment::Start)
+                .gap(UI_PANEL_GAP.px()),
+            )
         } else {
             Either::B(sized_box(label("")).width(0.px()).height(0.px()))
         })
@@ -719,9 +768,11 @@ fn append_component_path(
 /// Apply a transform action from the transform panel
 /// Curve-quality panel: harmonize (G2 joins), Tunni balance, and the
 /// contour optimizer, applied to the selection or (empty selection)
-/// the whole glyph. Mirrors runebender-web's CurvePanel actions; the
-/// curvature comb and continuity dots come later.
+/// the whole glyph, plus smooth flag detection for the whole glyph.
+/// Mirrors runebender-web's CurvePanel actions; the curvature comb and
+/// continuity dots come later.
 fn curve_panel() -> impl WidgetView<AppState> + use<> {
+    use crate::settings::smooth_detection::ANGLE_TOLERANCE;
     use xilem::style::Style as _;
     use xilem::view::button;
 
@@ -752,6 +803,8 @@ fn curve_panel() -> impl WidgetView<AppState> + use<> {
             op_button("Harmonize", |s| s.harmonize_selection()),
             op_button("Balance", |s| s.balance_selection()),
             op_button("Optimize", |s| s.optimize_selection(0.12)),
+            op_button("Smooth Flags", |s| s.detect_smooth_points(ANGLE_TOLERANCE, false)),
+            op_button("Snap Smooth", |s| s.detect_smooth_points(ANGLE_TOLERANCE, true)),
             toggle_button("Comb", |s| s.show_comb = !s.show_comb),
             toggle_button("Dots", |s| s.show_continuity = !s.show_continuity),
         ))
@@ -766,6 +819,394 @@ fn curve_panel() -> impl WidgetView<AppState> + use<> {
     .corner_radius(8.0.px())
 }
 
+/// Base glyph of the selected component, if one is selected
+fn selected_component_base(session: &crate::editing::EditSession) -> Option<String> {
+    let id = session.selected_component?;
+    session
+        .glyph
+        .components
+        .iter()
+        .find(|c| c.id == id)
+        .map(|c| c.base.clone())
+}
+
+/// Component panel under the curve panel: decompose the selected or
+/// all components, decompose every use of this glyph across the font,
+/// turn the selected contours into a new component glyph, or point the
+/// selected component at another base glyph (keeping its transform)
+fn component_panel(base: Option<String>) -> impl WidgetView<AppState> + use<> {
+    use xilem::style::Style as _;
+    use xilem::view::button;
+
+    let decompose_button = |name: &'static str, all: bool| {
+        button(label(name).text_size(12.0), move |state: &mut AppState| {
+            let Some(session) = &mut state.editor_session else {
+                return;
+            };
+            if session.decompose_components(all) {
+                session.sync_to_workspace();
+            }
+        })
+    };
+
+    sized_box(
+        flex_col((
+            label("Components")
+                .text_size(10.0)
+                .color(theme::panel::GLYPH_PREVIEW),
+            decompose_button("Decompose", false),
+            decompose_button("Decompose All", true),
+            button(
+                label("Decompose Uses").text_size(12.0),
+                |state: &mut AppState| state.decompose_uses_of_active_glyph(),
+            ),
+            button(
+                label("Make Component").text_size(12.0),
+                |state: &mut AppState| state.make_component_from_selection(),
+            ),
+            // Applied as soon as the typed name is an existing glyph
+            text_input(base.unwrap_or_default(), |state: &mut AppState, name| {
+                let Some(session) = &mut state.editor_session else {
+                    return;
+                };
+                if session.replace_selected_component_base(name.trim()) {
+                    session.sync_to_workspace();
+                }
+            })
+            .text_alignment(parley::Alignment::Center)
+            .placeholder("Base glyph"),
+        ))
+        .cross_axis_alignment(xilem::view::CrossAxisAlignment::Stretch)
+        .gap(4.px()),
+    )
+    .width(112.px())
+    .padding(6.0.px())
+    .background_color(theme::panel::BACKGROUND)
+    .corner_radius(8.0.px())
+}
+
+/// Metrics drift of the glyph open in the editor
+fn active_metrics_drift(state: &AppState, glyph_name: &str) -> Vec<MetricDrift> {
+    state
+        .active_workspace()
+        .map(|workspace_arc| read_workspace(&workspace_arc).metrics_drift(glyph_name))
+        .unwrap_or_default()
+}
+
+/// Metrics keys panel under the component panel: keys linking the
+/// sidebearings and width to other glyphs ("=n", "=|o", "=H+10"),
+/// each with the value it gives when the glyph differs from it
+fn metrics_keys_panel(
+    keys: MetricsKeys,
+    drift: Vec<MetricDrift>,
+) -> impl WidgetView<AppState> + use<> {
+    use xilem::style::Style as _;
+    use xilem::view::button;
+
+    let row = |side: MetricSide| {
+        let expected = drift
+            .iter()
+            .find(|d| d.side == side)
+            .map(|d| format!("→ {:.0}", d.expected))
+            .unwrap_or_default();
+        flex_col((
+            flex_row((
+                label(side.label())
+                    .text_size(10.0)
+                    .color(theme::panel::GLYPH_PREVIEW),
+                label(expected)
+                    .text_size(10.0)
+                    .color(theme::grid::CELL_METRICS_DRIFT),
+            ))
+            .gap(4.px()),
+            text_input(
+                keys.get(side).unwrap_or_default().to_string(),
+                move |state: &mut AppState, key| state.update_metrics_key(side, key),
+            )
+            .text_alignment(parley::Alignment::Center)
+            .placeholder("=n"),
+        ))
+        .cross_axis_alignment(xilem::view::CrossAxisAlignment::Stretch)
+        .gap(2.px())
+    };
+
+    sized_box(
+        flex_col((
+            label("Metrics Keys")
+                .text_size(10.0)
+                .color(theme::panel::GLYPH_PREVIEW),
+            row(MetricSide::Left),
+            row(MetricSide::Right),
+            row(MetricSide::Width),
+            button(label("Apply Keys").text_size(12.0), |state: &mut AppState| {
+                state.apply_active_metrics_keys();
+            }),
+        ))
+        .cross_axis_alignment(xilem::view::CrossAxisAlignment::Stretch)
+        .gap(4.px()),
+    )
+    .width(112.px())
+    .padding(6.0.px())
+    .background_color(theme::panel::BACKGROUND)
+    .corner_radius(8.0.px())
+}
+
+/// Numeric transform section under the transform panel: scale,
+/// rotate, skew and move typed in, applied about the coordinate
+/// panel's quadrant origin to the selection, the glyph or the glyphs
+/// selected in the grid
+fn numeric_transform_panel(
+    values: NumericTransform,
+    scope: TransformScope,
+) -> impl WidgetView<AppState> + use<> {
+    use xilem::style::Style as _;
+    use xilem::view::button;
+
+    let input = |value: f64, apply: fn(&mut NumericTransform, f64)| {
+        sized_box(
+            text_input(format!("{value:.0}"), move |state: &mut AppState, new_value| {
+                let Ok(v) = new_value.trim().parse::<f64>() else {
+                    return;
+                };
+                if let Some(session) = &mut state.editor_session {
+                    apply(&mut session.numeric_transform, v);
+                }
+            })
+            .text_alignment(parley::Alignment::Center),
+        )
+        .width(48.px())
+    };
+
+    fn row<V: WidgetView<AppState>>(
+        name: &'static str,
+        fields: V,
+    ) -> impl WidgetView<AppState> + use<V> {
+        flex_col((
+            label(name).text_size(10.0).color(theme::panel::GLYPH_PREVIEW),
+            fields,
+        ))
+        .cross_axis_alignment(xilem::view::CrossAxisAlignment::Start)
+        .gap(2.px())
+    }
+
+    sized_box(
+        flex_col((
+            label("Transform").text_size(10.0).color(theme::panel::GLYPH_PREVIEW),
+            row(
+                "Scale %",
+                flex_row((
+                    input(values.scale_x, |t, v| t.scale_x = v),
+                    input(values.scale_y, |t, v| t.scale_y = v),
+                ))
+                .gap(4.px()),
+            ),
+            row(
+                "Rotate °",
+                flex_row((input(values.rotate, |t, v| t.rotate = v),)),
+            ),
+            row(
+                "Skew °",
+                flex_row((
+                    input(values.skew_x, |t, v| t.skew_x = v),
+                    input(values.skew_y, |t, v| t.skew_y = v),
+                ))
+                .gap(4.px()),
+            ),
+            row(
+                "Move",
+                flex_row((
+                    input(values.dx, |t, v| t.dx = v),
+                    input(values.dy, |t, v| t.dy = v),
+                ))
+                .gap(4.px()),
+            ),
+            button(
+                label(scope.label()).text_size(12.0),
+                |state: &mut AppState| {
+                    if let Some(session) = &mut state.editor_session {
+                        session.transform_scope = session.transform_scope.next();
+                    }
+                },
+            ),
+            button(label("Apply").text_size(12.0), |state: &mut AppState| {
+                state.apply_numeric_transform(false);
+            }),
+            button(label("Repeat Last").text_size(12.0), |state: &mut AppState| {
+                state.apply_numeric_transform(true);
+            }),
+        ))
+        .cross_axis_alignment(xilem::view::CrossAxisAlignment::Stretch)
+        .gap(4.px()),
+    )
+    // Explicit width, as with the curve panel
+    .width(112.px())
+    .padding(6.0.px())
+    .background_color(theme::panel::BACKGROUND)
+    .corner_radius(8.0.px())
+}
+
+/// Option strip under the shapes toolbar: the parameter of the
+/// current shape plus a reminder of the drawing modifiers
+fn shape_options_strip(
+    shape: ShapeType,
+    options: ShapeOptions,
+) -> impl WidgetView<AppState> + use<> {
+    let field = |name: &'static str,
+                 value: String,
+                 apply: fn(&mut ShapeOptions, f64)| {
+        flex_row((
+            label(name).text_size(10.0).color(theme::panel::GLYPH_PREVIEW),
+            sized_box(
+                text_input(value, move |state: &mut AppState, new_value| {
+                    if let Ok(v) = new_value.trim().parse::<f64>() {
+                        state.update_shape_options(|opts| apply(opts, v));
+                    }
+                })
+                .text_alignment(parley::Alignment::Center),
+            )
+            .width(56.px()),
+        ))
+        .gap(4.px())
+    };
+
+    let fields = match shape {
+        ShapeType::RoundedRect => Either::A(field(
+            "Radius",
+            format!("{:.0}", options.corner_radius),
+            |o, v| o.corner_radius = v,
+        )),
+        ShapeType::Polygon => Either::A(field(
+            "Sides",
+            options.sides.to_string(),
+            |o, v| o.sides = v.round().max(0.0) as usize,
+        )),
+        ShapeType::Star => Either::B(Either::A(flex_row((
+            field("Points", options.sides.to_string(), |o, v| {
+                o.sides = v.round().max(0.0) as usize;
+            }),
+            field(
+                "Inner %",
+                format!("{:.0}", options.inner_ratio * 100.0),
+                |o, v| o.inner_ratio = v / 100.0,
+            ),
+        ))
+        .gap(8.px()))),
+        ShapeType::StrokeLine => Either::A(field(
+            "Width",
+            format!("{:.0}", options.stroke_width),
+            |o, v| o.stroke_width = v,
+        )),
+        ShapeType::Rectangle | ShapeType::Ellipse => {
+            Either::B(Either::B(label("")))
+        }
+    };
+
+    sized_box(
+        flex_row((
+            fields,
+            label("⇧ constrain  ⌥ from center")
+                .text_size(10.0)
+                .color(theme::panel::GLYPH_PREVIEW),
+        ))
+        .gap(8.px()),
+    )
+    .padding(6.0.px())
+    .background_color(theme::panel::BACKGROUND)
+    .corner_radius(8.0.px())
+}
+
+/// Spacing mode toggles shown under the text direction toolbar
+fn spacing_strip(
+    spacing_mode: bool,
+    all_masters: bool,
+) -> impl WidgetView<AppState> + use<> {
+    use xilem::view::button;
+
+    let toggle = |text: &str, on: bool| {
+        let text = if on {
+            format!("● {text}")
+        } else {
+            text.to_string()
+        };
+        label(text).text_size(12.0)
+    };
+
+    sized_box(
+        flex_row((
+            button(
+                toggle("Spacing", spacing_mode),
+                |state: &mut AppState| state.toggle_spacing_mode(),
+            ),
+            button(
+                toggle("All Masters", all_masters),
+                |state: &mut AppState| state.toggle_spacing_all_masters(),
+            ),
+            label("drag edges  ←→ nudge  ⇧ ×10")
+                .text_size(10.0)
+                .color(theme::panel::GLYPH_PREVIEW),
+        ))
+        .gap(8.px()),
+    )
+    .padding(6.0.px())
+    .background_color(theme::panel::BACKGROUND)
+    .corner_radius(8.0.px())
+}
+
+/// Paragraph mode toggles and settings shown under the spacing
+/// toggles
+fn paragraph_strip(
+    settings: ParagraphSettings,
+) -> impl WidgetView<AppState> + use<> {
+    use xilem::view::button;
+
+    let toggle = |text: &str, on: bool| {
+        let text = if on {
+            format!("● {text}")
+        } else {
+            text.to_string()
+        };
+        label(text).text_size(12.0)
+    };
+    let field = |value: f64, field: fn(&mut ParagraphSettings) -> &mut f64| {
+        sized_box(
+            text_input(format!("{value}"), move |state: &mut AppState, input| {
+                state.set_paragraph_option(input, field);
+            })
+            .text_alignment(parley::Alignment::Center),
+        )
+        .width(52.px())
+    };
+    let caption = |text: &str| {
+        label(text.to_string())
+            .text_size(10.0)
+            .color(theme::panel::GLYPH_PREVIEW)
+    };
+
+    sized_box(
+        flex_row((
+            button(
+                toggle("Paragraph", settings.enabled),
+                |state: &mut AppState| state.toggle_paragraph_mode(),
+            ),
+            button(
+                toggle("Waterfall", settings.waterfall),
+                |state: &mut AppState| state.toggle_paragraph_waterfall(),
+            ),
+            caption("pt"),
+            field(settings.point_size, |s| &mut s.point_size),
+            caption("width"),
+            field(settings.line_width, |s| &mut s.line_width),
+            caption("leading"),
+            field(settings.line_spacing, |s| &mut s.line_spacing),
+        ))
+        .cross_axis_alignment(xilem::view::CrossAxisAlignment::Center)
+        .gap(8.px()),
+    )
+    .padding(6.0.px())
+    .background_color(theme::panel::BACKGROUND)
+    .corner_radius(8.0.px())
+}
+
 fn apply_transform(
     state: &mut AppState,
     action: TransformAction,
@@ -815,6 +1256,34 @@ fn apply_transform(
         TransformAction::Exclude => {
             session.boolean_op(linesweeper::BinaryOp::Xor);
         }
+        TransformAction::AlignTarget => {
+            session.align_target = session.align_target.next();
+            return;
+        }
+        TransformAction::AlignLeft => {
+            session.align_selection(AlignEdge::Left);
+        }
+        TransformAction::AlignRight => {
+            session.align_selection(AlignEdge::Right);
+        }
+        TransformAction::AlignCenter => {
+            session.align_selection(AlignEdge::Center);
+        }
+        TransformAction::AlignMiddle => {
+            session.align_selection(AlignEdge::Middle);
+        }
+        TransformAction::AlignTop => {
+            session.align_selection(AlignEdge::Top);
+        }
+        TransformAction::AlignBottom => {
+            session.align_selection(AlignEdge::Bottom);
+        }
+        TransformAction::DistributeH => {
+            session.distribute_selection(kurbo::Axis::Horizontal);
+        }
+        TransformAction::DistributeV => {
+            session.distribute_selection(kurbo::Axis::Vertical);
+        }
     }
 
     session.sync_to_workspace();
@@ -0,0 +1,201 @@
+// Copyright 2026 the Runebender Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Autospacing panel, shown over the glyph grid. Sidebearings are
+//! proposed for the selected glyphs from the settings here, listed
+//! next to the current ones, and nothing changes until "Apply".
+
+use masonry::layout::AsUnit;
+use xilem::WidgetView;
+use xilem::style::Style;
+use xilem::view::{
+    CrossAxisAlignment, FlexExt, button, flex_col, flex_row, label, sized_box, text_input,
+};
+
+use crate::data::AppState;
+use crate::model::auto_space::{AutoSpaceOptions, CategorySpacing, SpacingProposal};
+use crate::theme;
+
+/// Proposals listed at once; the rest are applied all the same
+const MAX_ROWS: usize = 24;
+
+/// Width of the panel
+const PANEL_WIDTH: f64 = 420.0;
+
+/// Width of the label column
+const NAME_WIDTH: f64 = 120.0;
+
+pub(super) fn auto_space_panel(state: &AppState) -> impl WidgetView<AppState> + use<> {
+    let options = &state.auto_space_options;
+    let categories: Vec<_> = options
+        .categories
+        .iter()
+        .enumerate()
+        .map(|(index, category)| category_row(index, category))
+        .collect();
+
+    let proposals = state
+        .auto_space
+        .as_ref()
+        .map(|a| a.proposals.as_slice())
+        .unwrap_or_default();
+    let summary = match &state.auto_space {
+        None => "Proposes sidebearings for the selected glyphs".to_string(),
+        Some(_) => {
+            let changing = proposals.iter().filter(|p| p.changes()).count();
+            let accepted = proposals.iter().filter(|p| p.accepted).count();
+            format!(
+                "{} glyph(s), {changing} changing, {accepted} accepted",
+                proposals.len()
+            )
+        }
+    };
+    let hidden = proposals.len().saturating_sub(MAX_ROWS);
+    let rows: Vec<_> = proposals
+        .iter()
+        .take(MAX_ROWS)
+        .cloned()
+        .enumerate()
+        .map(|(index, proposal)| proposal_row(index, proposal))
+        .collect();
+    let mut notes = Vec::new();
+    if hidden > 0 {
+        notes.push(format!("…and {hidden} more"));
+    }
+    notes.extend(state.auto_space_message.clone());
+    let notes: Vec<_> = notes.into_iter().map(proposal_note).collect();
+
+    sized_box(
+        flex_col((
+            flex_row((
+                label("Auto Space").text_size(14.0).flex(1.0),
+                button(label("Close").text_size(12.0), |state: &mut AppState| {
+                    state.auto_space_open = false;
+                }),
+            ))
+            .cross_axis_alignment(CrossAxisAlignment::Center),
+            flex_row((
+                label("Area").text_size(12.0),
+                option_input(options.area, |o| &mut o.area),
+                label("Depth %").text_size(12.0),
+                option_input(options.depth, |o| &mut o.depth),
+                label("Overshoot %").text_size(12.0),
+                option_input(options.overshoot, |o| &mut o.overshoot),
+            ))
+            .cross_axis_alignment(CrossAxisAlignment::Center)
+            .gap(6.px()),
+            label("Category · reference · factor").text_size(10.0),
+            flex_col(categories).gap(2.px()),
+            flex_row((
+                button(label("Propose").text_size(12.0), |state: &mut AppState| {
+                    state.run_auto_space()
+                }),
+                button(
+                    label("Accept All").text_size(12.0),
+                    |state: &mut AppState| state.accept_all_space_proposals(true),
+                ),
+                button(
+                    label("Reject All").text_size(12.0),
+                    |state: &mut AppState| state.accept_all_space_proposals(false),
+                ),
+            ))
+            .gap(6.px()),
+            label(summary).text_size(12.0).color(theme::grid::CELL_TEXT),
+            flex_col(rows).gap(2.px()),
+            flex_col(notes).gap(2.px()),
+            flex_row((
+                button(label("Apply").text_size(12.0), |state: &mut AppState| {
+                    state.apply_auto_space()
+                }),
+                button(label("Discard").text_size(12.0), |state: &mut AppState| {
+                    state.discard_auto_space()
+                }),
+            ))
+            .gap(6.px()),
+        ))
+        .cross_axis_alignment(CrossAxisAlignment::Stretch)
+        .gap(6.px()),
+    )
+    .width(PANEL_WIDTH.px())
+    .padding(8.0.px())
+    .background_color(theme::panel::BACKGROUND)
+    .corner_radius(8.0.px())
+}
+
+fn option_input(
+    value: f64,
+    field: fn(&mut AutoSpaceOptions) -> &mut f64,
+) -> impl WidgetView<AppState> + use<> {
+    sized_box(
+        text_input(format!("{value}"), move |state: &mut AppState, input| {
+            state.set_auto_space_option(input, field);
+        })
+        .text_alignment(parley::Alignment::Center),
+    )
+    .width(56.px())
+}
+
+fn category_row(index: usize, category: &CategorySpacing) -> impl WidgetView<AppState> + use<> {
+    flex_row((
+        sized_box(
+            label(category.category.display_name().to_string())
+                .text_size(12.0)
+                .color(theme::grid::CELL_TEXT),
+        )
+        .width(NAME_WIDTH.px()),
+        sized_box(text_input(
+            category.reference.clone(),
+            move |state: &mut AppState, reference| {
+                state.set_auto_space_reference(index, reference);
+            },
+        ))
+        .width(96.px()),
+        sized_box(
+            text_input(
+                format!("{}", category.factor),
+                move |state: &mut AppState, factor| {
+                    state.set_auto_space_factor(index, factor);
+                },
+            )
+            .text_alignment(parley::Alignment::Center),
+        )
+        .width(56.px()),
+    ))
+    .cross_axis_alignment(CrossAxisAlignment::Center)
+    .gap(6.px())
+}
+
+fn proposal_row(index: usize, proposal: SpacingProposal) -> impl WidgetView<AppState> + use<> {
+    let mark = if proposal.accepted { "✓" } else { "–" };
+    let color = if proposal.changes() {
+        theme::panel::GLYPH_PREVIEW
+    } else {
+        theme::grid::CELL_TEXT
+    };
+    flex_row((
+        sized_box(button(
+            label(mark).text_size(12.0),
+            move |state: &mut AppState| state.toggle_space_proposal(index),
+        ))
+        .width(32.px()),
+        sized_box(
+            label(proposal.glyph)
+                .text_size(12.0)
+                .color(theme::grid::CELL_TEXT),
+        )
+        .width(NAME_WIDTH.px()),
+        label(format!(
+            "L {:.0} → {:.0}   R {:.0} → {:.0}",
+            proposal.current_left, proposal.left, proposal.current_right, proposal.right
+        ))
+        .text_size(12.0)
+        .color(color)
+        .flex(1.0),
+    ))
+    .cross_axis_alignment(CrossAxisAlignment::Center)
+    .gap(6.px())
+}
+
+fn proposal_note(text: String) -> impl WidgetView<AppState> + use<> {
+    label(text).text_size(11.0).color(theme::grid::CELL_TEXT)
+}
@@ -55,6 +55,8 @@ thread_local! {
 const CELL_LABEL_HEIGHT: f64 = 56.0;
 /// Padding around the glyph preview and labels
 const CELL_PAD: f64 = 8.0;
+/// Radius of the metrics drift dot
+const DRIFT_MARKER_RADIUS: f64 = 4.0;
 
 /// Custom widget that renders a glyph cell and handles
 /// click, double-click, and shift-click events.
@@ -65,6 +67,8 @@ pub(super) struct GlyphCellWidget {
     upm: f64,
     is_selected: bool,
     mark_color: Option<usize>,
+    /// Metrics differ from the glyph's metrics keys
+    metrics_drift: bool,
 }
 
 impl GlyphCellWidget {
@@ -75,6 +79,7 @@ impl GlyphCellWidget {
         upm: f64,
         is_selected: bool,
         mark_color: Option<usize>,
+        metrics_drift: bool,
     ) -> Self {
         Self {
             glyph_name,
@@ -83,6 +88,7 @@ impl GlyphCellWidget {
             upm,
             is_selected,
             mark_color,
+            metrics_drift,
         }
     }
 
@@ -143,6 +149,20 @@ impl GlyphCellWidget {
         painter.fill(&transformed_path, &Brush::Solid(color)).draw();
     }
 
+    /// Paint a dot in the top-right corner when the metrics drifted
+    /// from their keys
+    fn paint_drift_marker(&self, painter: &mut Painter<'_>, size: Size) {
+        if !self.metrics_drift {
+            return;
+        }
+        let radius = DRIFT_MARKER_RADIUS;
+        let center = kurbo::Point::new(size.width - CELL_PAD - radius, CELL_PAD + radius);
+        let dot = kurbo::Circle::new(center, radius);
+        painter
+            .fill(&dot, &Brush::Solid(theme::grid::CELL_METRICS_DRIFT))
+            .draw();
+    }
+
     /// Paint the name and unicode labels
     fn paint_labels(&self, painter: &mut Painter<'_>, label_rect: Rect, is_hovered: bool) {
         let text_color = if self.is_selected || is_hovered {
@@ -260,6 +280,7 @@ impl Widget for GlyphCellWidget {
             size.height - CELL_PAD,
         );
         self.paint_labels(painter, label_rect, ctx.is_hovered());
+        self.paint_drift_marker(painter, size);
     }
 
     fn accessibility_role(&self) -> Role {
@@ -326,6 +347,7 @@ pub(super) fn glyph_cell_view<State, Action>(
     is_selected: bool,
     upm: f64,
     mark_color: Option<usize>,
+    metrics_drift: bool,
     callback: impl Fn(&mut State, GlyphCellAction) + Send + Sync + 'static,
 ) -> GlyphCellView<State, Action>
 where
@@ -339,6 +361,7 @@ where
         is_selected,
         upm,
         mark_color,
+        metrics_drift,
         callback: Box::new(callback),
         phantom: PhantomData,
     }
@@ -352,6 +375,7 @@ pub(super) struct GlyphCellView<State, Action = ()> {
     is_selected: bool,
     upm: f64,
     mark_color: Option<usize>,
+    metrics_drift: bool,
     callback: GlyphCellCallback<State>,
     phantom: PhantomData<fn() -> (State, Action)>,
 }
@@ -372,6 +396,7 @@ impl<State: 'static, Action: 'static + Default> View<State, Action, ViewCtx>
             self.upm,
             self.is_selected,
             self.mark_color,
+            self.metrics_drift,
         );
         let pod = ctx.create_pod(widget);
         ctx.record_action_source(pod.new_widget.id());
@@ -412,6 +437,10 @@ impl<State: 'static, Action: 'static + Default> View<State, Action, ViewCtx>
             w.mark_color = self.mark_color;
             changed = true;
         }
+        if self.metrics_drift != prev.metrics_drift {
+            w.metrics_drift = self.metrics_drift;
+            changed = true;
+        }
         if changed {
             element.ctx.request_render();
         }
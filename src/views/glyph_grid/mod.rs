@@ -9,6 +9,7 @@
 //! selection and opening the editor. The grid reflows based on window width
 //! and supports arrow-key navigation and multi-select.
 
+mod auto_space;
 mod glyph_cell;
 
 use std::collections::HashSet;
@@ -20,6 +21,7 @@ use xilem::core::one_of::Either;
 use xilem::style::Style;
 use xilem::view::{CrossAxisAlignment, FlexExt, ZStackExt, flex_col, flex_row, label, sized_box, zstack};
 
+use auto_space::auto_space_panel;
 use glyph_cell::{GlyphCellAction, glyph_cell_view};
 
 use crate::components::{
@@ -28,6 +30,7 @@ use crate::components::{
     grid_scroll_handler, mark_color_panel, master_toolbar_view, size_tracker, system_toolbar_view,
 };
 use crate::data::AppState;
+use crate::editing::normalize::DirectionConvention;
 use crate::model::glyph_renderer;
 use crate::model::read_workspace;
 use crate::model::workspace;
@@ -148,6 +151,16 @@ pub fn glyph_grid_tab(state: &mut AppState) -> impl WidgetView<AppState> + use<>
         .alignment(xilem::view::ChildAlignment::SelfAligned(
             masonry::layout::UnitPoint::TOP_LEFT,
         )),
+        // Autospacing panel (over the grid, beside the category panel)
+        xilem::view::transformed(if state.auto_space_open {
+            Either::A(auto_space_panel(state))
+        } else {
+            Either::B(sized_box(label("")).width(0.px()).height(0.px()))
+        })
+        .translate((CATEGORY_PANEL_WIDTH + BENTO_GAP * 4.0, 70.0))
+        .alignment(xilem::view::ChildAlignment::SelfAligned(
+            masonry::layout::UnitPoint::TOP_LEFT,
+        )),
     ))
 }
 
@@ -197,6 +210,28 @@ fn system_menu_panel(state: &AppState) -> impl WidgetView<AppState> + use<> {
             entry("Save".into(), |s| s.save_workspace()),
             entry("Save As…".into(), |s| s.save_workspace_as()),
             entry("Close Font".into(), |s| s.close_font()),
+            label("Outlines").text_size(10.0),
+            entry("Detect Smooth Points".into(), |s| {
+                s.detect_smooth_points_in_font(false);
+            }),
+            entry("Detect Smooth + Snap Handles".into(), |s| {
+                s.detect_smooth_points_in_font(true);
+            }),
+            entry("Normalize Contours (PostScript)".into(), |s| {
+                s.normalize_contours_in_font(DirectionConvention::PostScript);
+            }),
+            entry("Normalize Contours (TrueType)".into(), |s| {
+                s.normalize_contours_in_font(DirectionConvention::TrueType);
+            }),
+            label("Metrics").text_size(10.0),
+            entry("Update Metrics from Keys".into(), |s| {
+                s.update_all_metrics_from_keys();
+            }),
+            entry("Auto Space Selected…".into(), |s| {
+                s.auto_space_open = true;
+            }),
+            label("Kerning").text_size(10.0),
+            entry("Kerning Table".into(), |s| s.open_kerning_table()),
             label("Recent").text_size(10.0),
             flex_col(recents).gap(2.px()),
         ))
@@ -246,7 +281,7 @@ pub(crate) fn file_info_panel(state: &AppState) -> impl WidgetView<AppState> + u
 }
 
 /// Master toolbar panel — only shown when designspace has multiple masters
-fn master_toolbar_panel(state: &AppState) -> impl WidgetView<AppState> + use<> {
+pub(crate) fn master_toolbar_panel(state: &AppState) -> impl WidgetView<AppState> + use<> {
     if let Some(ref designspace) = state.designspace
         && designspace.masters.len() > 1
     {
@@ -328,13 +363,14 @@ fn get_upm_from_state(state: &AppState) -> f64 {
 
 /// Type alias for glyph data tuple
 /// (name, path with components, codepoints, contour count,
-///  mark color palette index, column span)
+///  mark color palette index, metrics drift from keys, column span)
 type GlyphData = (
     String,
     Option<BezPath>,
     Vec<char>,
     usize,
     Option<usize>,
+    bool,
     usize,
 );
 
@@ -408,6 +444,7 @@ fn build_single_glyph_data(workspace: &workspace::Workspace, name: &str, upm: f6
         let codepoints = glyph.codepoints.clone();
         let path = glyph_renderer::glyph_to_bezpath_with_components(glyph, workspace);
         let mark_index = glyph_mark_index(glyph);
+        let drift = workspace.has_metrics_drift(name);
         let span = compute_col_span(name, glyph.width, upm);
         (
             name.to_string(),
@@ -415,10 +452,11 @@ fn build_single_glyph_data(workspace: &workspace::Workspace, name: &str, upm: f6
             codepoints,
             count,
             mark_index,
+            drift,
             span,
         )
     } else {
-        (name.to_string(), None, Vec::new(), 0, None, 1)
+        (name.to_string(), None, Vec::new(), 0, None, false, 1)
     }
 }
 
@@ -530,7 +568,7 @@ fn build_glyph_rows(
         let mut used = 0;
 
         for &(idx, span) in row_slots {
-            let (name, path_opt, codepoints, _, mark_color, _) = &glyph_data[idx];
+            let (name, path_opt, codepoints, _, mark_color, drift, _) = &glyph_data[idx];
             let is_selected = selected_glyphs.contains(name);
             let w = cell_pixel_width(span, cell_unit);
             items.push(Either::A(
@@ -541,6 +579,7 @@ fn build_glyph_rows(
                     is_selected,
                     upm,
                     *mark_color,
+                    *drift,
                 ))
                 .width(w.px())
                 ,
@@ -580,6 +619,7 @@ fn glyph_cell(
     is_selected: bool,
     upm: f64,
     mark_color: Option<usize>,
+    metrics_drift: bool,
 ) -> impl WidgetView<AppState> + use<> {
     glyph_cell_view(
         glyph_name,
@@ -588,6 +628,7 @@ fn glyph_cell(
         is_selected,
         upm,
         mark_color,
+        metrics_drift,
         |state: &mut AppState, action| match action {
             GlyphCellAction::Select(name) => {
                 state.select_glyph(name);
@@ -0,0 +1,309 @@
+// Copyright 2026 the Runebender Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Kerning tab — every pair in the active master's kerning as a
+//! table, filtered by glyph or group on either side.
+//!
+//! Each row shows a sample of the pair set with its value, the pair's
+//! kind (group–group, group–glyph, glyph–group, glyph–glyph or
+//! exception), an editable value, and delete / exception actions.
+//! Group sides are sampled with the filter glyph when it is a member
+//! of the group, otherwise the group's first member, and "Make
+//! Exception" creates an exception for exactly the glyphs shown.
+//!
+//! The tab also switches to the group manager (`kerning_groups`), the
+//! kerning audit (`kerning_audit`), kerning import / export
+//! (`kerning_exchange`) and kerning suggestions (`kerning_suggest`).
+//! Everything works on the active master, which the master toolbar
+//! switches.
+
+use kurbo::{Affine, BezPath};
+use masonry::layout::AsUnit;
+use xilem::WidgetView;
+use xilem::core::one_of::Either;
+use xilem::style::Style;
+use xilem::view::{
+    CrossAxisAlignment, FlexExt, button, flex_col, flex_row, label, sized_box, text_input,
+};
+
+use crate::components::multi_glyph_view;
+use crate::data::{AppState, KerningView};
+use crate::model::glyph_renderer::glyph_to_bezpath_with_components;
+use crate::model::kerning_table::{KernRow, PairKind, kerning_rows, representative};
+use crate::model::read_workspace;
+use crate::model::workspace::Workspace;
+use crate::theme;
+use crate::views::kerning_audit::kerning_audit_panel;
+use crate::views::kerning_exchange::kerning_exchange_panel;
+use crate::views::kerning_groups::kerning_groups_panel;
+use crate::views::kerning_suggest::kerning_suggest_panel;
+
+/// Gap between tiles and row cells
+const GAP: f64 = 6.0;
+
+/// Rows shown per page; every row renders a sample
+const PAGE_SIZE: usize = 50;
+
+/// Size of the pair sample in each row
+const SAMPLE_WIDTH: f64 = 120.0;
+const SAMPLE_HEIGHT: f64 = 40.0;
+
+/// Width of the first / second side columns
+const NAME_WIDTH: f64 = 160.0;
+
+/// A table row with its sample and exception target worked out
+struct RowItem {
+    row: KernRow,
+    sample: BezPath,
+    /// Glyph pair for "Make Exception", when the row involves a group
+    /// and the shown glyphs don't have an exception yet
+    exception: Option<(String, String)>,
+}
+
+/// Tab 2: kerning table, group manager, audit, import / export or
+/// suggestions
+pub fn kerning_tab(state: &mut AppState) -> impl WidgetView<AppState> + use<> {
+    let body = match state.kerning_view {
+        KerningView::Pairs => Either::A(pairs_panel(state)),
+        KerningView::Groups => Either::B(Either::A(kerning_groups_panel(state))),
+        KerningView::Audit => Either::B(Either::B(Either::A(kerning_audit_panel(state)))),
+        KerningView::Exchange => Either::B(Either::B(Either::B(Either::A(
+            kerning_exchange_panel(state),
+        )))),
+        KerningView::Suggest => Either::B(Either::B(Either::B(Either::B(kerning_suggest_panel(
+            state,
+        ))))),
+    };
+
+    flex_col((
+        // Row 1: back to the grid, views, file info, masters
+        flex_row((
+            sized_box(button(
+                label("← Glyphs").text_size(12.0),
+                |state: &mut AppState| state.close_kerning_table(),
+            ))
+            .width(96.px()),
+            view_button("Pairs", KerningView::Pairs, state.kerning_view),
+            view_button("Groups", KerningView::Groups, state.kerning_view),
+            view_button("Audit", KerningView::Audit, state.kerning_view),
+            view_button("Import/Export", KerningView::Exchange, state.kerning_view),
+            view_button("Suggest", KerningView::Suggest, state.kerning_view),
+            crate::views::glyph_grid::file_info_panel(state).flex(1.0),
+            crate::views::glyph_grid::master_toolbar_panel(state),
+        ))
+        .gap(GAP.px()),
+        body.flex(1.0),
+    ))
+    .cross_axis_alignment(CrossAxisAlignment::Stretch)
+    .gap(GAP.px())
+    .padding((GAP * 2.0).px())
+    .background_color(theme::app::BACKGROUND)
+}
+
+/// Side filters, paging and the pair rows
+fn pairs_panel(state: &AppState) -> impl WidgetView<AppState> + use<> {
+    let (items, total, upm) = match state.active_workspace() {
+        Some(workspace_arc) => {
+            let workspace = read_workspace(&workspace_arc);
+            let rows = kerning_rows(
+                &workspace.kerning,
+                &workspace.groups,
+                &state.kerning_first_filter,
+                &state.kerning_second_filter,
+            );
+            let total = rows.len();
+            let items: Vec<RowItem> = rows
+                .into_iter()
+                .skip(state.kerning_page * PAGE_SIZE)
+                .take(PAGE_SIZE)
+                .map(|row| row_item(row, &workspace, state))
+                .collect();
+            let upm = workspace.units_per_em.unwrap_or(1000.0);
+            (items, total, upm)
+        }
+        None => (Vec::new(), 0, 1000.0),
+    };
+
+    let skipped = (state.kerning_page * PAGE_SIZE).min(total);
+    let last_shown = (skipped + PAGE_SIZE).min(total);
+    let first_shown = if total == 0 { 0 } else { skipped + 1 };
+    let has_next = last_shown < total;
+    let rows: Vec<_> = items.into_iter().map(|item| kern_row(item, upm)).collect();
+
+    flex_col((
+        // Side filters and paging
+        flex_row((
+            filter_input(
+                state.kerning_first_filter.clone(),
+                "First glyph or group",
+                false,
+            ),
+            filter_input(
+                state.kerning_second_filter.clone(),
+                "Second glyph or group",
+                true,
+            ),
+            label(format!("{first_shown}–{last_shown} of {total}"))
+                .text_size(12.0)
+                .color(theme::grid::CELL_TEXT),
+            button(label("Prev").text_size(12.0), |state: &mut AppState| {
+                state.kerning_page = state.kerning_page.saturating_sub(1);
+            }),
+            button(
+                label("Next").text_size(12.0),
+                move |state: &mut AppState| {
+                    if has_next {
+                        state.kerning_page += 1;
+                    }
+                },
+            ),
+        ))
+        .cross_axis_alignment(CrossAxisAlignment::Center)
+        .gap(GAP.px()),
+        // The pairs
+        sized_box(
+            flex_col(rows)
+                .cross_axis_alignment(CrossAxisAlignment::Start)
+                .gap(2.px()),
+        )
+        .padding(GAP.px())
+        .background_color(theme::panel::BACKGROUND)
+        .border_color(theme::panel::OUTLINE)
+        .border_width(1.5.px())
+        .corner_radius(theme::size::PANEL_RADIUS.px())
+        .flex(1.0),
+    ))
+    .cross_axis_alignment(CrossAxisAlignment::Stretch)
+    .gap(GAP.px())
+}
+
+fn view_button(
+    text: &'static str,
+    view: KerningView,
+    current: KerningView,
+) -> impl WidgetView<AppState> + use<> {
+    let text = if view == current {
+        format!("● {text}")
+    } else {
+        text.to_string()
+    };
+    sized_box(button(
+        label(text).text_size(12.0),
+        move |state: &mut AppState| state.set_kerning_view(view),
+    ))
+    .width(112.px())
+}
+
+fn filter_input(
+    value: String,
+    placeholder: &'static str,
+    second: bool,
+) -> impl WidgetView<AppState> + use<> {
+    sized_box(
+        text_input(value, move |state: &mut AppState, filter| {
+            state.set_kerning_filter(second, filter);
+        })
+        .placeholder(placeholder),
+    )
+    .width(NAME_WIDTH.px())
+}
+
+/// Outline of two glyphs set side by side with a kerning value
+pub(crate) fn pair_sample(first: &str, second: &str, value: f64, workspace: &Workspace) -> BezPath {
+    let mut sample = BezPath::new();
+    if let (Some(left), Some(right)) = (workspace.get_glyph(first), workspace.get_glyph(second)) {
+        sample.extend(glyph_to_bezpath_with_components(left, workspace));
+        let offset = Affine::translate((left.width + value, 0.0));
+        sample.extend(offset * glyph_to_bezpath_with_components(right, workspace));
+    }
+    sample
+}
+
+/// Work out a row's sample and exception target
+fn row_item(row: KernRow, workspace: &Workspace, state: &AppState) -> RowItem {
+    let first = representative(&row.first, &state.kerning_first_filter, &workspace.groups);
+    let second = representative(&row.second, &state.kerning_second_filter, &workspace.groups);
+
+    let sample = match (first, second) {
+        (Some(first), Some(second)) => pair_sample(first, second, row.value, workspace),
+        _ => BezPath::new(),
+    };
+
+    let involves_group = !matches!(row.kind, PairKind::GlyphGlyph | PairKind::Exception);
+    let exception = match (first, second) {
+        (Some(first), Some(second)) if involves_group => {
+            let exists = workspace
+                .kerning
+                .get(first)
+                .is_some_and(|seconds| seconds.contains_key(second));
+            (!exists).then(|| (first.to_string(), second.to_string()))
+        }
+        _ => None,
+    };
+
+    RowItem {
+        row,
+        sample,
+        exception,
+    }
+}
+
+fn kern_row(item: RowItem, upm: f64) -> impl WidgetView<AppState> + use<> {
+    let RowItem {
+        row,
+        sample,
+        exception,
+    } = item;
+    let value = row.value;
+
+    let edit = {
+        let (first, second) = (row.first.clone(), row.second.clone());
+        move |state: &mut AppState, input: String| {
+            state.set_kern_pair_value(&first, &second, input);
+        }
+    };
+    let delete = {
+        let (first, second) = (row.first.clone(), row.second.clone());
+        move |state: &mut AppState| state.delete_kern_pair(&first, &second)
+    };
+    let delete_label = if row.kind == PairKind::Exception {
+        "Remove Exception"
+    } else {
+        "Delete"
+    };
+    let make_exception = match exception {
+        Some((first, second)) => Either::A(button(
+            label(format!("Make Exception {first}/{second}")).text_size(11.0),
+            move |state: &mut AppState| state.make_kern_exception(&first, &second, value),
+        )),
+        None => Either::B(sized_box(label("")).width(0.px()).height(0.px())),
+    };
+
+    let name = |text: String| {
+        sized_box(label(text).text_size(12.0).color(theme::grid::CELL_TEXT)).width(NAME_WIDTH.px())
+    };
+
+    flex_row((
+        sized_box(
+            multi_glyph_view(vec![sample], SAMPLE_WIDTH, SAMPLE_HEIGHT, upm)
+                .color(theme::panel::GLYPH_PREVIEW)
+                .fit_to_bounds(),
+        )
+        .width(SAMPLE_WIDTH.px())
+        .height(SAMPLE_HEIGHT.px()),
+        name(row.first),
+        name(row.second),
+        sized_box(
+            label(row.kind.label())
+                .text_size(11.0)
+                .color(theme::panel::GLYPH_PREVIEW),
+        )
+        .width(90.px()),
+        sized_box(text_input(format!("{value}"), edit).text_alignment(parley::Alignment::Center))
+            .width(64.px()),
+        button(label(delete_label).text_size(11.0), delete),
+        make_exception,
+    ))
+    .cross_axis_alignment(CrossAxisAlignment::Center)
+    .gap(GAP.px())
+}
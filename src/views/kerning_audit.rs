@@ -0,0 +1,172 @@
+// Copyright 2026 the Runebender Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Kerning audit — the findings of the last audit of the active
+//! master, shown in the kerning tab: colliding pairs, values too large
+//! for the glyphs, and redundant exceptions. "Open" sets the pair in
+//! the editor's text buffer for kerning.
+
+use masonry::layout::AsUnit;
+use xilem::WidgetView;
+use xilem::style::Style;
+use xilem::view::{
+    CrossAxisAlignment, FlexExt, button, flex_col, flex_row, label, sized_box, text_input,
+};
+
+use crate::components::multi_glyph_view;
+use crate::data::AppState;
+use crate::model::kerning_audit::AuditIssue;
+use crate::model::read_workspace;
+use crate::theme;
+use crate::views::kerning::pair_sample;
+
+/// Gap between rows and row cells
+const GAP: f64 = 6.0;
+
+/// Findings shown per page
+const PAGE_SIZE: usize = 50;
+
+/// Size of the pair sample in each row
+const SAMPLE_WIDTH: f64 = 120.0;
+const SAMPLE_HEIGHT: f64 = 40.0;
+
+/// Width of the pair column
+const PAIR_WIDTH: f64 = 240.0;
+
+/// The audit body of the kerning tab
+pub(crate) fn kerning_audit_panel(state: &AppState) -> impl WidgetView<AppState> + use<> {
+    let issues = state.kerning_audit.as_deref().unwrap_or_default();
+    let total = issues.len();
+    let (rows, upm) = match state.active_workspace() {
+        Some(workspace_arc) => {
+            let workspace = read_workspace(&workspace_arc);
+            let rows: Vec<_> = issues
+                .iter()
+                .skip(state.kerning_page * PAGE_SIZE)
+                .take(PAGE_SIZE)
+                .map(|issue| {
+                    let sample = pair_sample(
+                        &issue.first_glyph,
+                        &issue.second_glyph,
+                        issue.value,
+                        &workspace,
+                    );
+                    (issue.clone(), sample)
+                })
+                .collect();
+            (rows, workspace.units_per_em.unwrap_or(1000.0))
+        }
+        None => (Vec::new(), 1000.0),
+    };
+
+    let summary = match &state.kerning_audit {
+        None => "Not run yet".to_string(),
+        Some(_) if total == 0 => "No issues found".to_string(),
+        Some(_) => {
+            let first = state.kerning_page * PAGE_SIZE + 1;
+            let last = (first + PAGE_SIZE - 1).min(total);
+            format!("{first}–{last} of {total} issues")
+        }
+    };
+    let has_next = (state.kerning_page + 1) * PAGE_SIZE < total;
+    let options = state.kerning_audit_options;
+    let rows: Vec<_> = rows
+        .into_iter()
+        .map(|(issue, sample)| issue_row(issue, sample, upm))
+        .collect();
+
+    flex_col((
+        // Thresholds, run, and paging
+        flex_row((
+            label("Min distance").text_size(12.0),
+            threshold_input(options.min_distance, false),
+            label("Max % of width").text_size(12.0),
+            threshold_input(options.max_percent, true),
+            button(
+                label("Run Audit").text_size(12.0),
+                |state: &mut AppState| state.run_kerning_audit(),
+            ),
+            label(summary).text_size(12.0).color(theme::grid::CELL_TEXT),
+            button(label("Prev").text_size(12.0), |state: &mut AppState| {
+                state.kerning_page = state.kerning_page.saturating_sub(1);
+            }),
+            button(
+                label("Next").text_size(12.0),
+                move |state: &mut AppState| {
+                    if has_next {
+                        state.kerning_page += 1;
+                    }
+                },
+            ),
+        ))
+        .cross_axis_alignment(CrossAxisAlignment::Center)
+        .gap(GAP.px()),
+        // The findings
+        sized_box(
+            flex_col(rows)
+                .cross_axis_alignment(CrossAxisAlignment::Start)
+                .gap(2.px()),
+        )
+        .padding(GAP.px())
+        .background_color(theme::panel::BACKGROUND)
+        .border_color(theme::panel::OUTLINE)
+        .border_width(1.5.px())
+        .corner_radius(theme::size::PANEL_RADIUS.px())
+        .flex(1.0),
+    ))
+    .cross_axis_alignment(CrossAxisAlignment::Stretch)
+    .gap(GAP.px())
+}
+
+fn threshold_input(value: f64, percent: bool) -> impl WidgetView<AppState> + use<> {
+    sized_box(
+        text_input(format!("{value}"), move |state: &mut AppState, input| {
+            state.set_kerning_audit_threshold(percent, input);
+        })
+        .text_alignment(parley::Alignment::Center),
+    )
+    .width(56.px())
+}
+
+fn issue_row(
+    issue: AuditIssue,
+    sample: kurbo::BezPath,
+    upm: f64,
+) -> impl WidgetView<AppState> + use<> {
+    let AuditIssue {
+        first,
+        second,
+        first_glyph,
+        second_glyph,
+        value,
+        problem,
+    } = issue;
+
+    flex_row((
+        sized_box(
+            multi_glyph_view(vec![sample], SAMPLE_WIDTH, SAMPLE_HEIGHT, upm)
+                .color(theme::panel::GLYPH_PREVIEW)
+                .fit_to_bounds(),
+        )
+        .width(SAMPLE_WIDTH.px())
+        .height(SAMPLE_HEIGHT.px()),
+        sized_box(
+            label(format!("{first} / {second}  {value}"))
+                .text_size(12.0)
+                .color(theme::grid::CELL_TEXT),
+        )
+        .width(PAIR_WIDTH.px()),
+        label(problem.describe())
+            .text_size(12.0)
+            .color(theme::panel::GLYPH_PREVIEW)
+            .flex(1.0),
+        button(
+            label("Open").text_size(11.0),
+            move |state: &mut AppState| {
+                state.open_kern_pair(&first_glyph, &second_glyph);
+            },
+        ),
+    ))
+    .cross_axis_alignment(CrossAxisAlignment::Center)
+    .gap(GAP.px())
+}
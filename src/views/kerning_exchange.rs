@@ -0,0 +1,181 @@
+// Copyright 2026 the Runebender Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Kerning import / export — CSV and feature-file exchange for the
+//! active master, shown in the kerning tab. A file picked for import
+//! is held as a dry run: its diff against the master is listed, and
+//! nothing changes until "Apply".
+
+use masonry::layout::AsUnit;
+use xilem::WidgetView;
+use xilem::core::one_of::Either;
+use xilem::style::Style;
+use xilem::view::{CrossAxisAlignment, FlexExt, button, flex_col, flex_row, label, sized_box};
+
+use crate::data::AppState;
+use crate::model::kerning_io::{GroupChange, ImportMode, KerningDiff, PairChange, diff_import};
+use crate::model::read_workspace;
+use crate::theme;
+
+/// Gap between rows and row cells
+const GAP: f64 = 6.0;
+
+/// Diff lines listed; the summary counts all of them
+const MAX_DIFF_LINES: usize = 200;
+
+/// The import / export body of the kerning tab
+pub(crate) fn kerning_exchange_panel(state: &AppState) -> impl WidgetView<AppState> + use<> {
+    let master = state.kerning_master_name();
+    let mode = state.kerning_import_mode;
+    let message = state.kerning_io_message.clone().unwrap_or_default();
+
+    let pending = state.kerning_import.as_ref().and_then(|import| {
+        let workspace_arc = state.active_workspace()?;
+        let workspace = read_workspace(&workspace_arc);
+        let diff = diff_import(&workspace.kerning, &workspace.groups, &import.data, mode);
+        Some((import.source.clone(), import.data.skipped, diff))
+    });
+
+    let dry_run = match pending {
+        Some((source, skipped, diff)) => Either::A(diff_panel(source, skipped, diff)),
+        None => Either::B(sized_box(label("")).width(0.px()).height(0.px())),
+    };
+
+    flex_col((
+        // Export
+        flex_row((
+            label(format!("Master: {master}"))
+                .text_size(12.0)
+                .color(theme::grid::CELL_TEXT),
+            button(
+                label("Export CSV…").text_size(12.0),
+                |state: &mut AppState| state.export_kerning(false),
+            ),
+            button(
+                label("Export Feature File…").text_size(12.0),
+                |state: &mut AppState| state.export_kerning(true),
+            ),
+        ))
+        .cross_axis_alignment(CrossAxisAlignment::Center)
+        .gap(GAP.px()),
+        // Import
+        flex_row((
+            button(
+                label("Import CSV…").text_size(12.0),
+                |state: &mut AppState| state.import_kerning(false),
+            ),
+            button(
+                label("Import Feature File…").text_size(12.0),
+                |state: &mut AppState| state.import_kerning(true),
+            ),
+            mode_button("Merge", ImportMode::Merge, mode),
+            mode_button("Replace", ImportMode::Replace, mode),
+            label(message).text_size(12.0).color(theme::grid::CELL_TEXT),
+        ))
+        .cross_axis_alignment(CrossAxisAlignment::Center)
+        .gap(GAP.px()),
+        // Dry run
+        dry_run.flex(1.0),
+    ))
+    .cross_axis_alignment(CrossAxisAlignment::Stretch)
+    .gap(GAP.px())
+}
+
+fn mode_button(
+    text: &'static str,
+    mode: ImportMode,
+    current: ImportMode,
+) -> impl WidgetView<AppState> + use<> {
+    let text = if mode == current {
+        format!("● {text}")
+    } else {
+        text.to_string()
+    };
+    button(label(text).text_size(12.0), move |state: &mut AppState| {
+        state.set_kerning_import_mode(mode);
+    })
+}
+
+fn diff_panel(
+    source: String,
+    skipped: usize,
+    diff: KerningDiff,
+) -> impl WidgetView<AppState> + use<> {
+    let mut summary = format!(
+        "{source}: {} pair and {} group changes",
+        diff.pairs.len(),
+        diff.groups.len()
+    );
+    if skipped > 0 {
+        summary.push_str(&format!(", {skipped} statements skipped"));
+    }
+    if diff.is_empty() {
+        summary.push_str(" — nothing to apply");
+    }
+
+    let lines: Vec<_> = diff
+        .groups
+        .iter()
+        .map(group_line)
+        .chain(diff.pairs.iter().map(pair_line))
+        .take(MAX_DIFF_LINES)
+        .map(|line| {
+            label(line)
+                .text_size(11.0)
+                .color(theme::panel::GLYPH_PREVIEW)
+        })
+        .collect();
+
+    sized_box(
+        flex_col((
+            flex_row((
+                label(summary).text_size(12.0).color(theme::grid::CELL_TEXT),
+                button(label("Apply").text_size(12.0), |state: &mut AppState| {
+                    state.apply_kerning_import()
+                }),
+                button(label("Discard").text_size(12.0), |state: &mut AppState| {
+                    state.discard_kerning_import()
+                }),
+            ))
+            .cross_axis_alignment(CrossAxisAlignment::Center)
+            .gap(GAP.px()),
+            flex_col(lines)
+                .cross_axis_alignment(CrossAxisAlignment::Start)
+                .gap(1.px()),
+        ))
+        .cross_axis_alignment(CrossAxisAlignment::Start)
+        .gap(GAP.px()),
+    )
+    .padding(GAP.px())
+    .background_color(theme::panel::BACKGROUND)
+    .border_color(theme::panel::OUTLINE)
+    .border_width(1.5.px())
+    .corner_radius(theme::size::PANEL_RADIUS.px())
+}
+
+fn pair_line(change: &PairChange) -> String {
+    let PairChange {
+        first,
+        second,
+        old,
+        new,
+    } = change;
+    match (old, new) {
+        (None, Some(new)) => format!("+ {first} {second} {new}"),
+        (Some(old), None) => format!("− {first} {second} {old}"),
+        (Some(old), Some(new)) => format!("~ {first} {second} {old} → {new}"),
+        (None, None) => String::new(),
+    }
+}
+
+fn group_line(change: &GroupChange) -> String {
+    let GroupChange { name, old, new } = change;
+    match (old, new) {
+        (None, Some(new)) => format!("+ {name} [{}]", new.join(" ")),
+        (Some(old), None) => format!("− {name} [{}]", old.join(" ")),
+        (Some(old), Some(new)) => {
+            format!("~ {name} [{}] → [{}]", old.join(" "), new.join(" "))
+        }
+        (None, None) => String::new(),
+    }
+}
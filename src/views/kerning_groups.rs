@@ -0,0 +1,256 @@
+// Copyright 2026 the Runebender Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Kerning group manager — the `public.kern1.*` or `public.kern2.*`
+//! groups of the font with their members, shown in the kerning tab.
+//!
+//! Glyphs are moved between groups by picking one up (click its name)
+//! and dropping it on another group; a whole group is picked up with
+//! "Merge…" and dropped to merge it. Glyphs that belong to more than
+//! one group on the same side are marked with "!" and listed above
+//! the groups. Renaming, merging and deleting rewrite the kerning
+//! pairs that name the group.
+
+use masonry::layout::AsUnit;
+use xilem::WidgetView;
+use xilem::core::one_of::Either;
+use xilem::style::Style;
+use xilem::view::{
+    CrossAxisAlignment, FlexExt, button, flex_col, flex_row, label, sized_box, text_input,
+};
+
+use crate::data::{AppState, GroupDrag};
+use crate::model::kerning_groups::{GroupSide, conflicts, side_groups};
+use crate::model::read_workspace;
+use crate::theme;
+
+/// Gap between rows and row cells
+const GAP: f64 = 6.0;
+
+/// Groups shown per page
+const PAGE_SIZE: usize = 20;
+
+/// Member names per line of a group row
+const MEMBERS_PER_LINE: usize = 12;
+
+/// Width of the group name column
+const NAME_WIDTH: f64 = 160.0;
+
+/// A group row's contents
+struct GroupItem {
+    name: String,
+    members: Vec<String>,
+}
+
+/// The group manager body of the kerning tab
+pub(crate) fn kerning_groups_panel(state: &AppState) -> impl WidgetView<AppState> + use<> {
+    let side = state.kerning_group_side;
+    let (items, conflicted, total) = match state.active_workspace() {
+        Some(workspace_arc) => {
+            let workspace = read_workspace(&workspace_arc);
+            let groups = side_groups(&workspace.groups, side);
+            let total = groups.len();
+            let items: Vec<GroupItem> = groups
+                .into_iter()
+                .skip(state.kerning_group_page * PAGE_SIZE)
+                .take(PAGE_SIZE)
+                .map(|(name, members)| GroupItem {
+                    name: name.to_string(),
+                    members: members.to_vec(),
+                })
+                .collect();
+            (items, conflicts(&workspace.groups, side), total)
+        }
+        None => (Vec::new(), Default::default(), 0),
+    };
+
+    let has_next = (state.kerning_group_page + 1) * PAGE_SIZE < total;
+    let conflict_text = if conflicted.is_empty() {
+        String::new()
+    } else {
+        let list: Vec<String> = conflicted
+            .iter()
+            .map(|(glyph, groups)| format!("{glyph} ({})", groups.join(", ")))
+            .collect();
+        format!("! In several groups: {}", list.join("; "))
+    };
+    let status = match (&state.kerning_group_drag, &state.kerning_group_message) {
+        (Some(GroupDrag::Glyph(glyph)), _) => format!("Moving {glyph}: drop it on a group"),
+        (Some(GroupDrag::Group(group)), _) => format!("Merging {group}: drop it on a group"),
+        (None, Some(message)) => message.clone(),
+        (None, None) => String::new(),
+    };
+    let holding_glyph = matches!(state.kerning_group_drag, Some(GroupDrag::Glyph(_)));
+
+    let rows: Vec<_> = items
+        .into_iter()
+        .map(|item| group_row(item, state.kerning_group_drag.as_ref(), &conflicted))
+        .collect();
+
+    flex_col((
+        // Side, name field, create, and what is being moved
+        flex_row((
+            side_button("First Side", GroupSide::First, side),
+            side_button("Second Side", GroupSide::Second, side),
+            sized_box(
+                text_input(
+                    state.kerning_group_name.clone(),
+                    |state: &mut AppState, name| state.kerning_group_name = name,
+                )
+                .placeholder("Group name or glyphs"),
+            )
+            .width(200.px()),
+            button(
+                label("Create Group").text_size(12.0),
+                |state: &mut AppState| {
+                    state.create_kerning_group();
+                },
+            ),
+            label(status).text_size(12.0).color(theme::grid::CELL_TEXT),
+            if holding_glyph {
+                Either::A(button(
+                    label("Ungroup").text_size(12.0),
+                    |state: &mut AppState| state.ungroup_held_glyph(),
+                ))
+            } else {
+                Either::B(sized_box(label("")).width(0.px()).height(0.px()))
+            },
+            button(label("Prev").text_size(12.0), |state: &mut AppState| {
+                state.kerning_group_page = state.kerning_group_page.saturating_sub(1);
+            }),
+            button(
+                label("Next").text_size(12.0),
+                move |state: &mut AppState| {
+                    if has_next {
+                        state.kerning_group_page += 1;
+                    }
+                },
+            ),
+        ))
+        .cross_axis_alignment(CrossAxisAlignment::Center)
+        .gap(GAP.px()),
+        label(conflict_text)
+            .text_size(12.0)
+            .color(theme::panel::GLYPH_PREVIEW),
+        // The groups
+        sized_box(
+            flex_col(rows)
+                .cross_axis_alignment(CrossAxisAlignment::Start)
+                .gap(GAP.px()),
+        )
+        .padding(GAP.px())
+        .background_color(theme::panel::BACKGROUND)
+        .border_color(theme::panel::OUTLINE)
+        .border_width(1.5.px())
+        .corner_radius(theme::size::PANEL_RADIUS.px())
+        .flex(1.0),
+    ))
+    .cross_axis_alignment(CrossAxisAlignment::Stretch)
+    .gap(GAP.px())
+}
+
+fn side_button(
+    text: &'static str,
+    side: GroupSide,
+    current: GroupSide,
+) -> impl WidgetView<AppState> + use<> {
+    let text = if side == current {
+        format!("● {text}")
+    } else {
+        text.to_string()
+    };
+    button(label(text).text_size(12.0), move |state: &mut AppState| {
+        state.set_kerning_group_side(side);
+    })
+}
+
+fn group_row(
+    item: GroupItem,
+    drag: Option<&GroupDrag>,
+    conflicted: &std::collections::BTreeMap<String, Vec<String>>,
+) -> impl WidgetView<AppState> + use<> {
+    let GroupItem { name, members } = item;
+    let short = name
+        .split_once('.')
+        .and_then(|(_, rest)| rest.split_once('.'))
+        .map_or(name.as_str(), |(_, short)| short)
+        .to_string();
+
+    let lines: Vec<_> = members
+        .chunks(MEMBERS_PER_LINE)
+        .map(|chunk| {
+            let chips: Vec<_> = chunk
+                .iter()
+                .map(|glyph| member_chip(glyph, drag, conflicted.contains_key(glyph)))
+                .collect();
+            flex_row(chips).gap(2.px())
+        })
+        .collect();
+
+    let drop_label = match drag {
+        Some(GroupDrag::Glyph(glyph)) if !members.contains(glyph) => Some("Drop Here"),
+        Some(GroupDrag::Group(from)) if *from != name => Some("Merge Here"),
+        _ => None,
+    };
+    let actions = match drop_label {
+        Some(text) => {
+            let group = name.clone();
+            Either::A(button(
+                label(text).text_size(11.0),
+                move |state: &mut AppState| state.drop_on_kerning_group(&group),
+            ))
+        }
+        None => Either::B(group_actions(name.clone())),
+    };
+
+    flex_row((
+        sized_box(label(short).text_size(12.0).color(theme::grid::CELL_TEXT))
+            .width(NAME_WIDTH.px()),
+        flex_col(lines).gap(2.px()).flex(1.0),
+        actions,
+    ))
+    .cross_axis_alignment(CrossAxisAlignment::Start)
+    .gap(GAP.px())
+}
+
+/// A member name; clicking it picks the glyph up
+fn member_chip(
+    glyph: &str,
+    drag: Option<&GroupDrag>,
+    conflicted: bool,
+) -> impl WidgetView<AppState> + use<> {
+    let held = matches!(drag, Some(GroupDrag::Glyph(g)) if g == glyph);
+    let text = match (held, conflicted) {
+        (true, _) => format!("[{glyph}]"),
+        (false, true) => format!("!{glyph}"),
+        (false, false) => glyph.to_string(),
+    };
+    let glyph = glyph.to_string();
+    button(label(text).text_size(11.0), move |state: &mut AppState| {
+        state.pick_up_in_kerning_groups(GroupDrag::Glyph(glyph.clone()));
+    })
+}
+
+/// Add / rename / merge / delete buttons of a group
+fn group_actions(group: String) -> impl WidgetView<AppState> + use<> {
+    let action = |text: &'static str, group: String, f: fn(&mut AppState, &str)| {
+        button(label(text).text_size(11.0), move |state: &mut AppState| {
+            f(state, &group)
+        })
+    };
+    flex_row((
+        action("Add", group.clone(), |state, group| {
+            state.add_glyphs_to_kerning_group(group);
+        }),
+        action("Rename", group.clone(), |state, group| {
+            state.rename_kerning_group(group);
+        }),
+        action("Merge…", group.clone(), |state, group| {
+            state.pick_up_in_kerning_groups(GroupDrag::Group(group.to_string()));
+        }),
+        action("Delete", group, |state, group| {
+            state.delete_kerning_group(group);
+        }),
+    ))
+    .gap(2.px())
+}
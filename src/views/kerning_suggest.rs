@@ -0,0 +1,236 @@
+// Copyright 2026 the Runebender Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Kerning suggestions — values proposed from outline distance for a
+//! glyph list, shown in the kerning tab. Suggestions are reviewed
+//! pair by pair, set with their proposed value, and nothing changes
+//! until "Apply".
+
+use masonry::layout::AsUnit;
+use xilem::WidgetView;
+use xilem::style::Style;
+use xilem::view::{
+    CrossAxisAlignment, FlexExt, button, flex_col, flex_row, label, sized_box, text_input,
+};
+
+use crate::components::multi_glyph_view;
+use crate::data::AppState;
+use crate::model::auto_kern::{AutoKernOptions, KernSuggestion};
+use crate::model::read_workspace;
+use crate::theme;
+use crate::views::kerning::pair_sample;
+
+/// Gap between rows and row cells
+const GAP: f64 = 6.0;
+
+/// Suggestions shown per page
+const PAGE_SIZE: usize = 50;
+
+/// Size of the pair sample in each row
+const SAMPLE_WIDTH: f64 = 120.0;
+const SAMPLE_HEIGHT: f64 = 40.0;
+
+/// Width of the pair column
+const PAIR_WIDTH: f64 = 240.0;
+
+/// The suggestion body of the kerning tab
+pub(crate) fn kerning_suggest_panel(state: &AppState) -> impl WidgetView<AppState> + use<> {
+    let suggestions = state
+        .auto_kern
+        .as_ref()
+        .map(|a| a.suggestions.as_slice())
+        .unwrap_or_default();
+    let total = suggestions.len();
+    let skipped = state.kerning_page * PAGE_SIZE;
+    let (rows, upm) = match state.active_workspace() {
+        Some(workspace_arc) => {
+            let workspace = read_workspace(&workspace_arc);
+            let rows: Vec<_> = suggestions
+                .iter()
+                .enumerate()
+                .skip(skipped)
+                .take(PAGE_SIZE)
+                .map(|(index, suggestion)| {
+                    let sample = pair_sample(
+                        &suggestion.first,
+                        &suggestion.second,
+                        suggestion.value,
+                        &workspace,
+                    );
+                    (index, suggestion.clone(), sample)
+                })
+                .collect();
+            (rows, workspace.units_per_em.unwrap_or(1000.0))
+        }
+        None => (Vec::new(), 1000.0),
+    };
+
+    let summary = match (&state.auto_kern, &state.auto_kern_message) {
+        (_, Some(message)) => message.clone(),
+        (None, None) => "Not run yet".to_string(),
+        (Some(auto_kern), None) if total == 0 => {
+            format!("Gap {:.0}: nothing to change", auto_kern.target)
+        }
+        (Some(auto_kern), None) => {
+            let accepted = suggestions.iter().filter(|s| s.accepted).count();
+            let last = (skipped + PAGE_SIZE).min(total);
+            format!(
+                "Gap {:.0}: {}–{last} of {total} suggestions, {accepted} accepted",
+                auto_kern.target,
+                skipped + 1
+            )
+        }
+    };
+    let has_next = (state.kerning_page + 1) * PAGE_SIZE < total;
+    let options = state.auto_kern_options;
+    let rows: Vec<_> = rows
+        .into_iter()
+        .map(|(index, suggestion, sample)| suggestion_row(index, suggestion, sample, upm))
+        .collect();
+
+    flex_col((
+        // Glyphs, reference pairs and settings
+        flex_row((
+            text_input(
+                state.auto_kern_glyphs.clone(),
+                |state: &mut AppState, glyphs| state.set_auto_kern_glyphs(glyphs),
+            )
+            .placeholder("Glyph names")
+            .flex(1.0),
+            button(
+                label("Use Selection").text_size(12.0),
+                |state: &mut AppState| state.auto_kern_grid_selection(),
+            ),
+            label("Reference").text_size(12.0),
+            sized_box(
+                text_input(
+                    state.auto_kern_references.clone(),
+                    |state: &mut AppState, references| state.set_auto_kern_references(references),
+                )
+                .placeholder("nn oo"),
+            )
+            .width(96.px()),
+            label("Depth").text_size(12.0),
+            option_input(options.depth, |o| &mut o.depth),
+            label("Threshold").text_size(12.0),
+            option_input(options.threshold, |o| &mut o.threshold),
+            label("Min distance").text_size(12.0),
+            option_input(options.min_distance, |o| &mut o.min_distance),
+            button(label("Suggest").text_size(12.0), |state: &mut AppState| {
+                state.run_auto_kern()
+            }),
+        ))
+        .cross_axis_alignment(CrossAxisAlignment::Center)
+        .gap(GAP.px()),
+        // Review and paging
+        flex_row((
+            label(summary)
+                .text_size(12.0)
+                .color(theme::grid::CELL_TEXT)
+                .flex(1.0),
+            button(
+                label("Accept All").text_size(12.0),
+                |state: &mut AppState| state.accept_all_kern_suggestions(true),
+            ),
+            button(
+                label("Reject All").text_size(12.0),
+                |state: &mut AppState| state.accept_all_kern_suggestions(false),
+            ),
+            button(label("Apply").text_size(12.0), |state: &mut AppState| {
+                state.apply_kern_suggestions()
+            }),
+            button(label("Discard").text_size(12.0), |state: &mut AppState| {
+                state.discard_kern_suggestions()
+            }),
+            button(label("Prev").text_size(12.0), |state: &mut AppState| {
+                state.kerning_page = state.kerning_page.saturating_sub(1);
+            }),
+            button(
+                label("Next").text_size(12.0),
+                move |state: &mut AppState| {
+                    if has_next {
+                        state.kerning_page += 1;
+                    }
+                },
+            ),
+        ))
+        .cross_axis_alignment(CrossAxisAlignment::Center)
+        .gap(GAP.px()),
+        // The suggestions
+        sized_box(
+            flex_col(rows)
+                .cross_axis_alignment(CrossAxisAlignment::Start)
+                .gap(2.px()),
+        )
+        .padding(GAP.px())
+        .background_color(theme::panel::BACKGROUND)
+        .border_color(theme::panel::OUTLINE)
+        .border_width(1.5.px())
+        .corner_radius(theme::size::PANEL_RADIUS.px())
+        .flex(1.0),
+    ))
+    .cross_axis_alignment(CrossAxisAlignment::Stretch)
+    .gap(GAP.px())
+}
+
+fn option_input(
+    value: f64,
+    field: fn(&mut AutoKernOptions) -> &mut f64,
+) -> impl WidgetView<AppState> + use<> {
+    sized_box(
+        text_input(format!("{value}"), move |state: &mut AppState, input| {
+            state.set_auto_kern_option(input, field);
+        })
+        .text_alignment(parley::Alignment::Center),
+    )
+    .width(56.px())
+}
+
+fn suggestion_row(
+    index: usize,
+    suggestion: KernSuggestion,
+    sample: kurbo::BezPath,
+    upm: f64,
+) -> impl WidgetView<AppState> + use<> {
+    let KernSuggestion {
+        first,
+        second,
+        current,
+        value,
+        accepted,
+    } = suggestion;
+    let mark = if accepted { "✓" } else { "–" };
+
+    flex_row((
+        sized_box(button(
+            label(mark).text_size(12.0),
+            move |state: &mut AppState| state.toggle_kern_suggestion(index),
+        ))
+        .width(32.px()),
+        sized_box(
+            multi_glyph_view(vec![sample], SAMPLE_WIDTH, SAMPLE_HEIGHT, upm)
+                .color(theme::panel::GLYPH_PREVIEW)
+                .fit_to_bounds(),
+        )
+        .width(SAMPLE_WIDTH.px())
+        .height(SAMPLE_HEIGHT.px()),
+        sized_box(
+            label(format!("{first} / {second}"))
+                .text_size(12.0)
+                .color(theme::grid::CELL_TEXT),
+        )
+        .width(PAIR_WIDTH.px()),
+        label(format!("{current} → {value}"))
+            .text_size(12.0)
+            .color(theme::panel::GLYPH_PREVIEW)
+            .flex(1.0),
+        button(
+            label("Open").text_size(11.0),
+            move |state: &mut AppState| {
+                state.open_kern_pair(&first, &second);
+            },
+        ),
+    ))
+    .cross_axis_alignment(CrossAxisAlignment::Center)
+    .gap(GAP.px())
+}
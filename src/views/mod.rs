@@ -9,14 +9,25 @@
 //!
 //! - `editor`: The main glyph editing interface with canvas and toolbars
 //! - `glyph_grid`: The grid view showing all glyphs in the font
+//! - `kerning`: The kerning table with groups and exceptions
+//! - `kerning_audit`: The kerning audit inside the kerning tab
+//! - `kerning_exchange`: Kerning import and export inside the kerning tab
+//! - `kerning_groups`: The kerning group manager inside the kerning tab
+//! - `kerning_suggest`: Kerning suggestions inside the kerning tab
 //! - `welcome`: The welcome screen shown when no font is loaded
 
 pub mod editor;
 pub mod editor_sidebar;
 pub mod glyph_grid;
+pub mod kerning;
+pub mod kerning_audit;
+pub mod kerning_exchange;
+pub mod kerning_groups;
+pub mod kerning_suggest;
 pub mod welcome;
 
 pub use editor::editor_tab;
 pub use editor_sidebar::editor_sidebar;
 pub use glyph_grid::glyph_grid_tab;
+pub use kerning::kerning_tab;
 pub use welcome::welcome;
@@ -173,6 +173,8 @@ fn create_r_glyph() -> Glyph {
         right_group: None,
         mark_color: None,
         mark_label: None,
+        measurements: Vec::new(),
+        metrics_keys: Default::default(),
     }
 }
 